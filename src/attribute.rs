@@ -0,0 +1,67 @@
+use crate::{
+    parser::{parse_keyed_date, parse_keyed_date_exact},
+    FlexibleDate, ParseOptions, Parsed,
+};
+
+/// A date given as a taskwarrior-style `key:value` attribute, eg. "due:fri" or
+/// `wait:"in 3 days"`. The key lets applications tell apart eg. due dates from scheduled ones.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KeyedDate {
+    /// The attribute's key, in lowercase.
+    pub key: String,
+    pub date: FlexibleDate,
+}
+
+impl KeyedDate {
+    /// Parses a `KeyedDate` from a string. Fails (returns `None`) if the full string does not
+    /// match a keyed date. Values with spaces in them must be quoted.
+    ///
+    /// ```rust
+    /// # use smart_date::{FlexibleDate, KeyedDate};
+    /// # fn main() {
+    /// let result = KeyedDate::parse_from_str("due:tomorrow").unwrap();
+    /// assert_eq!(result.key, "due");
+    /// assert_eq!(result.date, FlexibleDate::Tomorrow);
+    ///
+    /// let result = KeyedDate::parse_from_str("wait:\"in 3 days\"").unwrap();
+    /// assert_eq!(result.date, FlexibleDate::DaysFromNow(3));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn parse_from_str(text: &str) -> Option<KeyedDate> {
+        Self::parse_from_str_with_options(text, &ParseOptions::default())
+    }
+
+    /// Same as [`KeyedDate::parse_from_str`], but with control over how the text is parsed.
+    #[must_use]
+    pub fn parse_from_str_with_options(text: &str, options: &ParseOptions) -> Option<KeyedDate> {
+        parse_keyed_date_exact(text, options)
+            .ok()
+            .map(|(_, keyed)| keyed.data)
+    }
+
+    /// Finds and parses a `KeyedDate` from within a string.
+    ///
+    /// ```rust
+    /// # use smart_date::KeyedDate;
+    /// # fn main() {
+    /// let result = KeyedDate::find_and_parse_in_str("pay rent due:2024-02-01 +home").unwrap();
+    /// assert_eq!(result.data.key, "due");
+    /// assert_eq!(result.range, (9..23));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn find_and_parse_in_str(text: &str) -> Option<Parsed<KeyedDate>> {
+        Self::find_and_parse_in_str_with_options(text, &ParseOptions::default())
+    }
+
+    /// Same as [`KeyedDate::find_and_parse_in_str`], but with control over how the text is
+    /// parsed.
+    #[must_use]
+    pub fn find_and_parse_in_str_with_options(
+        text: &str,
+        options: &ParseOptions,
+    ) -> Option<Parsed<KeyedDate>> {
+        parse_keyed_date(text, options)
+    }
+}
@@ -0,0 +1,185 @@
+use crate::Parsed;
+use std::ops::{Deref, Range};
+
+/// A [`Parsed`] that also borrows the text it was found in, for applications like editors that
+/// work with the matched text itself without copying it out of the document.
+///
+/// It derefs to the owned [`Parsed`], so its fields can be read directly, and converts back into
+/// one with [`BorrowedParsed::into_owned`] once the document can't be borrowed any more.
+///
+/// ```rust
+/// # use smart_date::{FlexibleDate, Parsed};
+/// # fn main() {
+/// let document = String::from("call mom tomorrow");
+/// let result = FlexibleDate::find_and_parse_in_str(&document)
+///     .unwrap()
+///     .borrow_text(&document)
+///     .unwrap();
+/// assert_eq!(result.text, "tomorrow");
+/// assert_eq!(result.data, FlexibleDate::Tomorrow);
+///
+/// let owned: Parsed<FlexibleDate> = result.into_owned();
+/// assert_eq!(owned.range, (9..17));
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BorrowedParsed<'a, T> {
+    /// The part of the input that the data was parsed from.
+    pub text: &'a str,
+    pub parsed: Parsed<T>,
+}
+
+impl<T> Parsed<T> {
+    /// The text that the result was parsed from, as it was written in `source`, which should be
+    /// the string that was searched. Matching ignores case, but this keeps it, for applications
+    /// that echo the user's phrasing. Fails (returns `None`) if the `range` doesn't fit in
+    /// `source`.
+    ///
+    /// ```rust
+    /// # use smart_date::FlexibleDate;
+    /// # fn main() {
+    /// let source = "Scheduled for Tomorrow";
+    /// let result = FlexibleDate::find_and_parse_in_str(source).unwrap();
+    /// assert_eq!(result.text(source), Some("Tomorrow"));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn text<'a>(&self, source: &'a str) -> Option<&'a str> {
+        source.get(self.range.clone())
+    }
+
+    /// Splits the result's `range` into the parts of the phrase that carry meaning, leaving out
+    /// the `connectives` between them (matched ignoring case) along with any punctuation, for
+    /// UIs that highlight each part separately. The `range` is still the span of the whole
+    /// phrase. Fails (returns `None`) if the `range` doesn't fit in `source`.
+    ///
+    /// ```rust
+    /// # use smart_date::FlexibleDateTime;
+    /// # fn main() {
+    /// let source = "dinner friday, in the evening";
+    /// let result = FlexibleDateTime::find_and_parse_in_str(source).unwrap();
+    /// assert_eq!(result.range, (7..29));
+    ///
+    /// let parts = result.component_ranges(source, &["in", "the"]).unwrap();
+    /// assert_eq!(parts, vec![(7..13), (22..29)]);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn component_ranges(
+        &self,
+        source: &str,
+        connectives: &[&str],
+    ) -> Option<Vec<Range<usize>>> {
+        let punctuation = |c: char| c.is_ascii_punctuation();
+        let text = self.text(source)?;
+        let mut components: Vec<Range<usize>> = Vec::new();
+        // whether a connective or punctuation has come since the last component
+        let mut separated = true;
+        for (offset, word) in words(text) {
+            let trimmed = word.trim_start_matches(punctuation);
+            let leading = word.len() - trimmed.len();
+            let trimmed = trimmed.trim_end_matches(punctuation);
+            if trimmed.is_empty() || connectives.iter().any(|c| c.eq_ignore_ascii_case(trimmed)) {
+                separated = true;
+                continue;
+            }
+            let start = self.range.start + offset + leading;
+            let end = start + trimmed.len();
+            match components.last_mut() {
+                Some(last) if !separated && leading == 0 => last.end = end,
+                _ => components.push(start..end),
+            }
+            // eg. the comma in "friday, 5pm"
+            separated = leading + trimmed.len() < word.len();
+        }
+        Some(components)
+    }
+
+    /// Pairs the result with the text it was parsed from, borrowed out of `source` like
+    /// [`Parsed::text`]. Fails (returns `None`) if the `range` doesn't fit in `source`.
+    #[must_use]
+    pub fn borrow_text(self, source: &str) -> Option<BorrowedParsed<'_, T>> {
+        let text = self.text(source)?;
+        Some(BorrowedParsed { text, parsed: self })
+    }
+}
+
+/// The words in `text`, split on whitespace, along with where each one starts.
+fn words(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.split_whitespace()
+        .map(move |word| (word.as_ptr() as usize - text.as_ptr() as usize, word))
+}
+
+impl<T> BorrowedParsed<'_, T> {
+    /// The owned result, without the borrowed text.
+    #[must_use]
+    pub fn into_owned(self) -> Parsed<T> {
+        self.parsed
+    }
+}
+
+impl<T> Deref for BorrowedParsed<'_, T> {
+    type Target = Parsed<T>;
+
+    fn deref(&self) -> &Parsed<T> {
+        &self.parsed
+    }
+}
+
+impl<T> From<BorrowedParsed<'_, T>> for Parsed<T> {
+    fn from(borrowed: BorrowedParsed<'_, T>) -> Self {
+        borrowed.into_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::{FlexibleDate, FlexibleDateTime};
+
+    #[test]
+    fn test_borrow_text() {
+        let source = "due friday";
+        let parsed = FlexibleDate::find_and_parse_in_str(source).unwrap();
+        let borrowed = parsed.clone().borrow_text(source).unwrap();
+        assert_eq!(borrowed.text, "friday");
+        assert_eq!(borrowed.range, parsed.range);
+        assert_eq!(Parsed::from(borrowed), parsed);
+
+        // the range has to fit in the source
+        assert_eq!(parsed.borrow_text("fri"), None);
+    }
+
+    #[test]
+    fn test_component_ranges() {
+        let source = "Lunch next Friday at 1pm";
+        let parsed = FlexibleDateTime::find_and_parse_in_str(source).unwrap();
+        let parts = parsed.component_ranges(source, &["at"]).unwrap();
+        let parts: Vec<_> = parts.into_iter().map(|range| &source[range]).collect();
+        assert_eq!(parts, vec!["next Friday", "1pm"]);
+
+        // punctuation separates parts too, and connectives are matched ignoring case
+        let source = "tomorrow, AT 5:30pm";
+        let parsed = FlexibleDateTime::find_and_parse_in_str(source).unwrap();
+        let parts = parsed.component_ranges(source, &["at"]).unwrap();
+        assert_eq!(parts, vec![(0..8), (13..19)]);
+        let parts = parsed.component_ranges(source, &[]).unwrap();
+        assert_eq!(parts, vec![(0..8), (10..19)]);
+
+        assert_eq!(parsed.component_ranges("tomorrow", &[]), None);
+    }
+
+    #[test]
+    fn test_text_keeps_case() {
+        let source = "DUE Friday";
+        let parsed = FlexibleDate::find_and_parse_in_str(source).unwrap();
+        assert_eq!(parsed.text(source), Some("Friday"));
+
+        // "İ" is longer once it's lowercased, which mustn't shift the range
+        let source = "İİİ due Friday";
+        let parsed = FlexibleDate::find_and_parse_in_str(source).unwrap();
+        assert_eq!(parsed.text(source), Some("Friday"));
+    }
+}
@@ -0,0 +1,142 @@
+use chrono::{Datelike, NaiveDate, Weekday as ChronoWeekday};
+use std::iter;
+
+/// Decides which days count as business days, for phrases like "the last business day of the
+/// month". Implement this to account for holidays or a different work week.
+///
+/// ```rust
+/// # use smart_date::{BusinessCalendar, FlexibleDate};
+/// # use chrono::{Datelike, NaiveDate, Weekday};
+/// # fn main() {
+/// struct NoFridays;
+///
+/// impl BusinessCalendar for NoFridays {
+///     fn is_business_day(&self, date: NaiveDate) -> bool {
+///         date.weekday() != Weekday::Fri && smart_date::Weekdays.is_business_day(date)
+///     }
+/// }
+///
+/// let today = NaiveDate::parse_from_str("2024-05-02", "%Y-%m-%d").unwrap();
+/// let date = FlexibleDate::parse_from_str("the last business day of the month").unwrap();
+/// // May 31 2024 was a Friday
+/// let date = date.into_naive_date_with_calendar(today, &NoFridays);
+/// assert_eq!(date.to_string(), "2024-05-30");
+/// # }
+/// ```
+pub trait BusinessCalendar {
+    fn is_business_day(&self, date: NaiveDate) -> bool;
+}
+
+/// The usual business calendar, where Monday through Friday are business days.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Weekdays;
+
+impl BusinessCalendar for Weekdays {
+    fn is_business_day(&self, date: NaiveDate) -> bool {
+        !matches!(date.weekday(), ChronoWeekday::Sat | ChronoWeekday::Sun)
+    }
+}
+
+/// The latest business day on or before `date`. Gives up and returns `date` if there's no
+/// business day within a year of it.
+pub(crate) fn business_day_on_or_before(
+    date: NaiveDate,
+    calendar: &dyn BusinessCalendar,
+) -> NaiveDate {
+    date.iter_days()
+        .rev()
+        .take(366)
+        .find(|day| calendar.is_business_day(*day))
+        .unwrap_or(date)
+}
+
+/// The earliest business day on or after `date`. Gives up and returns `date` if there's no
+/// business day within a year of it.
+pub(crate) fn business_day_on_or_after(
+    date: NaiveDate,
+    calendar: &dyn BusinessCalendar,
+) -> NaiveDate {
+    date.iter_days()
+        .take(366)
+        .find(|day| calendar.is_business_day(*day))
+        .unwrap_or(date)
+}
+
+/// The date `amount` business days after `date`, not counting `date` itself, or `None` if that's
+/// too far away for a [`NaiveDate`] to hold.
+pub(crate) fn add_business_days(
+    date: NaiveDate,
+    amount: u32,
+    calendar: &dyn BusinessCalendar,
+) -> Option<NaiveDate> {
+    // there can't be more business days left than days, so don't scan for them
+    let days_left = NaiveDate::MAX.signed_duration_since(date).num_days();
+    if i64::from(amount) > days_left {
+        return None;
+    }
+    match amount.checked_sub(1) {
+        None => Some(date),
+        // `iter_days` stops short of `NaiveDate::MAX`, so step through the days by hand
+        Some(index) => iter::successors(date.succ_opt(), NaiveDate::succ_opt)
+            .filter(|day| calendar.is_business_day(*day))
+            .nth(index as usize),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_add_business_days() {
+        // a Thursday
+        let date = NaiveDate::parse_from_str("2023-10-12", "%Y-%m-%d").unwrap();
+        let result = add_business_days(date, 1, &Weekdays).unwrap();
+        assert_eq!(result.to_string(), "2023-10-13");
+        let result = add_business_days(date, 2, &Weekdays).unwrap();
+        assert_eq!(result.to_string(), "2023-10-16");
+        let result = add_business_days(date, 0, &Weekdays).unwrap();
+        assert_eq!(result, date);
+
+        // a Saturday
+        let date = NaiveDate::parse_from_str("2023-10-14", "%Y-%m-%d").unwrap();
+        let result = add_business_days(date, 1, &Weekdays).unwrap();
+        assert_eq!(result.to_string(), "2023-10-16");
+
+        // past the end of the calendar
+        assert_eq!(add_business_days(date, 99_999_999, &Weekdays), None);
+        assert_eq!(add_business_days(NaiveDate::MAX, 1, &Weekdays), None);
+        // a week before the end only has five business days left
+        let date = NaiveDate::MAX - chrono::Days::new(7);
+        assert!(add_business_days(date, 5, &Weekdays).is_some());
+        assert_eq!(add_business_days(date, 6, &Weekdays), None);
+    }
+
+    #[test]
+    fn test_business_day_on_or_after() {
+        // a Sunday
+        let date = NaiveDate::parse_from_str("2023-10-08", "%Y-%m-%d").unwrap();
+        let result = business_day_on_or_after(date, &Weekdays);
+        assert_eq!(result.to_string(), "2023-10-09");
+
+        // a Wednesday
+        let date = NaiveDate::parse_from_str("2023-10-11", "%Y-%m-%d").unwrap();
+        let result = business_day_on_or_after(date, &Weekdays);
+        assert_eq!(result, date);
+    }
+
+    #[test]
+    fn test_business_day_on_or_before() {
+        // a Sunday
+        let date = NaiveDate::parse_from_str("2023-10-08", "%Y-%m-%d").unwrap();
+        let result = business_day_on_or_before(date, &Weekdays);
+        assert_eq!(result.to_string(), "2023-10-06");
+
+        // a Wednesday
+        let date = NaiveDate::parse_from_str("2023-10-11", "%Y-%m-%d").unwrap();
+        let result = business_day_on_or_before(date, &Weekdays);
+        assert_eq!(result, date);
+    }
+}
@@ -0,0 +1,41 @@
+use crate::{FlexibleDate, Parsed};
+use chrono::NaiveDate;
+use std::{fmt, ops::Range};
+
+/// Two date phrases in the same text that refer to different days, eg. "tomorrow, 2024-01-27"
+/// typed on a day where tomorrow isn't the 27th. One of the phrases is relative to today and the
+/// other is a calendar date.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DateConflict {
+    /// Where the phrase that's relative to today, eg. "tomorrow", is in the text.
+    pub relative: Range<usize>,
+
+    /// The day that the relative phrase resolved to.
+    pub relative_date: NaiveDate,
+
+    /// Where the phrase that names a calendar date, eg. "2024-01-27", is in the text.
+    pub absolute: Range<usize>,
+
+    /// The day that the calendar date names.
+    pub absolute_date: NaiveDate,
+}
+
+impl fmt::Display for DateConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the date at {:?} is {}, but the date at {:?} is {}",
+            self.relative, self.relative_date, self.absolute, self.absolute_date
+        )
+    }
+}
+
+impl std::error::Error for DateConflict {}
+
+/// The first date phrase in some text, along with any other phrase in the text that disagrees
+/// with it. See [`FlexibleDate::find_and_check_in_str`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CheckedDate {
+    pub parsed: Parsed<FlexibleDate>,
+    pub conflict: Option<DateConflict>,
+}
@@ -0,0 +1,299 @@
+use crate::{parse_and_resolve, ParseOptions, ResolveContext, ResolvedKind, ResolvedParse};
+use chrono::{NaiveDate, NaiveDateTime};
+use std::fmt;
+
+/// A collection of example inputs along with what they should parse to, for checking that
+/// changes to the grammar (or to the [`ParseOptions`] an application uses) don't break phrases
+/// that used to work.
+///
+/// Corpora are written one case per line, as `input => expected`. The expected value is a date
+/// like `2023-10-09`, a date and time like `2023-10-09 17:00`, or `none` if the input shouldn't
+/// have a date in it. It can be followed by the part of the input that the date should come from,
+/// in brackets. Every corpus starts with an `@now` line, giving the moment that relative dates
+/// are counted from, and has only the one. Blank lines and lines starting with `#` are skipped.
+///
+/// The format is this small line-based one rather than TOML or JSON so that each case reads like
+/// the text it stands for, without quoting or escaping, and so that reading a corpus doesn't
+/// need any dependencies beyond the ones the parser already has.
+///
+/// ```rust
+/// # use smart_date::{Corpus, ParseOptions};
+/// # fn main() {
+/// let corpus = Corpus::parse_from_str(
+///     "@now 2023-10-08 12:00
+///     call mom tomorrow at 5 => 2023-10-09 17:00 [tomorrow at 5]
+///     water plants friday => 2023-10-13
+///     I'm a little teapot => none",
+/// )
+/// .unwrap();
+///
+/// let failures = corpus.run(&ParseOptions::default());
+/// assert!(failures.is_empty(), "{failures:?}");
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Corpus {
+    /// The moment that relative dates are counted from.
+    pub now: NaiveDateTime,
+    pub cases: Vec<CorpusCase>,
+}
+
+/// One example in a [`Corpus`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CorpusCase {
+    /// The line of the corpus that the case is on, starting from 1.
+    pub line: usize,
+    pub input: String,
+    pub expected: Expected,
+
+    /// The part of the input that the date should come from, if the corpus says.
+    pub text: Option<String>,
+}
+
+/// What a [`CorpusCase`] should parse to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expected {
+    /// No date at all.
+    Nothing,
+    /// A whole day.
+    Date(NaiveDate),
+    /// A time on some day.
+    DateTime(NaiveDateTime),
+}
+
+/// A [`CorpusCase`] whose input didn't parse to what was expected.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CorpusFailure {
+    pub case: CorpusCase,
+
+    /// What the input parsed to instead.
+    pub actual: Option<ResolvedParse>,
+}
+
+/// A line of a [`Corpus`] that couldn't be read.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CorpusError {
+    /// The line with the problem, starting from 1.
+    pub line: usize,
+    pub message: String,
+}
+
+impl Corpus {
+    /// Reads a corpus from its text.
+    ///
+    /// # Errors
+    ///
+    /// Fails if a line isn't written the way [`Corpus`] describes, if there's no `@now` line
+    /// before the first case, or if there's more than one `@now` line.
+    pub fn parse_from_str(text: &str) -> Result<Corpus, CorpusError> {
+        let mut now = None;
+        let mut cases = Vec::new();
+        for (index, line) in text.lines().enumerate() {
+            let line_number = index + 1;
+            let error = |message: &str| CorpusError {
+                line: line_number,
+                message: message.into(),
+            };
+
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(moment) = line.strip_prefix("@now ") {
+                let moment = NaiveDateTime::parse_from_str(moment.trim(), DATETIME_FORMAT)
+                    .map_err(|_| error("expected a date and time like 2023-10-08 12:00"))?;
+                // every case is counted from the same moment, so a later one can't change it
+                if now.replace(moment).is_some() {
+                    return Err(error("expected only one @now line"));
+                }
+                continue;
+            }
+            if now.is_none() {
+                return Err(error("expected an @now line before the first case"));
+            }
+
+            let (input, rest) = line
+                .rsplit_once("=>")
+                .ok_or_else(|| error("expected `input => expected`"))?;
+            let rest = rest.trim();
+            let (expected, text) = match rest.split_once('[') {
+                Some((expected, text)) => {
+                    let text = text
+                        .strip_suffix(']')
+                        .ok_or_else(|| error("expected a closing bracket"))?;
+                    (expected.trim(), Some(text.to_string()))
+                }
+                None => (rest, None),
+            };
+            let expected = parse_expected(expected).ok_or_else(|| {
+                error("expected `none`, a date like 2023-10-09, or a date and time")
+            })?;
+
+            cases.push(CorpusCase {
+                line: line_number,
+                input: input.trim().to_string(),
+                expected,
+                text,
+            });
+        }
+
+        let now = now.ok_or(CorpusError {
+            line: 1,
+            message: "expected an @now line".into(),
+        })?;
+        Ok(Corpus { now, cases })
+    }
+
+    /// Parses every case in the corpus, relative to its `now`, and returns the ones that didn't
+    /// parse to what was expected.
+    #[must_use]
+    pub fn run(&self, options: &ParseOptions) -> Vec<CorpusFailure> {
+        let context = ResolveContext {
+            now: self.now,
+            options: options.clone(),
+        };
+        self.cases
+            .iter()
+            .filter_map(|case| {
+                let actual = parse_and_resolve(&case.input, &context);
+                if case.matches(actual.as_ref()) {
+                    None
+                } else {
+                    Some(CorpusFailure {
+                        case: case.clone(),
+                        actual,
+                    })
+                }
+            })
+            .collect()
+    }
+}
+
+impl CorpusCase {
+    fn matches(&self, actual: Option<&ResolvedParse>) -> bool {
+        let Some(actual) = actual else {
+            return self.expected == Expected::Nothing;
+        };
+        let right_date = match self.expected {
+            Expected::Nothing => false,
+            Expected::Date(date) => {
+                actual.kind == ResolvedKind::Date && actual.resolved_date == date
+            }
+            Expected::DateTime(datetime) => actual.resolved_datetime == Some(datetime),
+        };
+        let right_text = self
+            .text
+            .as_ref()
+            .is_none_or(|text| self.input.get(actual.range.clone()) == Some(text));
+        right_date && right_text
+    }
+}
+
+const DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+fn parse_expected(expected: &str) -> Option<Expected> {
+    if expected == "none" {
+        Some(Expected::Nothing)
+    } else if let Ok(datetime) = NaiveDateTime::parse_from_str(expected, DATETIME_FORMAT) {
+        Some(Expected::DateTime(datetime))
+    } else {
+        NaiveDate::parse_from_str(expected, "%Y-%m-%d")
+            .ok()
+            .map(Expected::Date)
+    }
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Nothing => write!(f, "none"),
+            Expected::Date(date) => write!(f, "{date}"),
+            Expected::DateTime(datetime) => write!(f, "{}", datetime.format(DATETIME_FORMAT)),
+        }
+    }
+}
+
+impl fmt::Display for CorpusFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let case = &self.case;
+        write!(
+            f,
+            "line {}: {:?} expected {}",
+            case.line, case.input, case.expected
+        )?;
+        if let Some(text) = &case.text {
+            write!(f, " [{text}]")?;
+        }
+        match &self.actual {
+            None => write!(f, ", but found nothing"),
+            Some(actual) => {
+                let resolved = match actual.resolved_datetime {
+                    Some(datetime) => Expected::DateTime(datetime),
+                    None => Expected::Date(actual.resolved_date),
+                };
+                let text = case.input.get(actual.range.clone()).unwrap_or_default();
+                write!(f, ", but got {resolved} [{text}]")
+            }
+        }
+    }
+}
+
+impl fmt::Display for CorpusError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for CorpusError {}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_english_corpus() {
+        let corpus = Corpus::parse_from_str(include_str!("../corpus/english.txt")).unwrap();
+        let failures = corpus.run(&ParseOptions::default());
+        let report: Vec<_> = failures.iter().map(ToString::to_string).collect();
+        assert!(failures.is_empty(), "{}", report.join("\n"));
+    }
+
+    #[test]
+    fn test_failures() {
+        let corpus = Corpus::parse_from_str(
+            "@now 2023-10-08 12:00
+            lunch friday => 2023-10-14
+            lunch friday at noon => 2023-10-13 12:00 [friday]",
+        )
+        .unwrap();
+        let failures = corpus.run(&ParseOptions::default());
+        assert_eq!(failures.len(), 2);
+        assert_eq!(
+            failures[0].to_string(),
+            r#"line 2: "lunch friday" expected 2023-10-14, but got 2023-10-13 [friday]"#
+        );
+        assert_eq!(failures[1].case.text.as_deref(), Some("friday"));
+    }
+
+    #[test]
+    fn test_corpus_errors() {
+        let error = Corpus::parse_from_str("tomorrow => 2023-10-09").unwrap_err();
+        assert_eq!(error.line, 1);
+
+        let error =
+            Corpus::parse_from_str("@now 2023-10-08 12:00\n\ntomorrow => soon").unwrap_err();
+        assert_eq!(error.line, 3);
+
+        let error = Corpus::parse_from_str(
+            "@now 2023-10-08 12:00
+            tomorrow => 2023-10-09
+            @now 2024-01-01 12:00
+            tomorrow => 2024-01-02",
+        )
+        .unwrap_err();
+        assert_eq!(error.line, 3);
+        assert_eq!(error.message, "expected only one @now line");
+    }
+}
@@ -0,0 +1,187 @@
+use crate::Rule;
+use std::fmt;
+
+/// What this version of the crate can parse, for applications that adapt to it at runtime, eg.
+/// by hiding a recurrence picker when recurrences aren't supported.
+///
+/// The roadmap is read from the checklist in the [`FlexibleDate`](crate::FlexibleDate) docs, so
+/// the two always agree. Its [`Display`](fmt::Display) output is a stable, line-based report with
+/// a line for each roadmap item, rule, and enabled cargo feature.
+///
+/// ```rust
+/// # use smart_date::Coverage;
+/// # fn main() {
+/// let coverage = Coverage::current();
+/// assert!(coverage.supports("each monday"));
+/// assert!(!coverage.supports("jan 27 2024"));
+///
+/// let report = coverage.to_string();
+/// assert!(report.contains("[x] \"today\", \"tod\"\n"));
+/// assert!(report.contains("rule iso_date\n"));
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Coverage {
+    pub roadmap: Vec<RoadmapItem>,
+
+    /// The grammar rules, which can be turned off with
+    /// [`ParseOptions::disabled_rules`](crate::ParseOptions::disabled_rules).
+    pub rules: Vec<Rule>,
+
+    /// The cargo features that the crate was built with, eg. "obsidian".
+    pub features: Vec<&'static str>,
+}
+
+/// One item on the roadmap, eg. `"in 3 days", "in three days"`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoadmapItem {
+    /// The item as written in the docs, without its links.
+    pub description: String,
+
+    /// The phrases quoted in the description, eg. "in 3 days" and "in three days".
+    pub examples: Vec<String>,
+
+    pub implemented: bool,
+}
+
+impl Coverage {
+    /// The coverage of this build of the crate.
+    #[must_use]
+    pub fn current() -> Self {
+        let mut features = Vec::new();
+        if cfg!(feature = "obsidian") {
+            features.push("obsidian");
+        }
+        Self {
+            roadmap: parse_roadmap(include_str!("lib.rs")),
+            rules: Rule::ALL.to_vec(),
+            features,
+        }
+    }
+
+    /// Whether `phrase` is one of the examples of an implemented roadmap item.
+    #[must_use]
+    pub fn supports(&self, phrase: &str) -> bool {
+        self.roadmap
+            .iter()
+            .filter(|item| item.implemented)
+            .any(|item| item.examples.iter().any(|example| example == phrase))
+    }
+}
+
+impl fmt::Display for Coverage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for item in &self.roadmap {
+            let mark = if item.implemented { 'x' } else { ' ' };
+            writeln!(f, "[{mark}] {}", item.description)?;
+        }
+        for rule in &self.rules {
+            writeln!(f, "rule {}", rule.id())?;
+        }
+        for feature in &self.features {
+            writeln!(f, "feature {feature}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads the checklist items out of doc comments, joining items that wrap onto more lines.
+fn parse_roadmap(source: &str) -> Vec<RoadmapItem> {
+    let mut items: Vec<(bool, String)> = Vec::new();
+    let mut in_item = false;
+    for line in source.lines() {
+        let doc = line.trim_start().strip_prefix("///").unwrap_or_default();
+        if let Some(rest) = doc.strip_prefix(" - [x] ") {
+            items.push((true, rest.to_string()));
+            in_item = true;
+        } else if let Some(rest) = doc.strip_prefix(" - [ ] ") {
+            items.push((false, rest.to_string()));
+            in_item = true;
+        } else if let (true, Some(rest), Some((_, description))) =
+            (in_item, doc.strip_prefix("   "), items.last_mut())
+        {
+            description.push(' ');
+            description.push_str(rest.trim());
+        } else {
+            in_item = false;
+        }
+    }
+    items
+        .into_iter()
+        .map(|(implemented, description)| {
+            let description = remove_links(&description);
+            let examples = description
+                .split('"')
+                .skip(1)
+                .step_by(2)
+                .map(String::from)
+                .collect();
+            RoadmapItem {
+                description,
+                examples,
+                implemented,
+            }
+        })
+        .collect()
+}
+
+/// Turns markdown links like "[`Rule`]" or "[ambiguous](Parsed::ambiguous)" into their text.
+fn remove_links(text: &str) -> String {
+    let mut result = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find('[') {
+        let Some(end) = rest[start..].find(']').map(|end| start + end) else {
+            break;
+        };
+        result.push_str(&rest[..start]);
+        result.push_str(&rest[start + 1..end]);
+        rest = &rest[end + 1..];
+        if rest.starts_with('(') {
+            if let Some(close) = rest.find(')') {
+                rest = &rest[close + 1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_roadmap() {
+        let source = r#"
+/// Some docs.
+/// - [x] "today", "tod"
+/// - [x] flagging "sat" as [ambiguous](Parsed::ambiguous), unless it's written
+///   like "on Sat" (see [`AmbiguousNames`])
+/// - [ ] "next week"
+///
+///   not part of the last item
+fn unrelated() {}
+"#;
+        let roadmap = parse_roadmap(source);
+        assert_eq!(roadmap.len(), 3);
+        assert_eq!(roadmap[0].examples, vec!["today", "tod"]);
+        assert_eq!(
+            roadmap[1].description,
+            r#"flagging "sat" as ambiguous, unless it's written like "on Sat" (see `AmbiguousNames`)"#
+        );
+        assert_eq!(roadmap[1].examples, vec!["sat", "on Sat"]);
+        assert!(!roadmap[2].implemented);
+    }
+
+    #[test]
+    fn test_current_coverage() {
+        let coverage = Coverage::current();
+        assert!(coverage.supports("tomorrow"));
+        assert!(coverage.supports("in 3 days"));
+        assert!(!coverage.supports("next week"));
+        assert!(!coverage.supports("not on the roadmap"));
+
+        // the report is the same from one run to the next
+        assert_eq!(coverage.to_string(), Coverage::current().to_string());
+    }
+}
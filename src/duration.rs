@@ -0,0 +1,141 @@
+use crate::{
+    parser::{parse_flex_duration, parse_flex_duration_exact},
+    ParseOptions, Parsed,
+};
+use chrono::{Days, Duration, Months, NaiveDate, NaiveDateTime};
+
+/// Represents a length of time, eg. "90 minutes" or "2 weeks". Months and years don't have a
+/// fixed length, so they're kept apart from days and minutes until they're added to a date.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FlexibleDuration {
+    /// Calendar months, including twelve for each year.
+    pub months: u32,
+
+    /// Calendar days, including seven for each week.
+    pub days: u32,
+
+    pub minutes: u32,
+}
+
+impl FlexibleDuration {
+    /// Parses a `FlexibleDuration` from a string. Fails (returns `None`) if the full string does
+    /// not match a duration.
+    ///
+    /// ```rust
+    /// # use smart_date::FlexibleDuration;
+    /// # fn main() {
+    /// let result = FlexibleDuration::parse_from_str("for 90 minutes").unwrap();
+    /// assert_eq!(result.minutes, 90);
+    ///
+    /// let result = FlexibleDuration::parse_from_str("2 weeks").unwrap();
+    /// assert_eq!(result.days, 14);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn parse_from_str(text: &str) -> Option<FlexibleDuration> {
+        Self::parse_from_str_with_options(text, &ParseOptions::default())
+    }
+
+    /// Same as [`FlexibleDuration::parse_from_str`], but with control over how the text is
+    /// parsed.
+    #[must_use]
+    pub fn parse_from_str_with_options(
+        text: &str,
+        options: &ParseOptions,
+    ) -> Option<FlexibleDuration> {
+        parse_flex_duration_exact(text, options)
+            .ok()
+            .map(|(_, duration)| duration.data)
+    }
+
+    /// Finds and parses a `FlexibleDuration` from within a string.
+    ///
+    /// ```rust
+    /// # use smart_date::FlexibleDuration;
+    /// # fn main() {
+    /// let result = FlexibleDuration::find_and_parse_in_str("standup tomorrow at 9 for 15 mins").unwrap();
+    /// assert_eq!(result.data.minutes, 15);
+    /// assert_eq!(result.range, (22..33));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn find_and_parse_in_str(text: &str) -> Option<Parsed<FlexibleDuration>> {
+        Self::find_and_parse_in_str_with_options(text, &ParseOptions::default())
+    }
+
+    /// Same as [`FlexibleDuration::find_and_parse_in_str`], but with control over how the text
+    /// is parsed.
+    #[must_use]
+    pub fn find_and_parse_in_str_with_options(
+        text: &str,
+        options: &ParseOptions,
+    ) -> Option<Parsed<FlexibleDuration>> {
+        parse_flex_duration(text, options)
+    }
+
+    /// Converts the duration into a [`Duration`], eg. for an estimate of how long a task takes.
+    /// Fails (returns `None`) for durations with months or years in them, which don't have a
+    /// fixed length.
+    ///
+    /// ```rust
+    /// # use smart_date::FlexibleDuration;
+    /// # fn main() {
+    /// let duration = FlexibleDuration::parse_from_str("an hour and a half").unwrap();
+    /// assert_eq!(duration.to_duration(), Some(chrono::Duration::minutes(90)));
+    ///
+    /// let duration = FlexibleDuration::parse_from_str("for 1 month").unwrap();
+    /// assert_eq!(duration.to_duration(), None);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_duration(&self) -> Option<Duration> {
+        (self.months == 0)
+            .then(|| Duration::days(self.days.into()) + Duration::minutes(self.minutes.into()))
+    }
+
+    /// Adds the duration to a date. Minutes only count in whole days. Landing on a day that the
+    /// month doesn't have (eg. one month after January 31) gives the last day of that month.
+    /// Returns `None` if the result is out of range.
+    ///
+    /// ```rust
+    /// # use smart_date::FlexibleDuration;
+    /// # fn main() {
+    /// let date = chrono::NaiveDate::parse_from_str("2024-01-31", "%Y-%m-%d").unwrap();
+    ///
+    /// let duration = FlexibleDuration::parse_from_str("1 month").unwrap();
+    /// assert_eq!(duration.add_to_date(date).unwrap().to_string(), "2024-02-29");
+    ///
+    /// let duration = FlexibleDuration::parse_from_str("99999999 years").unwrap();
+    /// assert_eq!(duration.add_to_date(date), None);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn add_to_date(&self, date: NaiveDate) -> Option<NaiveDate> {
+        let days = u64::from(self.days) + u64::from(self.minutes / (24 * 60));
+        date.checked_add_months(Months::new(self.months))?
+            .checked_add_days(Days::new(days))
+    }
+
+    /// Adds the duration to a date and time, eg. to find when "tomorrow at 2pm for 90 minutes"
+    /// ends. Returns `None` if the result is out of range.
+    ///
+    /// ```rust
+    /// # use smart_date::{FlexibleDateTime, FlexibleDuration};
+    /// # fn main() {
+    /// let now = chrono::NaiveDateTime::parse_from_str("2023-10-08 12:00", "%Y-%m-%d %H:%M").unwrap();
+    ///
+    /// let start = FlexibleDateTime::parse_from_str("tomorrow at 2pm").unwrap();
+    /// let start = start.into_naive_datetime(now).unwrap();
+    /// let duration = FlexibleDuration::parse_from_str("for 90 minutes").unwrap();
+    /// let end = duration.add_to_datetime(start).unwrap();
+    /// assert_eq!(end.to_string(), "2023-10-09 15:30:00");
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn add_to_datetime(&self, datetime: NaiveDateTime) -> Option<NaiveDateTime> {
+        datetime
+            .checked_add_months(Months::new(self.months))?
+            .checked_add_days(Days::new(self.days.into()))?
+            .checked_add_signed(Duration::minutes(self.minutes.into()))
+    }
+}
@@ -0,0 +1,11 @@
+use crate::FlexibleDate;
+
+/// The possible dates that some text could be referring to.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Interpretations {
+    /// The candidate dates, in the order they appeared in the text.
+    pub dates: Vec<FlexibleDate>,
+
+    /// Whether the dates were offered as alternatives using "or", eg. "tuesday or wednesday".
+    pub from_alternatives: bool,
+}
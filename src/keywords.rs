@@ -0,0 +1,127 @@
+use crate::FlexibleTime;
+
+/// Words and phrases that applications can teach the parser at runtime.
+///
+/// ```rust
+/// # use smart_date::{FlexibleDateTime, FlexibleTime, Keywords, NamedTime, ParseOptions};
+/// # fn main() {
+/// let options = ParseOptions {
+///     keywords: Keywords {
+///         named_times: vec![NamedTime::at("lunch", FlexibleTime { hour: 12, minute: 30 })],
+///         ..Keywords::default()
+///     },
+///     ..ParseOptions::default()
+/// };
+///
+/// let result = FlexibleDateTime::parse_from_str_with_options("tomorrow after lunch", &options);
+/// assert_eq!(result.unwrap().time, FlexibleTime { hour: 12, minute: 30 });
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Keywords {
+    /// Times of day that can be referred to by name, like "lunch" in "tomorrow after lunch".
+    pub named_times: Vec<NamedTime>,
+
+    /// Set phrases that stand for a time of day, like "first thing" in "first thing tomorrow".
+    /// Defaults to [`Idiom::english`].
+    pub idioms: Vec<Idiom>,
+}
+
+impl Default for Keywords {
+    fn default() -> Self {
+        Self {
+            named_times: Vec::new(),
+            idioms: Idiom::english(),
+        }
+    }
+}
+
+/// A time of day, or a span of the day, that has a name, eg. "lunch" or "work".
+///
+/// Phrases starting with "before" resolve to the start of the span and phrases starting with
+/// "after" resolve to its end. Both are approximate, since they don't say exactly when.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NamedTime {
+    /// The name as it appears in text. Must be lowercase.
+    pub name: String,
+    pub start: FlexibleTime,
+
+    /// When the span ends, if it lasts a while, eg. 17:00 for "work".
+    pub end: Option<FlexibleTime>,
+}
+
+impl NamedTime {
+    /// A named time that happens at a single point in the day.
+    #[must_use]
+    pub fn at(name: &str, time: FlexibleTime) -> Self {
+        Self {
+            name: name.into(),
+            start: time,
+            end: None,
+        }
+    }
+
+    /// A named time that spans part of the day.
+    #[must_use]
+    pub fn between(name: &str, start: FlexibleTime, end: FlexibleTime) -> Self {
+        Self {
+            name: name.into(),
+            start,
+            end: Some(end),
+        }
+    }
+}
+
+/// A set phrase that stands for a time of day, eg. "first thing" or "end of play". Unlike named
+/// times, idioms can come before the date, as in "first thing tomorrow".
+///
+/// ```rust
+/// # use smart_date::{FlexibleDateTime, FlexibleTime, Idiom, IdiomTime, Keywords, ParseOptions};
+/// # fn main() {
+/// let mut options = ParseOptions::default();
+/// options.keywords.idioms.push(Idiom::new("crack of dawn", IdiomTime::At(FlexibleTime { hour: 6, minute: 0 })));
+///
+/// let result = FlexibleDateTime::parse_from_str_with_options("crack of dawn tomorrow", &options);
+/// assert_eq!(result.unwrap().time, FlexibleTime { hour: 6, minute: 0 });
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Idiom {
+    /// The phrase as it appears in text. Must be lowercase.
+    pub phrase: String,
+    pub time: IdiomTime,
+}
+
+/// The time of day that an [`Idiom`] stands for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum IdiomTime {
+    /// [`ParseOptions::workday_start`](crate::ParseOptions::workday_start).
+    WorkdayStart,
+
+    /// [`ParseOptions::close_of_business`](crate::ParseOptions::close_of_business).
+    CloseOfBusiness,
+
+    /// A particular time of day.
+    At(FlexibleTime),
+}
+
+impl Idiom {
+    #[must_use]
+    pub fn new(phrase: &str, time: IdiomTime) -> Self {
+        Self {
+            phrase: phrase.into(),
+            time,
+        }
+    }
+
+    /// The built-in English idioms: "first thing", "end of play", "end of day", and "eod".
+    #[must_use]
+    pub fn english() -> Vec<Self> {
+        vec![
+            Self::new("first thing", IdiomTime::WorkdayStart),
+            Self::new("end of play", IdiomTime::CloseOfBusiness),
+            Self::new("end of day", IdiomTime::CloseOfBusiness),
+            Self::new("eod", IdiomTime::CloseOfBusiness),
+        ]
+    }
+}
@@ -1,13 +1,78 @@
 #![warn(clippy::all, clippy::pedantic, clippy::unwrap_used)]
-use chrono::{Datelike, Days, NaiveDate, Weekday as ChronoWeekday};
-use parser::{parse_flex_date, parse_flex_date_exact};
-use std::ops::Range;
+use business::{add_business_days, business_day_on_or_after, business_day_on_or_before};
+use chrono::{Datelike, Days, Duration, Months, NaiveDate, Weekday as ChronoWeekday};
+use parser::{
+    parse_all_flex_dates, parse_flex_date, parse_flex_date_exact, parse_interpretations,
+    parse_lone_year, scan_flex_dates,
+};
+use period::next_quarter_numbered;
+use std::{fmt, ops::Range, sync::Arc};
+use suggest::{complete_date_word, suggest_date_word};
 
+pub use attribute::KeyedDate;
+pub use borrowed::BorrowedParsed;
+pub use business::{BusinessCalendar, Weekdays};
+pub use conflict::{CheckedDate, DateConflict};
+pub use corpus::{Corpus, CorpusCase, CorpusError, CorpusFailure, Expected};
+pub use coverage::{Coverage, RoadmapItem};
+pub use duration::FlexibleDuration;
+pub use interpretations::Interpretations;
+pub use keywords::{Idiom, IdiomTime, Keywords, NamedTime};
+pub use list::DateList;
+pub use live::LiveParser;
+pub use locale::{DateOrder, Locale};
+#[cfg(feature = "obsidian")]
+pub use obsidian::ObsidianTask;
+pub use options::{
+    AmbiguousNames, CancelToken, FuzzyQuantities, MatchPreference, ParseOptions, SearchOptions,
+};
+pub use period::{
+    Period, PeriodUnit, SectionPolicy, Segment, ThisWeekPolicy, WeekdayPolicy, WeekdayScope,
+    YearPolicy,
+};
+pub use range::{FlexibleDateRange, OpenDateRange, WindowEnd};
+pub use recurrence::{Frequency, LeapDayPolicy, Recurrence, RecurrenceEnd};
+pub use resolve::{
+    parse_and_resolve, resolve_batch, CanonicalKey, ResolveContext, ResolvedBatch, ResolvedKind,
+    ResolvedParse,
+};
+pub use rule::Rule;
+pub use scan::{DateProblem, Diagnostic, Scan};
+pub use schedule::Schedule;
+pub use suggest::Suggestion;
+pub use time::{FlexibleDateTime, FlexibleTime, MidnightPolicy, TimeOnlyPolicy};
+
+mod attribute;
+mod borrowed;
+mod business;
+mod conflict;
+mod corpus;
+mod coverage;
+mod duration;
+mod interpretations;
+mod keywords;
+mod list;
+mod live;
+mod locale;
+mod numbers;
+#[cfg(feature = "obsidian")]
+mod obsidian;
+mod options;
 mod parser;
+mod period;
+mod range;
+mod recurrence;
+mod resolve;
+mod rule;
+mod scan;
+mod schedule;
+mod suggest;
+mod time;
 
 /// Represents some data that has been parsed out of a string.
 /// Contains the data that was extracted as well as the location in
 /// the input string of the substring that was related to the data.
+#[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Parsed<T> {
     pub data: T,
 
@@ -15,6 +80,63 @@ pub struct Parsed<T> {
     // compute the offset.
     // see https://stackoverflow.com/questions/67148359/check-if-a-str-is-a-sub-slice-of-another-str
     pub range: Range<usize>,
+
+    /// Whether the input only loosely described the data, eg. "in a few days".
+    pub approximate: bool,
+
+    /// Whether part of the data was filled in by a guess, eg. the PM in "tomorrow at 5".
+    /// UIs may want to confirm these with the user.
+    pub inferred: bool,
+
+    /// For approximate data, how far off it might be in either direction, eg. the half hour
+    /// either side of "around 3".
+    pub tolerance: Option<Duration>,
+
+    /// Whether the input might not have been a date at all, eg. "sat" in "sat down". UIs may
+    /// want to confirm these with the user.
+    pub ambiguous: bool,
+
+    /// How sure the parser is that the text really means this, from 0 to 100, for applications
+    /// that act on some matches without asking, eg. `confidence >= 80`.
+    ///
+    /// Each grammar rule has a [specificity](Rule::specificity), and a match made of several
+    /// parts, like a date and a time, starts from the least specific of them. It then loses 40
+    /// if it's [ambiguous](Parsed::ambiguous), 15 if it's [approximate](Parsed::approximate), 10
+    /// if it's [inferred](Parsed::inferred), 10 if it's three characters or fewer, and 20 if a
+    /// misspelling had to be corrected (see [`ParseOptions::typo_tolerance`]).
+    ///
+    /// ```rust
+    /// # use smart_date::FlexibleDateTime;
+    /// # fn main() {
+    /// let result = FlexibleDateTime::find_and_parse_in_str("lunch tomorrow at noon").unwrap();
+    /// let sure = result.confidence;
+    ///
+    /// let result = FlexibleDateTime::find_and_parse_in_str("sat around 3").unwrap();
+    /// assert!(result.confidence < sure);
+    /// # }
+    /// ```
+    pub confidence: u8,
+
+    /// How finely the text pinned down the data, eg. [`Precision::Year`] for "in 2026" or
+    /// [`Precision::Minute`] for "at 5:30pm". This comes from the grammar rules that matched,
+    /// since the data alone doesn't say, eg. "next month" and "the 1st of next month" land on the
+    /// same day.
+    ///
+    /// ```rust
+    /// # use smart_date::{FlexibleDateTime, Precision};
+    /// # fn main() {
+    /// let result = FlexibleDateTime::find_and_parse_in_str("move out next month").unwrap();
+    /// assert_eq!(result.precision, Precision::Month);
+    ///
+    /// let result = FlexibleDateTime::find_and_parse_in_str("lunch tomorrow at noon").unwrap();
+    /// assert_eq!(result.precision, Precision::Hour);
+    /// # }
+    /// ```
+    pub precision: Precision,
+
+    /// The [name](Locale::name) of the locale whose words produced the match. This is usually
+    /// the main locale, but can be one of the [`ParseOptions::extra_locales`].
+    pub locale: Arc<str>,
 }
 
 /// Represents a relative (or, eventually, absolute) date.
@@ -25,29 +147,300 @@ pub struct Parsed<T> {
 /// - [x] "today", "tod"
 /// - [x] "tomorrow", "tom", "tmrw"
 /// - [x] "wednesday", "wed" (any weekday)
+/// - [x] flagging "sat" and "sun" as [ambiguous](Parsed::ambiguous), unless they're written
+///   like "on Sat" (see [`AmbiguousNames`])
 /// - [ ] "next week"
-/// - [ ] "this weekend"
-/// - [ ] "next weekend"
-/// - [ ] "in 3 days", "in three days"
-/// - [ ] "in 2 weeks", "in two weeks"
-/// - [ ] "2 weeks from now"
-/// - [ ] "in four months"
-/// - [ ] "in one year"
-/// - [ ] "next month"
-/// - [ ] "january 27", "jan 27", "01/27"
-/// - [ ] "jan 27 2024", "01/27/2024"
-/// - [ ] "27th"
-/// - [ ] "mid january"
-/// - [ ] "mid jan"
-/// - [ ] "later this week"
-/// - [ ] "two weeks from tomorrow"
+/// - [x] "this weekend"
+/// - [x] "next weekend"
+/// - [x] "in 3 days", "in three days"
+/// - [x] "in a couple of days", "in a few days"
+/// - [x] "soon", "sometime next week" (see [`FlexibleDateRange`])
+/// - [x] "from monday to friday", "june 5 - june 9"
+/// - [x] "mid next week", "early next month"
+/// - [x] "halfway through q2", "the middle of next week", "early next quarter"
+/// - [x] "the end of the year", "the beginning of next month" (see [`SectionPolicy`])
+/// - [x] "weekend after next", "the week after next"
+/// - [x] "tomorrow at 5" (see [`FlexibleDateTime`]), and all-day dates like "tomorrow"
+/// - [x] resolving the first date or date time in one call (see [`parse_and_resolve`])
+/// - [x] resolving a batch of texts against one moment (see [`resolve_batch`])
+/// - [x] "for 90 minutes", "2 weeks" (see [`FlexibleDuration`])
+/// - [x] "an hour and a half", "half a day"
+/// - [x] "friday around 3", "tomorrow at noonish"
+/// - [x] "around the 15th", "about a week from now" (see [`ParseOptions::approximate_date_tolerance`])
+/// - [x] "tomorrow after lunch", "friday before work" (see [`Keywords`])
+/// - [x] "cob friday", "tomorrow at close of business"
+/// - [x] "first thing tomorrow", "end of play today" (see [`Idiom`])
+/// - [x] "friday in the morning", "tomorrow at 10 at night"
+/// - [x] "tonight", "this morning", "this afternoon", "this evening"
+/// - [x] weekdays within the current week only (see [`WeekdayScope`])
+/// - [x] "next friday", as either the next one or the one next week (see [`WeekdayPolicy`])
+/// - [x] "this saturday", "this tue"
+/// - [x] "tomorrow at 5 p.m."
+/// - [x] "5:30pm", "at 17:00", and times on their own (see [`FlexibleTime`])
+/// - [x] "tomorrow 2pm", "fri 14:00"
+/// - [x] "9am on the 3rd", "at 5 tomorrow"
+/// - [x] "at 5" on its own, as today or tomorrow (see [`TimeOnlyPolicy`])
+/// - [x] "12am", "12 noon", "midnight"
+/// - [x] "friday at midnight", "midnight tomorrow" (see [`MidnightPolicy`])
+/// - [x] "every monday at 9am", "every morning at 8" (see [`Recurrence`])
+/// - [x] "every year on march 5", "every march 5"
+/// - [x] "every week starting friday", "every day from tomorrow"
+/// - [x] "every friday until june 1", "every day for 5 weeks" (see [`RecurrenceEnd`])
+/// - [x] "every monday at 9 and every friday at 5" (see [`Schedule`])
+/// - [x] "every workday at 9 except holidays", "every weekday" (see [`BusinessCalendar`])
+/// - [x] "each monday"
+/// - [x] "weekly on thursdays", "monthly on the 15th"
+/// - [x] "every 3rd day", "every 2nd week"
+/// - [x] "every other week", "every 2 weeks", "every other monday"
+/// - [x] "in 3 days at noon"
+/// - [x] "3 weeks from friday"
+/// - [x] "2 weeks into next quarter", "3 days into next month"
+/// - [x] "the monday of week 42"
+/// - [x] "fri w42", "tue cw3"
+/// - [x] "tuesday or wednesday" (see [`FlexibleDate::find_interpretations_in_str`])
+/// - [x] "mon, wed and fri" (see [`DateList`])
+/// - [x] "the next two mondays", "the next few weekends"
+/// - [x] "llamar a mamá tomorrow" (see [`ParseOptions::extra_locales`])
+/// - [x] only looking after "due" (see [`ParseOptions::only_after_triggers`])
+/// - [x] catching "tomorrow, 2024-01-27" when they disagree (see [`FlexibleDate::find_and_check_in_str`])
+/// - [x] "not this friday, the next one", "the friday after this one"
+/// - [x] "in 3 wks", "2d", "1mo"
+/// - [x] "for the next two weeks", "over the next 3 days", "within 3 days"
+/// - [x] "this week" (see [`ThisWeekPolicy`])
+/// - [x] "anytime before friday", "after the 15th" (see [`OpenDateRange`])
+/// - [x] "in 5 days skipping weekends", "in 5 business days"
+/// - [x] "27.09.2024", and "27.IX.2024" with [`ParseOptions::roman_numeral_months`]
+/// - [x] "2024-01-27"
+/// - [x] turning off individual rules (see [`Rule`])
+/// - [x] scoring how sure each match is (see [`Parsed::confidence`])
+/// - [x] checking a corpus of phrases against the parser (see [`Corpus`])
+/// - [x] reporting which of these are done (see [`Coverage`])
+/// - [x] ignoring numbers like "3/4 cup", "v1.27", "1.2.3", and "$15.00"
+/// - [x] "due:fri", `due:"in 3 days"` (see [`KeyedDate`])
+/// - [x] "📅 2024-01-27", "🔁 every week" with the `obsidian` feature
+/// - [x] "the twenty-seventh", "every march twenty-seventh"
+/// - [x] "the 15th of next month", "the 5th next month", "the 1st after next"
+/// - [x] "the last weekday of the month", "last business day of next month"
+/// - [x] "in 2 weeks", "in two weeks"
+/// - [x] "2 weeks from now"
+/// - [x] "in four months"
+/// - [x] "in one year"
+/// - [x] "next month", "next year"
+/// - [x] "january 27", "jan 27", "the 27th of january"
+/// - [x] "01/27", "01/27/2024", and "27/01" with [`DateOrder::DayFirst`]
+/// - [ ] "jan 27 2024"
+/// - [x] "2026", "in 2026", "by 2027" (see [`YearPolicy`])
+/// - [x] "march next year", "next year in march"
+/// - [x] "27th", "3rd"
+/// - [x] "mid january", "early march", "late june"
+/// - [x] "mid jan"
+/// - [x] "later this week", "end of week", "end of the week"
+/// - [x] "the first week of march", "the last week of the month" (see [`Locale::week_start`])
+/// - [x] "two weeks from tomorrow", "3 days after friday", "a week from monday"
+/// - [x] borrowing the matched text from the input (see [`BorrowedParsed`])
+/// - [x] the matched text as it was written, capitals and all (see [`Parsed::text`])
+/// - [x] suggesting fixes for misspelled dates like "tomorow" (see [`FlexibleDate::suggest_in_str`])
+/// - [x] reading misspelled dates like "tommorow" (see [`ParseOptions::typo_tolerance`])
+/// - [x] how precise each result is, from the year down to the minute (see [`Precision`])
+/// - [x] completing dates as they're typed, like "to" (see [`FlexibleDate::completions`])
+/// - [x] telling when "tom", "tomorrow", and "2023-10-09" are the same date (see [`CanonicalKey`])
+/// - [x] highlighting the date in a line as it's typed (see [`LiveParser`])
+/// - [x] reporting impossible dates like "feb 30" without stopping the scan (see
+///   [`FlexibleDate::scan_str`])
+/// - [x] limiting how much text is scanned, and cancelling a scan (see
+///   [`ParseOptions::max_scan_len`] and [`CancelToken`])
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum FlexibleDate {
     Today,
     Tomorrow,
+
+    /// The date for a time given on its own, eg. "at 5" with [`TimeOnlyPolicy::Upcoming`]. As a
+    /// [`FlexibleDateTime`], it's today if the time is still to come, and tomorrow otherwise. On
+    /// its own, it's today.
+    TodayOrTomorrow,
     Weekday(Weekday),
+    DaysFromNow(u32),
+
+    /// Some number of business days after today, eg. "in 5 days skipping weekends". Which days
+    /// are business days depends on the [`BusinessCalendar`] it's resolved with.
+    BusinessDaysFromNow(u32),
+
+    /// A number of weeks from today, eg. "in 2 weeks" or "2 weeks from now".
+    WeeksFromNow(u32),
+
+    /// A number of months from today, eg. "in four months". When that month is too short for
+    /// today's day of the month, it resolves to the month's last day, so a month from January 31
+    /// is the end of February.
+    MonthsFromNow(u32),
+
+    /// A number of years from today, eg. "in one year". A year from February 29 is February 28.
+    YearsFromNow(u32),
+
+    /// The first day of next month, eg. "next month".
+    NextMonth,
+
+    /// The first day of next year, eg. "next year".
+    NextYear,
+
+    /// Some days after today, but no later than the end of the week, eg. "later this week" with
+    /// [`ParseOptions::later_this_week_days`] days.
+    LaterThisWeek(u32),
+
+    /// The first business day starting with today, eg. "this week" with
+    /// [`ThisWeekPolicy::NextWorkday`].
+    NextBusinessDay,
+    StartOf(Period),
+    EndOf(Period),
+    SegmentOf(Segment, Period),
+
+    /// A rough part of a month, eg. "mid january" or "early march". Early, mid, and late are the
+    /// 5th, 15th, and 25th, in the current year or the next one if that day has passed.
+    SegmentOfMonth(Segment, Month),
+
+    /// Some amount of time after another date, eg. "3 weeks from friday", "two weeks from
+    /// tomorrow", or "2 weeks into next quarter".
+    Offset {
+        amount: u32,
+        unit: DateUnit,
+        anchor: Box<FlexibleDate>,
+    },
+
+    /// The first occurrence of a weekday after today, so never today itself, eg. "next friday"
+    /// with [`WeekdayPolicy::NextOccurrence`].
+    NextWeekday(Weekday),
+
+    /// A weekday within the current week, from Monday to Sunday, so it might have passed
+    /// already, eg. "this saturday", or "friday" with [`WeekdayScope::CurrentWeek`].
+    WeekdayThisWeek(Weekday),
+
+    /// A weekday within a numbered ISO week, eg. "the monday of week 42". Resolves within the
+    /// current ISO year, or the next one if that day has passed.
+    WeekdayOfWeek {
+        weekday: Weekday,
+        week: u32,
+    },
+
+    /// A day of the month, eg. "the twenty-seventh". Resolves within the current month, or the
+    /// next one if that day has passed. Days past the end of a month resolve to its last day.
+    DayOfMonth(u32),
+
+    /// The day halfway through a period, eg. "halfway through next month" or "the middle of next
+    /// week".
+    MidpointOf(Period),
+
+    /// The day halfway through a numbered quarter of the year, eg. "halfway through q2". Resolves
+    /// within this year, or next year if that quarter has ended.
+    MidpointOfQuarter(u32),
+
+    /// A day of a month counted from the current one, eg. "the 5th of next month" has an `offset`
+    /// of 1. Days past the end of the month resolve to its last day.
+    DayInMonth {
+        day: u32,
+        offset: u32,
+    },
+
+    /// The last business day of a period, eg. "the last weekday of the month". Which days are
+    /// business days depends on the [`BusinessCalendar`] it's resolved with.
+    LastBusinessDayOf(Period),
+
+    /// The day before another date, eg. the last day of "the next 3 days" with
+    /// [`WindowEnd::Before`].
+    DayBefore(Box<FlexibleDate>),
+
+    /// The last time a weekday comes on or before another date, eg. the first day of "the last
+    /// week of the month", which is the locale's [`week_start`](Locale::week_start) on or before
+    /// the month's last day.
+    WeekdayOnOrBefore {
+        weekday: Weekday,
+        anchor: Box<FlexibleDate>,
+    },
+
+    /// The Saturday of the weekend we're in or coming up on, eg. "this weekend". On a Sunday
+    /// this resolves to today, since it's still the weekend.
+    ThisWeekend,
+
+    /// The Saturday of next week's weekend, eg. "next weekend". From a Saturday or Sunday, this
+    /// is the weekend after the current one.
+    NextWeekend,
+
+    /// A day of a particular month, eg. "january 27" or "jan 27". Resolves within the current
+    /// year, or the next one if that day has passed. February 29 resolves to the next leap year's.
+    MonthDay {
+        month: Month,
+        day: u32,
+    },
+
+    /// A specific day on the calendar, eg. "27.09.2024".
+    CalendarDate {
+        year: i32,
+        month: Month,
+        day: u32,
+    },
+
+    /// The first day of a numbered year, eg. "2026" or "in 2026" with [`YearPolicy::FirstDay`].
+    StartOfYear(i32),
+
+    /// The last day of a numbered year, eg. "by 2026" with [`YearPolicy::LastDay`].
+    EndOfYear(i32),
+
+    /// The first day of a month in a year counted from the current one, eg. "march next year" or
+    /// "next year in march" has an `offset` of 1.
+    MonthInYear {
+        month: Month,
+        offset: u32,
+    },
+}
+
+/// A unit of time that dates can be offset by.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DateUnit {
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl DateUnit {
+    /// Adds `amount` of this unit to `date`. Adding months or years to a day that the resulting
+    /// month doesn't have (eg. one month after January 31) gives the last day of that month.
+    /// Fails (returns `None`) if the result is too far away for a [`NaiveDate`] to hold.
+    fn add_to(&self, date: NaiveDate, amount: u32) -> Option<NaiveDate> {
+        match self {
+            DateUnit::Day => date.checked_add_days(Days::new(amount.into())),
+            DateUnit::Week => date.checked_add_days(Days::new(7 * u64::from(amount))),
+            DateUnit::Month => date.checked_add_months(Months::new(amount)),
+            DateUnit::Year => date.checked_add_months(Months::new(amount.checked_mul(12)?)),
+        }
+    }
+}
+
+/// How finely a phrase pins down a point in time, from a whole year down to the minute. Finer
+/// precisions compare as greater, so `Precision::Day < Precision::Hour`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Precision {
+    Year,
+    Quarter,
+    Month,
+    Week,
+    Day,
+    Hour,
+    Minute,
 }
 
+/// A day of the week. It converts to and from [`chrono::Weekday`], and to and from the ISO 8601
+/// day numbers, where Monday is 1 and Sunday is 7.
+///
+/// ```rust
+/// # use smart_date::Weekday;
+/// # fn main() {
+/// assert_eq!(u8::from(Weekday::Monday), 1);
+/// assert_eq!(Weekday::try_from(7), Ok(Weekday::Sunday));
+/// assert!(Weekday::try_from(0).is_err());
+///
+/// let day: chrono::Weekday = Weekday::Friday.into();
+/// assert_eq!(day, chrono::Weekday::Fri);
+/// # }
+/// ```
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub enum Weekday {
     Monday,
@@ -73,6 +466,71 @@ impl From<ChronoWeekday> for Weekday {
     }
 }
 
+impl From<Weekday> for ChronoWeekday {
+    fn from(day: Weekday) -> Self {
+        match day {
+            Weekday::Monday => ChronoWeekday::Mon,
+            Weekday::Tuesday => ChronoWeekday::Tue,
+            Weekday::Wednesday => ChronoWeekday::Wed,
+            Weekday::Thursday => ChronoWeekday::Thu,
+            Weekday::Friday => ChronoWeekday::Fri,
+            Weekday::Saturday => ChronoWeekday::Sat,
+            Weekday::Sunday => ChronoWeekday::Sun,
+        }
+    }
+}
+
+/// The ISO 8601 number of the day, from 1 for Monday to 7 for Sunday.
+impl From<Weekday> for u8 {
+    fn from(day: Weekday) -> Self {
+        match day {
+            Weekday::Monday => 1,
+            Weekday::Tuesday => 2,
+            Weekday::Wednesday => 3,
+            Weekday::Thursday => 4,
+            Weekday::Friday => 5,
+            Weekday::Saturday => 6,
+            Weekday::Sunday => 7,
+        }
+    }
+}
+
+/// The day with the given ISO 8601 number, from 1 for Monday to 7 for Sunday. Fails for any
+/// other number.
+impl TryFrom<u8> for Weekday {
+    type Error = WeekdayNumberError;
+
+    fn try_from(number: u8) -> Result<Self, Self::Error> {
+        match number {
+            1 => Ok(Weekday::Monday),
+            2 => Ok(Weekday::Tuesday),
+            3 => Ok(Weekday::Wednesday),
+            4 => Ok(Weekday::Thursday),
+            5 => Ok(Weekday::Friday),
+            6 => Ok(Weekday::Saturday),
+            7 => Ok(Weekday::Sunday),
+            number => Err(WeekdayNumberError(number)),
+        }
+    }
+}
+
+/// A number that isn't the ISO 8601 number of a day of the week, which run from 1 for Monday to
+/// 7 for Sunday. See [`Weekday`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WeekdayNumberError(pub u8);
+
+impl fmt::Display for WeekdayNumberError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "there's no weekday numbered {}, only 1 (Monday) to 7 (Sunday)",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for WeekdayNumberError {}
+
 impl Weekday {
     fn week_index(&self) -> u64 {
         match self {
@@ -94,6 +552,71 @@ impl Weekday {
     }
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Month {
+    January,
+    February,
+    March,
+    April,
+    May,
+    June,
+    July,
+    August,
+    September,
+    October,
+    November,
+    December,
+}
+
+impl Month {
+    /// The month's number, from 1 (January) to 12 (December).
+    fn number(&self) -> u32 {
+        match self {
+            Month::January => 1,
+            Month::February => 2,
+            Month::March => 3,
+            Month::April => 4,
+            Month::May => 5,
+            Month::June => 6,
+            Month::July => 7,
+            Month::August => 8,
+            Month::September => 9,
+            Month::October => 10,
+            Month::November => 11,
+            Month::December => 12,
+        }
+    }
+
+    /// The month with the given number, from 1 (January) to 12 (December).
+    fn from_number(number: u32) -> Option<Month> {
+        [
+            Month::January,
+            Month::February,
+            Month::March,
+            Month::April,
+            Month::May,
+            Month::June,
+            Month::July,
+            Month::August,
+            Month::September,
+            Month::October,
+            Month::November,
+            Month::December,
+        ]
+        .into_iter()
+        .find(|month| month.number() == number)
+    }
+
+    /// The most days the month can have, counting February 29.
+    fn max_days(&self) -> u32 {
+        match self {
+            Month::February => 29,
+            Month::April | Month::June | Month::September | Month::November => 30,
+            _ => 31,
+        }
+    }
+}
+
 impl FlexibleDate {
     /// Parses a `FlexibleDate` from within a string. Fails (returns `None`) if the full string does
     /// not match a date.
@@ -110,16 +633,32 @@ impl FlexibleDate {
     ///
     /// let result3 = FlexibleDate::parse_from_str("go to the store today");
     /// assert_eq!(result3, None);
+    ///
+    /// // a year on its own is a date here, though it needs a word like "in" within other text
+    /// let result4 = FlexibleDate::parse_from_str("2026").unwrap();
+    /// assert_eq!(result4, FlexibleDate::StartOfYear(2026));
+    /// assert_eq!(FlexibleDate::find_and_parse_in_str("room 2026"), None);
     ///  # }
     /// ```
     #[must_use]
     pub fn parse_from_str(text: &str) -> Option<FlexibleDate> {
-        parse_flex_date_exact(text).ok().map(|(_, date)| date)
+        Self::parse_from_str_with_options(text, &ParseOptions::default())
+    }
+
+    /// Same as [`FlexibleDate::parse_from_str`], but with control over how the text is parsed.
+    #[must_use]
+    pub fn parse_from_str_with_options(text: &str, options: &ParseOptions) -> Option<FlexibleDate> {
+        parse_flex_date_exact(text, options)
+            .or_else(|_| parse_lone_year(text, options))
+            .ok()
+            .map(|(_, date)| date.data)
     }
 
     /// Finds and parses a `FlexibleDate` from within a string. The returned `Parsed<>` type contains
     /// the date that was parsed as well as the location of the matching substring in the input.
     ///
+    /// Only the date is matched, so "tomorrow at 3pm" gives just "tomorrow". Use
+    /// [`FlexibleDateTime::find_and_parse_in_str`] to match the time along with it.
     ///
     /// ```rust
     /// # use smart_date::FlexibleDate;
@@ -135,10 +674,264 @@ impl FlexibleDate {
     /// ```
     #[must_use]
     pub fn find_and_parse_in_str(text: &str) -> Option<Parsed<FlexibleDate>> {
-        parse_flex_date(text)
+        Self::find_and_parse_in_str_with_options(text, &ParseOptions::default())
+    }
+
+    /// Same as [`FlexibleDate::find_and_parse_in_str`], but with control over how the text is
+    /// parsed.
+    #[must_use]
+    pub fn find_and_parse_in_str_with_options(
+        text: &str,
+        options: &ParseOptions,
+    ) -> Option<Parsed<FlexibleDate>> {
+        parse_flex_date(text, options)
+    }
+
+    /// Finds and parses every `FlexibleDate` within a string, in the order they appear, for
+    /// inputs that mention more than one date.
+    ///
+    /// ```rust
+    /// # use smart_date::{FlexibleDate, Weekday};
+    /// # fn main() {
+    /// let results = FlexibleDate::find_and_parse_all_in_str("meet tomorrow or friday");
+    /// assert_eq!(results.len(), 2);
+    /// assert_eq!(results[0].data, FlexibleDate::Tomorrow);
+    /// assert_eq!(results[1].data, FlexibleDate::Weekday(Weekday::Friday));
+    /// assert_eq!(results[1].range, (17..23));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn find_and_parse_all_in_str(text: &str) -> Vec<Parsed<FlexibleDate>> {
+        Self::find_and_parse_all_in_str_with_options(text, &ParseOptions::default())
+    }
+
+    /// Same as [`FlexibleDate::find_and_parse_all_in_str`], but with control over how the text
+    /// is parsed.
+    #[must_use]
+    pub fn find_and_parse_all_in_str_with_options(
+        text: &str,
+        options: &ParseOptions,
+    ) -> Vec<Parsed<FlexibleDate>> {
+        parse_all_flex_dates(text, options)
+    }
+
+    /// Finds every way that the first date phrase within a string could be read. This is usually
+    /// a single date, but will be several when the input offers alternatives, as in "tuesday or
+    /// wednesday".
+    ///
+    /// ```rust
+    /// # use smart_date::{FlexibleDate, Weekday};
+    /// # fn main() {
+    /// let result = FlexibleDate::find_interpretations_in_str("meet tuesday or wednesday").unwrap();
+    /// assert_eq!(
+    ///     result.data.dates,
+    ///     vec![
+    ///         FlexibleDate::Weekday(Weekday::Tuesday),
+    ///         FlexibleDate::Weekday(Weekday::Wednesday),
+    ///     ]
+    /// );
+    /// assert!(result.data.from_alternatives);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn find_interpretations_in_str(text: &str) -> Option<Parsed<Interpretations>> {
+        Self::find_interpretations_in_str_with_options(text, &ParseOptions::default())
+    }
+
+    /// Same as [`FlexibleDate::find_interpretations_in_str`], but with control over how the text
+    /// is parsed.
+    #[must_use]
+    pub fn find_interpretations_in_str_with_options(
+        text: &str,
+        options: &ParseOptions,
+    ) -> Option<Parsed<Interpretations>> {
+        parse_interpretations(text, options)
+    }
+
+    /// Finds every date phrase within a string, carrying on past phrases that are written like
+    /// dates but don't name a real day. Those are reported as [`Diagnostic`]s instead.
+    ///
+    /// ```rust
+    /// # use smart_date::{DateProblem, FlexibleDate, Month};
+    /// # fn main() {
+    /// let scan = FlexibleDate::scan_str("feb 30 or mar 3");
+    /// assert_eq!(
+    ///     scan.diagnostics[0].problem,
+    ///     DateProblem::NoSuchDay {
+    ///         year: None,
+    ///         month: Month::February,
+    ///         day: 30,
+    ///     }
+    /// );
+    /// assert_eq!(scan.diagnostics[0].range, (0..6));
+    ///
+    /// assert_eq!(
+    ///     scan.dates[0].data,
+    ///     FlexibleDate::MonthDay {
+    ///         month: Month::March,
+    ///         day: 3,
+    ///     }
+    /// );
+    /// assert_eq!(scan.dates[0].range, (10..15));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn scan_str(text: &str) -> Scan {
+        Self::scan_str_with_options(text, &ParseOptions::default())
+    }
+
+    /// Same as [`FlexibleDate::scan_str`], but with control over how the text is parsed.
+    #[must_use]
+    pub fn scan_str_with_options(text: &str, options: &ParseOptions) -> Scan {
+        let (dates, problems) = scan_flex_dates(text, options);
+        let diagnostics = problems
+            .into_iter()
+            .map(|problem| Diagnostic {
+                range: problem.range,
+                problem: problem.data,
+            })
+            .collect();
+        Scan { dates, diagnostics }
+    }
+
+    /// Looks for a word that's probably a date word written wrong, for error messages when no
+    /// date was found. The suggestion is the closest word that would make a date if it were
+    /// spelled differently or written out in full. Words that are already part of a date are
+    /// left alone.
+    ///
+    /// ```rust
+    /// # use smart_date::FlexibleDate;
+    /// # fn main() {
+    /// let text = "call mom tomorow";
+    /// assert_eq!(FlexibleDate::find_and_parse_in_str(text), None);
+    ///
+    /// let suggestion = FlexibleDate::suggest_in_str(text).unwrap();
+    /// assert_eq!(suggestion.range, (9..16));
+    /// assert_eq!(suggestion.replacement, "tomorrow");
+    /// assert_eq!(suggestion.date.data, FlexibleDate::Tomorrow);
+    /// assert_eq!(
+    ///     suggestion.to_string(),
+    ///     "did you mean \"tomorrow\" instead of \"tomorow\"?"
+    /// );
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn suggest_in_str(text: &str) -> Option<Suggestion> {
+        Self::suggest_in_str_with_options(text, &ParseOptions::default())
+    }
+
+    /// Same as [`FlexibleDate::suggest_in_str`], but with control over how the text is parsed.
+    #[must_use]
+    pub fn suggest_in_str_with_options(text: &str, options: &ParseOptions) -> Option<Suggestion> {
+        suggest_date_word(text, options)
+    }
+
+    /// The ways that the word being typed at the end of `prefix` could be finished to make a
+    /// date, for autocompletion as the user types. The likeliest come first: the ones whose
+    /// dates have the most [confidence](Parsed::confidence), and then the shortest.
+    ///
+    /// ```rust
+    /// # use smart_date::FlexibleDate;
+    /// # fn main() {
+    /// let completions = FlexibleDate::completions("call mom to");
+    /// assert_eq!(completions[0].replacement, "today");
+    /// assert_eq!(completions[0].date.data, FlexibleDate::Today);
+    /// assert_eq!(completions[1].replacement, "tomorrow");
+    ///
+    /// // the word being replaced
+    /// assert_eq!(completions[1].range, (9..11));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn completions(prefix: &str) -> Vec<Suggestion> {
+        Self::completions_with_options(prefix, &ParseOptions::default())
     }
 
-    /// Converts the `FlexibleDate` into a [`NaiveDate`].
+    /// Same as [`FlexibleDate::completions`], but with control over how the text is parsed.
+    #[must_use]
+    pub fn completions_with_options(prefix: &str, options: &ParseOptions) -> Vec<Suggestion> {
+        complete_date_word(prefix, options)
+    }
+
+    /// Finds the first date phrase within a string, like [`FlexibleDate::find_and_parse_in_str`],
+    /// and checks it against the rest of the string. If a phrase relative to today and a calendar
+    /// date both appear but land on different days, the result carries a [`DateConflict`].
+    ///
+    /// ```rust
+    /// # use smart_date::FlexibleDate;
+    /// # fn main() {
+    /// let today = chrono::NaiveDate::parse_from_str("2024-01-25", "%Y-%m-%d").unwrap();
+    ///
+    /// let result = FlexibleDate::find_and_check_in_str("call tomorrow, 2024-01-27", today).unwrap();
+    /// assert_eq!(result.parsed.data, FlexibleDate::Tomorrow);
+    /// assert_eq!(result.conflict.unwrap().absolute, (15..25));
+    ///
+    /// let result = FlexibleDate::find_and_check_in_str("call tomorrow, 2024-01-26", today).unwrap();
+    /// assert_eq!(result.conflict, None);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn find_and_check_in_str(text: &str, today: NaiveDate) -> Option<CheckedDate> {
+        Self::find_and_check_in_str_with_options(text, today, &ParseOptions::default()).ok()?
+    }
+
+    /// Same as [`FlexibleDate::find_and_check_in_str`], but with control over how the text is
+    /// parsed.
+    ///
+    /// # Errors
+    /// With [`ParseOptions::strict_conflicts`] set, returns the [`DateConflict`] as an error
+    /// rather than alongside the result.
+    pub fn find_and_check_in_str_with_options(
+        text: &str,
+        today: NaiveDate,
+        options: &ParseOptions,
+    ) -> Result<Option<CheckedDate>, DateConflict> {
+        let mut dates = parse_all_flex_dates(text, options).into_iter();
+        let Some(parsed) = dates.next() else {
+            return Ok(None);
+        };
+
+        let resolve = |parsed: &Parsed<FlexibleDate>| parsed.data.clone().into_naive_date(today);
+        let conflict = dates
+            .find(|other| {
+                parsed.data.is_absolute() != other.data.is_absolute()
+                    && resolve(&parsed) != resolve(other)
+            })
+            .map(|other| {
+                let (relative, absolute) = if parsed.data.is_absolute() {
+                    (&other, &parsed)
+                } else {
+                    (&parsed, &other)
+                };
+                DateConflict {
+                    relative: relative.range.clone(),
+                    relative_date: resolve(relative),
+                    absolute: absolute.range.clone(),
+                    absolute_date: resolve(absolute),
+                }
+            });
+
+        match conflict {
+            Some(conflict) if options.strict_conflicts => Err(conflict),
+            conflict => Ok(Some(CheckedDate { parsed, conflict })),
+        }
+    }
+
+    /// Whether the date names a day on the calendar, rather than a day relative to today.
+    #[must_use]
+    pub fn is_absolute(&self) -> bool {
+        matches!(
+            self,
+            FlexibleDate::CalendarDate { .. }
+                | FlexibleDate::MonthDay { .. }
+                | FlexibleDate::StartOfYear(_)
+                | FlexibleDate::EndOfYear(_)
+        )
+    }
+
+    /// Converts the `FlexibleDate` into a [`NaiveDate`]. Dates too far away for a [`NaiveDate`]
+    /// to hold, like "in 4000000000 days", give [`NaiveDate::MAX`]; use
+    /// [`FlexibleDate::checked_into_naive_date`] to tell them apart.
     ///
     /// ```rust
     /// # use smart_date::FlexibleDate;
@@ -166,14 +959,200 @@ impl FlexibleDate {
     /// ```
     #[must_use]
     pub fn into_naive_date(self, today: NaiveDate) -> NaiveDate {
-        match self {
-            FlexibleDate::Today => today,
+        self.into_naive_date_with_calendar(today, &Weekdays)
+    }
+
+    /// Same as [`FlexibleDate::into_naive_date`], but with control over which days count as
+    /// business days.
+    #[must_use]
+    pub fn into_naive_date_with_calendar(
+        self,
+        today: NaiveDate,
+        calendar: &dyn BusinessCalendar,
+    ) -> NaiveDate {
+        self.checked_into_naive_date_with_calendar(today, calendar)
+            .unwrap_or(NaiveDate::MAX)
+    }
+
+    /// Same as [`FlexibleDate::into_naive_date`], but fails (returns `None`) if the date is too
+    /// far away for a [`NaiveDate`] to hold.
+    ///
+    /// ```rust
+    /// # use smart_date::FlexibleDate;
+    /// # fn main() {
+    /// let today = chrono::NaiveDate::parse_from_str("2023-10-08", "%Y-%m-%d").unwrap();
+    ///
+    /// let date = FlexibleDate::parse_from_str("in 3 days").unwrap();
+    /// assert_eq!(date.checked_into_naive_date(today).unwrap().to_string(), "2023-10-11");
+    ///
+    /// let date = FlexibleDate::parse_from_str("in 4000000000 days").unwrap();
+    /// assert_eq!(date.checked_into_naive_date(today), None);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn checked_into_naive_date(self, today: NaiveDate) -> Option<NaiveDate> {
+        self.checked_into_naive_date_with_calendar(today, &Weekdays)
+    }
+
+    /// Same as [`FlexibleDate::checked_into_naive_date`], but with control over which days count
+    /// as business days.
+    #[must_use]
+    #[allow(clippy::too_many_lines)] // there's an arm for each kind of date
+    pub fn checked_into_naive_date_with_calendar(
+        self,
+        today: NaiveDate,
+        calendar: &dyn BusinessCalendar,
+    ) -> Option<NaiveDate> {
+        let date = match self {
+            FlexibleDate::Today | FlexibleDate::TodayOrTomorrow => today,
             FlexibleDate::Tomorrow => today + Days::new(1),
             FlexibleDate::Weekday(day) => {
                 let weekday: Weekday = today.weekday().into();
                 today + Days::new(weekday.days_until(&day))
             }
-        }
+            FlexibleDate::NextWeekday(day) => {
+                let weekday: Weekday = today.weekday().into();
+                match weekday.days_until(&day) {
+                    0 => today + Days::new(7),
+                    days => today + Days::new(days),
+                }
+            }
+            FlexibleDate::WeekdayThisWeek(day) => {
+                let this_week = Period {
+                    unit: PeriodUnit::Week,
+                    offset: 0,
+                };
+                this_week.first_day(today) + Days::new(day.week_index())
+            }
+            FlexibleDate::DaysFromNow(days) => today.checked_add_days(Days::new(days.into()))?,
+            FlexibleDate::WeeksFromNow(weeks) => {
+                today.checked_add_days(Days::new(7 * u64::from(weeks)))?
+            }
+            FlexibleDate::MonthsFromNow(months) => today.checked_add_months(Months::new(months))?,
+            FlexibleDate::YearsFromNow(years) => {
+                today.checked_add_months(Months::new(years.checked_mul(12)?))?
+            }
+            FlexibleDate::NextMonth => Period {
+                unit: PeriodUnit::Month,
+                offset: 1,
+            }
+            .first_day(today),
+            FlexibleDate::NextYear => {
+                NaiveDate::from_ymd_opt(today.year() + 1, 1, 1).unwrap_or(today)
+            }
+            FlexibleDate::BusinessDaysFromNow(days) => add_business_days(today, days, calendar)?,
+            FlexibleDate::LaterThisWeek(days) => {
+                let end_of_week = Period {
+                    unit: PeriodUnit::Week,
+                    offset: 0,
+                }
+                .last_day(today);
+                (today + Days::new(days.into())).min(end_of_week)
+            }
+            FlexibleDate::NextBusinessDay => business_day_on_or_after(today, calendar),
+            FlexibleDate::StartOf(period) => period.first_day(today),
+            FlexibleDate::EndOf(period) => period.last_day(today),
+            FlexibleDate::SegmentOf(segment, period) => period.segment_day(&segment, today),
+            FlexibleDate::SegmentOfMonth(segment, month) => {
+                let day = match segment {
+                    Segment::Early => 5,
+                    Segment::Mid => 15,
+                    Segment::Late => 25,
+                };
+                FlexibleDate::MonthDay { month, day }.checked_into_naive_date(today)?
+            }
+            FlexibleDate::Offset {
+                amount,
+                unit,
+                anchor,
+            } => unit.add_to(
+                anchor.checked_into_naive_date_with_calendar(today, calendar)?,
+                amount,
+            )?,
+            FlexibleDate::WeekdayOfWeek { weekday, week } => {
+                // every week number (even 53) comes around again within a few years
+                let this_year = today.iso_week().year();
+                (this_year..this_year + 10)
+                    .filter_map(|year| NaiveDate::from_isoywd_opt(year, week, ChronoWeekday::Mon))
+                    .map(|monday| monday + Days::new(weekday.week_index()))
+                    .find(|date| *date >= today)
+                    .unwrap_or(today)
+            }
+            FlexibleDate::DayOfMonth(day) => {
+                let this_month = Period {
+                    unit: PeriodUnit::Month,
+                    offset: 0,
+                };
+                let next_month = Period {
+                    offset: 1,
+                    ..this_month.clone()
+                };
+                let day_in = |month: Period| {
+                    let last_day = month.last_day(today);
+                    last_day.with_day(day).unwrap_or(last_day)
+                };
+                let date = day_in(this_month);
+                if date < today {
+                    day_in(next_month)
+                } else {
+                    date
+                }
+            }
+            FlexibleDate::MidpointOf(period) => period.midpoint(today),
+            FlexibleDate::MidpointOfQuarter(quarter) => {
+                next_quarter_numbered(quarter, today).midpoint(today)
+            }
+            FlexibleDate::DayInMonth { day, offset } => {
+                let last_day = Period {
+                    unit: PeriodUnit::Month,
+                    offset,
+                }
+                .last_day(today);
+                last_day.with_day(day).unwrap_or(last_day)
+            }
+            FlexibleDate::LastBusinessDayOf(period) => {
+                business_day_on_or_before(period.last_day(today), calendar)
+            }
+            FlexibleDate::DayBefore(date) => date
+                .checked_into_naive_date_with_calendar(today, calendar)?
+                .pred_opt()?,
+            FlexibleDate::WeekdayOnOrBefore { weekday, anchor } => {
+                let date = anchor.checked_into_naive_date_with_calendar(today, calendar)?;
+                date.checked_sub_days(Days::new(weekday.days_until(&date.weekday().into())))?
+            }
+            FlexibleDate::ThisWeekend => {
+                let this_weekend = Period {
+                    unit: PeriodUnit::Weekend,
+                    offset: 0,
+                };
+                this_weekend.first_day(today).max(today)
+            }
+            FlexibleDate::NextWeekend => Period {
+                unit: PeriodUnit::Weekend,
+                offset: 1,
+            }
+            .first_day(today),
+            FlexibleDate::MonthDay { month, day } => {
+                // february 29 comes around again within eight years
+                (today.year()..today.year() + 9)
+                    .filter_map(|year| NaiveDate::from_ymd_opt(year, month.number(), day))
+                    .find(|date| *date >= today)
+                    .unwrap_or(today)
+            }
+            FlexibleDate::CalendarDate { year, month, day } => {
+                NaiveDate::from_ymd_opt(year, month.number(), day).unwrap_or(today)
+            }
+            FlexibleDate::StartOfYear(year) => NaiveDate::from_ymd_opt(year, 1, 1).unwrap_or(today),
+            FlexibleDate::EndOfYear(year) => NaiveDate::from_ymd_opt(year, 12, 31).unwrap_or(today),
+            FlexibleDate::MonthInYear { month, offset } => {
+                let year = Period {
+                    unit: PeriodUnit::Year,
+                    offset,
+                };
+                year.first_day(today) + Months::new(month.number() - 1)
+            }
+        };
+        Some(date)
     }
 }
 
@@ -188,4 +1167,365 @@ mod weekday_tests {
         assert_eq!(today.days_until(&Weekday::Tuesday), 0);
         assert_eq!(today.days_until(&Weekday::Monday), 6);
     }
+
+    #[test]
+    fn test_conversions() {
+        for day in [
+            ChronoWeekday::Mon,
+            ChronoWeekday::Wed,
+            ChronoWeekday::Sat,
+            ChronoWeekday::Sun,
+        ] {
+            let weekday = Weekday::from(day);
+            assert_eq!(ChronoWeekday::from(weekday.clone()), day);
+            // the numbers agree with chrono's
+            let number = u8::from(weekday.clone());
+            assert_eq!(u32::from(number), day.number_from_monday());
+            assert_eq!(Weekday::try_from(number), Ok(weekday));
+        }
+
+        assert_eq!(Weekday::try_from(0), Err(WeekdayNumberError(0)));
+        assert_eq!(Weekday::try_from(8), Err(WeekdayNumberError(8)));
+        assert_eq!(
+            WeekdayNumberError(8).to_string(),
+            "there's no weekday numbered 8, only 1 (Monday) to 7 (Sunday)"
+        );
+    }
+}
+
+#[cfg(test)]
+mod flexible_date_tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_find_and_check_in_str() {
+        let today = NaiveDate::parse_from_str("2024-01-25", "%Y-%m-%d").unwrap();
+        let options = ParseOptions {
+            strict_conflicts: true,
+            ..ParseOptions::default()
+        };
+
+        let conflict = FlexibleDate::find_and_check_in_str_with_options(
+            "on 2024-01-27 aka tomorrow",
+            today,
+            &ParseOptions::default(),
+        )
+        .unwrap()
+        .unwrap()
+        .conflict
+        .unwrap();
+        assert_eq!(conflict.relative, (18..26));
+        assert_eq!(conflict.relative_date.to_string(), "2024-01-26");
+        assert_eq!(conflict.absolute, (3..13));
+
+        let result = FlexibleDate::find_and_check_in_str_with_options(
+            "tomorrow, 2024-01-27",
+            today,
+            &options,
+        );
+        assert!(result.is_err());
+
+        // alternatives aren't conflicts
+        let result =
+            FlexibleDate::find_and_check_in_str_with_options("tuesday or friday", today, &options);
+        assert_eq!(result.unwrap().unwrap().conflict, None);
+
+        let result = FlexibleDate::find_and_check_in_str_with_options("no dates", today, &options);
+        assert_eq!(result, Ok(None));
+    }
+
+    #[test]
+    fn test_day_in_month_into_naive_date() {
+        let today = NaiveDate::parse_from_str("2024-01-20", "%Y-%m-%d").unwrap();
+
+        let date = FlexibleDate::DayInMonth { day: 5, offset: 1 };
+        assert_eq!(date.into_naive_date(today).to_string(), "2024-02-05");
+
+        // past days this month don't roll over
+        let date = FlexibleDate::DayInMonth { day: 5, offset: 0 };
+        assert_eq!(date.into_naive_date(today).to_string(), "2024-01-05");
+
+        let date = FlexibleDate::DayInMonth { day: 31, offset: 1 };
+        assert_eq!(date.into_naive_date(today).to_string(), "2024-02-29");
+    }
+
+    #[test]
+    fn test_weekends_into_naive_date() {
+        let resolve = |date: FlexibleDate, today| {
+            let today = NaiveDate::parse_from_str(today, "%Y-%m-%d").unwrap();
+            date.into_naive_date(today).to_string()
+        };
+
+        // a Wednesday
+        assert_eq!(
+            resolve(FlexibleDate::ThisWeekend, "2023-10-11"),
+            "2023-10-14"
+        );
+        assert_eq!(
+            resolve(FlexibleDate::NextWeekend, "2023-10-11"),
+            "2023-10-21"
+        );
+
+        // a Saturday
+        assert_eq!(
+            resolve(FlexibleDate::ThisWeekend, "2023-10-14"),
+            "2023-10-14"
+        );
+        assert_eq!(
+            resolve(FlexibleDate::NextWeekend, "2023-10-14"),
+            "2023-10-21"
+        );
+
+        // a Sunday
+        assert_eq!(
+            resolve(FlexibleDate::ThisWeekend, "2023-10-15"),
+            "2023-10-15"
+        );
+        assert_eq!(
+            resolve(FlexibleDate::NextWeekend, "2023-10-15"),
+            "2023-10-21"
+        );
+    }
+
+    #[test]
+    fn test_offset_into_naive_date() {
+        // a Sunday
+        let today = NaiveDate::parse_from_str("2023-10-08", "%Y-%m-%d").unwrap();
+
+        let date = FlexibleDate::Offset {
+            amount: 3,
+            unit: DateUnit::Week,
+            anchor: Box::new(FlexibleDate::Weekday(Weekday::Friday)),
+        };
+        assert_eq!(date.into_naive_date(today).to_string(), "2023-11-03");
+    }
+
+    #[test]
+    fn test_out_of_range_into_naive_date() {
+        let today = NaiveDate::parse_from_str("2023-10-08", "%Y-%m-%d").unwrap();
+
+        let date = FlexibleDate::find_and_parse_in_str("in 4000000000 days")
+            .unwrap()
+            .data;
+        assert_eq!(date, FlexibleDate::DaysFromNow(4_000_000_000));
+        assert_eq!(date.clone().checked_into_naive_date(today), None);
+        assert_eq!(date.into_naive_date(today), NaiveDate::MAX);
+
+        for text in [
+            "in 400000000 weeks",
+            "in 99999999 months",
+            "in 400000000 years",
+            "3000000000 days from friday",
+            "400000000 years after tomorrow",
+            "in 4000000000 days skipping weekends",
+            "in 99999999 business days",
+        ] {
+            let date = FlexibleDate::find_and_parse_in_str(text).unwrap().data;
+            assert_eq!(date.checked_into_naive_date(today), None, "{text}");
+        }
+    }
+
+    #[test]
+    fn test_months_and_years_from_now_into_naive_date() {
+        let today = NaiveDate::parse_from_str("2024-01-31", "%Y-%m-%d").unwrap();
+
+        // a month from the end of january is the end of february
+        let date = FlexibleDate::MonthsFromNow(1);
+        assert_eq!(date.into_naive_date(today).to_string(), "2024-02-29");
+        let date = FlexibleDate::MonthsFromNow(4);
+        assert_eq!(date.into_naive_date(today).to_string(), "2024-05-31");
+        let date = FlexibleDate::NextMonth;
+        assert_eq!(date.into_naive_date(today).to_string(), "2024-02-01");
+
+        let today = NaiveDate::parse_from_str("2024-02-29", "%Y-%m-%d").unwrap();
+
+        let date = FlexibleDate::YearsFromNow(1);
+        assert_eq!(date.into_naive_date(today).to_string(), "2025-02-28");
+        let date = FlexibleDate::NextYear;
+        assert_eq!(date.into_naive_date(today).to_string(), "2025-01-01");
+    }
+
+    #[test]
+    fn test_month_offset_into_naive_date() {
+        let today = NaiveDate::parse_from_str("2024-01-31", "%Y-%m-%d").unwrap();
+
+        let date = FlexibleDate::Offset {
+            amount: 1,
+            unit: DateUnit::Month,
+            anchor: Box::new(FlexibleDate::Today),
+        };
+        assert_eq!(date.into_naive_date(today).to_string(), "2024-02-29");
+
+        let date = FlexibleDate::Offset {
+            amount: 1,
+            unit: DateUnit::Year,
+            anchor: Box::new(FlexibleDate::Today),
+        };
+        assert_eq!(date.into_naive_date(today).to_string(), "2025-01-31");
+    }
+
+    #[test]
+    fn test_month_day_into_naive_date() {
+        let today = NaiveDate::parse_from_str("2023-10-08", "%Y-%m-%d").unwrap();
+
+        let date = FlexibleDate::MonthDay {
+            month: Month::October,
+            day: 8,
+        };
+        assert_eq!(date.into_naive_date(today).to_string(), "2023-10-08");
+
+        let date = FlexibleDate::MonthDay {
+            month: Month::January,
+            day: 27,
+        };
+        assert_eq!(date.into_naive_date(today).to_string(), "2024-01-27");
+
+        let date = FlexibleDate::MonthDay {
+            month: Month::February,
+            day: 29,
+        };
+        let today = NaiveDate::parse_from_str("2024-03-01", "%Y-%m-%d").unwrap();
+        assert_eq!(date.into_naive_date(today).to_string(), "2028-02-29");
+    }
+
+    #[test]
+    fn test_year_into_naive_date() {
+        let today = NaiveDate::parse_from_str("2023-10-08", "%Y-%m-%d").unwrap();
+
+        let date = FlexibleDate::StartOfYear(2026);
+        assert_eq!(date.into_naive_date(today).to_string(), "2026-01-01");
+        let date = FlexibleDate::EndOfYear(2026);
+        assert_eq!(date.into_naive_date(today).to_string(), "2026-12-31");
+
+        let date = FlexibleDate::MonthInYear {
+            month: Month::March,
+            offset: 1,
+        };
+        assert_eq!(date.into_naive_date(today).to_string(), "2024-03-01");
+        let date = FlexibleDate::MonthInYear {
+            month: Month::January,
+            offset: 0,
+        };
+        assert_eq!(date.into_naive_date(today).to_string(), "2023-01-01");
+    }
+
+    #[test]
+    fn test_segment_of_month_into_naive_date() {
+        let today = NaiveDate::parse_from_str("2023-10-08", "%Y-%m-%d").unwrap();
+
+        let date = FlexibleDate::SegmentOfMonth(Segment::Late, Month::October);
+        assert_eq!(date.into_naive_date(today).to_string(), "2023-10-25");
+
+        // early october has passed, so it's next year's
+        let date = FlexibleDate::SegmentOfMonth(Segment::Early, Month::October);
+        assert_eq!(date.into_naive_date(today).to_string(), "2024-10-05");
+
+        let date = FlexibleDate::SegmentOfMonth(Segment::Mid, Month::January);
+        assert_eq!(date.into_naive_date(today).to_string(), "2024-01-15");
+    }
+
+    #[test]
+    fn test_weekday_this_week_into_naive_date() {
+        // a wednesday
+        let today = NaiveDate::parse_from_str("2024-01-24", "%Y-%m-%d").unwrap();
+
+        let date = FlexibleDate::WeekdayThisWeek(Weekday::Friday);
+        assert_eq!(date.into_naive_date(today).to_string(), "2024-01-26");
+        let date = FlexibleDate::WeekdayThisWeek(Weekday::Monday);
+        assert_eq!(date.into_naive_date(today).to_string(), "2024-01-22");
+        let date = FlexibleDate::WeekdayThisWeek(Weekday::Wednesday);
+        assert_eq!(date.into_naive_date(today), today);
+    }
+
+    #[test]
+    fn test_next_weekday_into_naive_date() {
+        // a friday
+        let today = NaiveDate::parse_from_str("2024-01-26", "%Y-%m-%d").unwrap();
+
+        let date = FlexibleDate::NextWeekday(Weekday::Friday);
+        assert_eq!(date.into_naive_date(today).to_string(), "2024-02-02");
+        let date = FlexibleDate::NextWeekday(Weekday::Saturday);
+        assert_eq!(date.into_naive_date(today).to_string(), "2024-01-27");
+    }
+
+    #[test]
+    fn test_later_this_week_into_naive_date() {
+        // a tuesday
+        let today = NaiveDate::parse_from_str("2024-01-23", "%Y-%m-%d").unwrap();
+        let date = FlexibleDate::LaterThisWeek(2);
+        assert_eq!(
+            date.clone().into_naive_date(today).to_string(),
+            "2024-01-25"
+        );
+
+        // a saturday, so it stops at sunday
+        let today = NaiveDate::parse_from_str("2024-01-27", "%Y-%m-%d").unwrap();
+        assert_eq!(date.into_naive_date(today).to_string(), "2024-01-28");
+    }
+
+    #[test]
+    fn test_day_of_month_into_naive_date() {
+        let today = NaiveDate::parse_from_str("2024-01-15", "%Y-%m-%d").unwrap();
+
+        let date = FlexibleDate::DayOfMonth(27);
+        assert_eq!(date.into_naive_date(today).to_string(), "2024-01-27");
+
+        let date = FlexibleDate::DayOfMonth(15);
+        assert_eq!(date.into_naive_date(today).to_string(), "2024-01-15");
+
+        let date = FlexibleDate::DayOfMonth(3);
+        assert_eq!(date.into_naive_date(today).to_string(), "2024-02-03");
+
+        // february doesn't have a 31st
+        let today = NaiveDate::parse_from_str("2024-02-02", "%Y-%m-%d").unwrap();
+        let date = FlexibleDate::DayOfMonth(31);
+        assert_eq!(date.into_naive_date(today).to_string(), "2024-02-29");
+    }
+
+    #[test]
+    fn test_last_business_day_into_naive_date() {
+        let today = NaiveDate::parse_from_str("2024-03-05", "%Y-%m-%d").unwrap();
+
+        // March 31 2024 was a Sunday
+        let date = FlexibleDate::LastBusinessDayOf(Period {
+            unit: PeriodUnit::Month,
+            offset: 0,
+        });
+        assert_eq!(date.into_naive_date(today).to_string(), "2024-03-29");
+
+        // April 30 2024 was a Tuesday
+        let date = FlexibleDate::LastBusinessDayOf(Period {
+            unit: PeriodUnit::Month,
+            offset: 1,
+        });
+        assert_eq!(date.into_naive_date(today).to_string(), "2024-04-30");
+    }
+
+    #[test]
+    fn test_weekday_of_week_into_naive_date() {
+        // a Sunday in ISO week 40
+        let today = NaiveDate::parse_from_str("2023-10-08", "%Y-%m-%d").unwrap();
+
+        let date = FlexibleDate::WeekdayOfWeek {
+            weekday: Weekday::Wednesday,
+            week: 42,
+        };
+        assert_eq!(date.into_naive_date(today).to_string(), "2023-10-18");
+
+        // week 14 has already passed this year
+        let date = FlexibleDate::WeekdayOfWeek {
+            weekday: Weekday::Wednesday,
+            week: 14,
+        };
+        assert_eq!(date.into_naive_date(today).to_string(), "2024-04-03");
+
+        // 2023 has no week 53, but 2026 does
+        let date = FlexibleDate::WeekdayOfWeek {
+            weekday: Weekday::Monday,
+            week: 53,
+        };
+        assert_eq!(date.into_naive_date(today).to_string(), "2026-12-28");
+    }
 }
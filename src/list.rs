@@ -0,0 +1,91 @@
+use crate::{
+    parser::{parse_date_list, parse_date_list_exact},
+    FlexibleDate, ParseOptions, Parsed,
+};
+use chrono::NaiveDate;
+
+/// Several separate dates, eg. "mon, wed and fri". Unlike a [`Recurrence`](crate::Recurrence),
+/// the dates only happen once each, which suits applications that create one task per date.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DateList {
+    /// The dates, in the order they were written.
+    pub dates: Vec<FlexibleDate>,
+}
+
+impl DateList {
+    /// Parses a `DateList` from a string. Fails (returns `None`) if the full string does not
+    /// match a list of dates.
+    ///
+    /// ```rust
+    /// # use smart_date::{DateList, FlexibleDate, Weekday};
+    /// # fn main() {
+    /// let result = DateList::parse_from_str("mon, wed and fri").unwrap();
+    /// assert_eq!(
+    ///     result.dates,
+    ///     vec![
+    ///         FlexibleDate::Weekday(Weekday::Monday),
+    ///         FlexibleDate::Weekday(Weekday::Wednesday),
+    ///         FlexibleDate::Weekday(Weekday::Friday),
+    ///     ]
+    /// );
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn parse_from_str(text: &str) -> Option<DateList> {
+        Self::parse_from_str_with_options(text, &ParseOptions::default())
+    }
+
+    /// Same as [`DateList::parse_from_str`], but with control over how the text is parsed.
+    #[must_use]
+    pub fn parse_from_str_with_options(text: &str, options: &ParseOptions) -> Option<DateList> {
+        parse_date_list_exact(text, options)
+            .ok()
+            .map(|(_, list)| list.data)
+    }
+
+    /// Finds and parses a `DateList` from within a string.
+    ///
+    /// ```rust
+    /// # use smart_date::DateList;
+    /// # fn main() {
+    /// let result = DateList::find_and_parse_in_str("gym on tuesday and thursday").unwrap();
+    /// assert_eq!(result.data.dates.len(), 2);
+    /// assert_eq!(result.range, (7..27));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn find_and_parse_in_str(text: &str) -> Option<Parsed<DateList>> {
+        Self::find_and_parse_in_str_with_options(text, &ParseOptions::default())
+    }
+
+    /// Same as [`DateList::find_and_parse_in_str`], but with control over how the text is
+    /// parsed.
+    #[must_use]
+    pub fn find_and_parse_in_str_with_options(
+        text: &str,
+        options: &ParseOptions,
+    ) -> Option<Parsed<DateList>> {
+        parse_date_list(text, options)
+    }
+
+    /// Converts each date in the list into a [`NaiveDate`], in the order they were written.
+    ///
+    /// ```rust
+    /// # use smart_date::DateList;
+    /// # fn main() {
+    /// // a Sunday
+    /// let today = chrono::NaiveDate::parse_from_str("2023-10-08", "%Y-%m-%d").unwrap();
+    ///
+    /// let list = DateList::parse_from_str("mon, wed and fri").unwrap();
+    /// let dates: Vec<_> = list.into_naive_dates(today).iter().map(ToString::to_string).collect();
+    /// assert_eq!(dates, vec!["2023-10-09", "2023-10-11", "2023-10-13"]);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn into_naive_dates(self, today: NaiveDate) -> Vec<NaiveDate> {
+        self.dates
+            .into_iter()
+            .map(|date| date.into_naive_date(today))
+            .collect()
+    }
+}
@@ -0,0 +1,216 @@
+use crate::{
+    parser::{date_words, parse_flex_datetime},
+    suggest::complete_date_word,
+    FlexibleDateTime, ParseOptions, Parsed,
+};
+
+/// Text that usually lengthens a date when it's typed right after it, like the "pm" in
+/// "tomorrow at 5pm".
+const SUFFIXES: [&str; 4] = ["5", ":30", "pm", "th"];
+
+/// Words that usually lengthen a date when they're typed after it, like the "at 5" in "tomorrow
+/// at 5", along with the date words.
+const NEXT_WORDS: [&str; 4] = ["5", "at 5", "days", "weeks"];
+
+/// Parses text as it's typed, for editors that highlight the date in a line while it's being
+/// written. Feed it the text with [`LiveParser::push_str`] as it comes in (or the whole line
+/// with [`LiveParser::set_text`] after each edit), and read the date found so far with
+/// [`LiveParser::best`].
+///
+/// The date is [settled](LiveParser::is_settled) once typing more can't change it, eg. once
+/// "tomorrow for lunch" has moved on from the date. Settled dates aren't parsed again as more
+/// text comes in, so a long line only costs as much as the part up to its date.
+///
+/// ```rust
+/// # use smart_date::{FlexibleDate, LiveParser};
+/// # fn main() {
+/// let mut parser = LiveParser::new();
+///
+/// parser.push_str("lunch tomorrow");
+/// assert_eq!(parser.best().unwrap().data.date, FlexibleDate::Tomorrow);
+/// // "tomorrow at 1" would be a different date
+/// assert!(!parser.is_settled());
+///
+/// parser.push_str(" with sam");
+/// assert_eq!(parser.best().unwrap().range, (6..14));
+/// assert!(parser.is_settled());
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LiveParser {
+    options: ParseOptions,
+    text: String,
+    best: Option<Parsed<FlexibleDateTime>>,
+    settled: bool,
+}
+
+impl LiveParser {
+    /// A parser with no text yet, and the default options.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Same as [`LiveParser::new`], but with control over how the text is parsed.
+    #[must_use]
+    pub fn with_options(options: ParseOptions) -> Self {
+        Self {
+            options,
+            ..Self::default()
+        }
+    }
+
+    /// Adds `text` to the end of the text typed so far.
+    pub fn push_str(&mut self, text: &str) {
+        self.text.push_str(text);
+        if !self.settled {
+            self.update();
+        }
+    }
+
+    /// Replaces the text typed so far with `text`, after an edit. Text that only adds to the end
+    /// is treated like [`LiveParser::push_str`], and anything else is parsed again from the
+    /// start.
+    pub fn set_text(&mut self, text: &str) {
+        if let Some(added) = text.strip_prefix(self.text.as_str()) {
+            self.push_str(added);
+        } else {
+            self.text = text.to_string();
+            self.update();
+        }
+    }
+
+    /// The text typed so far.
+    #[must_use]
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// The first date (with or without a time) in the text typed so far, if there is one.
+    #[must_use]
+    pub fn best(&self) -> Option<&Parsed<FlexibleDateTime>> {
+        self.best.as_ref()
+    }
+
+    /// Whether typing more at the end of the text can't change [`LiveParser::best`]. There's
+    /// always a chance of a date while there isn't one yet, so this is only `true` once there's
+    /// a date.
+    ///
+    /// This is judged by trying the sorts of text that usually lengthen a date, like "at 5",
+    /// "pm", or the rest of a partly typed date word, so it can't rule out every way of
+    /// changing the date.
+    #[must_use]
+    pub fn is_settled(&self) -> bool {
+        self.settled
+    }
+
+    fn update(&mut self) {
+        self.best = parse_flex_datetime(&self.text, &self.options);
+        self.settled = self.best.as_ref().is_some_and(|best| {
+            // text before the date can't change it, so there's no need to parse it again
+            let start = best.range.start;
+            let rest = &self.text[start..];
+            let unchanged = |more: &str| {
+                parse_flex_datetime(&format!("{rest}{more}"), &self.options).is_some_and(|parsed| {
+                    parsed.data == best.data
+                        && parsed.range.start + start == best.range.start
+                        && parsed.range.end + start == best.range.end
+                })
+            };
+            // the rest of a partly typed date word, like the "day" of "fri"
+            let completions = complete_date_word(rest, &self.options);
+            SUFFIXES.into_iter().all(unchanged)
+                && completions
+                    .iter()
+                    .filter_map(|completion| {
+                        // the replacement is lowercase, so count letters rather than bytes
+                        completion
+                            .replacement
+                            .get(completion.word.chars().count()..)
+                    })
+                    .all(unchanged)
+                && NEXT_WORDS
+                    .into_iter()
+                    .chain(date_words())
+                    .all(|word| unchanged(&format!(" {word}")))
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::{FlexibleDate, FlexibleTime, Weekday};
+
+    #[test]
+    fn test_live_parser() {
+        let mut parser = LiveParser::new();
+        assert_eq!(parser.best(), None);
+        assert!(!parser.is_settled());
+
+        parser.push_str("call mom ");
+        assert_eq!(parser.best(), None);
+        assert!(!parser.is_settled());
+
+        parser.push_str("fri");
+        let best = parser.best().unwrap();
+        assert_eq!(best.data.date, FlexibleDate::Weekday(Weekday::Friday));
+        assert_eq!(best.range, (9..12));
+        // "fri" could still become "friday at 5"
+        assert!(!parser.is_settled());
+
+        parser.push_str("day at 5");
+        let best = parser.best().unwrap();
+        assert_eq!(best.range, (9..20));
+        assert!(!parser.is_settled());
+
+        parser.push_str("pm about the");
+        assert_eq!(
+            parser.best().unwrap().data.time,
+            FlexibleTime {
+                hour: 17,
+                minute: 0
+            }
+        );
+        assert_eq!(parser.best().unwrap().range, (9..22));
+        assert!(parser.is_settled());
+
+        // the date stays put as more text comes in after it
+        parser.push_str(" party next week");
+        assert_eq!(parser.best().unwrap().range, (9..22));
+        assert_eq!(
+            parser.text(),
+            "call mom friday at 5pm about the party next week"
+        );
+    }
+
+    #[test]
+    fn test_live_parser_set_text() {
+        let mut parser = LiveParser::new();
+        parser.set_text("dentist tomorrow at 9am sharp");
+        assert!(parser.is_settled());
+
+        // backspacing into the date unsettles it
+        parser.set_text("dentist tomorrow at 9");
+        assert_eq!(parser.best().unwrap().range, (8..21));
+        assert!(!parser.is_settled());
+
+        parser.set_text("dentist");
+        assert_eq!(parser.best(), None);
+        assert!(!parser.is_settled());
+    }
+
+    #[test]
+    fn test_live_parser_partial_words() {
+        // "Wed" could still become "Wednesday", which has a different range
+        let mut parser = LiveParser::new();
+        parser.push_str("see you on Wed");
+        assert_eq!(parser.best().unwrap().range, (11..14));
+        assert!(!parser.is_settled());
+        parser.push_str("nesday, ok");
+        assert_eq!(parser.best().unwrap().range, (11..20));
+        assert!(parser.is_settled());
+    }
+}
@@ -0,0 +1,85 @@
+use crate::Weekday;
+use std::sync::Arc;
+
+/// Words that a language uses in date phrases. Parsing is otherwise still in English, but
+/// phrases whose words come from here can be swapped out for another language.
+///
+/// ```rust
+/// # use smart_date::{Locale, ParseOptions, Recurrence};
+/// # fn main() {
+/// let options = ParseOptions {
+///     locale: Locale {
+///         recurrence_triggers: vec!["every".into(), "each".into(), "any".into()],
+///         ..Locale::english()
+///     },
+///     ..ParseOptions::default()
+/// };
+///
+/// assert!(Recurrence::parse_from_str_with_options("any monday", &options).is_some());
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Locale {
+    /// A short name for the locale, like "en", that's reported on the
+    /// [results](crate::Parsed::locale) it produces. It's shared with the results rather than
+    /// copied into each one.
+    pub name: Arc<str>,
+
+    /// Words that start a recurrence, like the "every" in "every monday". Must be lowercase.
+    pub recurrence_triggers: Vec<String>,
+
+    /// Words and phrases that introduce a date, like the "due" in "report due friday". Used by
+    /// [`ParseOptions::only_after_triggers`](crate::ParseOptions::only_after_triggers). Must be
+    /// lowercase.
+    pub date_triggers: Vec<String>,
+
+    /// Words that often come right before a date, like the "on" in "on sat". A name like "sat"
+    /// that might not be a date isn't [ambiguous](crate::Parsed::ambiguous) after one of these.
+    /// Must be lowercase.
+    pub date_prepositions: Vec<String>,
+
+    /// Which number comes first in numeric dates like "01/02".
+    pub date_order: DateOrder,
+
+    /// The day that weeks start on, for phrases made of whole weeks, like "the first week of
+    /// march".
+    pub week_start: Weekday,
+}
+
+/// Which number comes first in a numeric date separated by slashes, like "01/02".
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DateOrder {
+    /// The month comes first, as in the US, so "01/02" is January 2.
+    MonthFirst,
+
+    /// The day comes first, as in most of Europe, so "01/02" is February 1.
+    DayFirst,
+}
+
+impl Locale {
+    /// The English words for date phrases. This is the default locale.
+    #[must_use]
+    pub fn english() -> Self {
+        Self {
+            name: "en".into(),
+            recurrence_triggers: vec!["every".into(), "each".into()],
+            date_triggers: vec![
+                "due".into(),
+                "remind me".into(),
+                "schedule for".into(),
+                "scheduled for".into(),
+            ],
+            date_prepositions: ["on", "in", "by", "until", "till", "before", "after", "from"]
+                .map(String::from)
+                .to_vec(),
+            date_order: DateOrder::MonthFirst,
+            week_start: Weekday::Monday,
+        }
+    }
+}
+
+impl Default for Locale {
+    fn default() -> Self {
+        Self::english()
+    }
+}
@@ -0,0 +1,172 @@
+//! Parsers for numbers written out as words, eg. "three" or "twenty-seventh".
+
+use nom::{
+    branch,
+    bytes::complete::tag,
+    character::complete::char,
+    combinator::{map, value},
+    sequence::separated_pair,
+    IResult,
+};
+
+/// Parses the tens part of a compound number, eg. the "twenty" in "twenty-seventh".
+fn parse_tens_word(input: &str) -> IResult<&str, u32> {
+    branch::alt((
+        value(20, tag("twenty")),
+        value(30, tag("thirty")),
+        value(40, tag("forty")),
+        value(50, tag("fifty")),
+        value(60, tag("sixty")),
+        value(70, tag("seventy")),
+        value(80, tag("eighty")),
+        value(90, tag("ninety")),
+    ))(input)
+}
+
+/// Parses a number word from "one" to "nine".
+fn parse_ones_word(input: &str) -> IResult<&str, u32> {
+    branch::alt((
+        value(1, tag("one")),
+        value(2, tag("two")),
+        value(3, tag("three")),
+        value(4, tag("four")),
+        value(5, tag("five")),
+        value(6, tag("six")),
+        value(7, tag("seven")),
+        value(8, tag("eight")),
+        value(9, tag("nine")),
+    ))(input)
+}
+
+/// Parses a number word from "ten" to "nineteen".
+fn parse_teen_word(input: &str) -> IResult<&str, u32> {
+    branch::alt((
+        value(10, tag("ten")),
+        value(11, tag("eleven")),
+        value(12, tag("twelve")),
+        value(13, tag("thirteen")),
+        value(14, tag("fourteen")),
+        value(15, tag("fifteen")),
+        value(16, tag("sixteen")),
+        value(17, tag("seventeen")),
+        value(18, tag("eighteen")),
+        value(19, tag("nineteen")),
+    ))(input)
+}
+
+/// Parses a number word from "one" to "ninety-nine". Compound numbers can be written with a
+/// hyphen or a space, eg. "twenty-one" or "twenty one".
+pub(crate) fn parse_cardinal_word(input: &str) -> IResult<&str, u32> {
+    branch::alt((
+        map(
+            separated_pair(
+                parse_tens_word,
+                branch::alt((char('-'), char(' '))),
+                parse_ones_word,
+            ),
+            |(tens, ones)| tens + ones,
+        ),
+        parse_tens_word,
+        // teens come first so that eg. "sixteen" isn't cut short at "six"
+        parse_teen_word,
+        parse_ones_word,
+    ))(input)
+}
+
+/// Parses an ordinal word from "first" to "ninth".
+fn parse_ones_ordinal_word(input: &str) -> IResult<&str, u32> {
+    branch::alt((
+        value(1, tag("first")),
+        value(2, tag("second")),
+        value(3, tag("third")),
+        value(4, tag("fourth")),
+        value(5, tag("fifth")),
+        value(6, tag("sixth")),
+        value(7, tag("seventh")),
+        value(8, tag("eighth")),
+        value(9, tag("ninth")),
+    ))(input)
+}
+
+/// Parses an ordinal word from "tenth" to "nineteenth".
+fn parse_teen_ordinal_word(input: &str) -> IResult<&str, u32> {
+    branch::alt((
+        value(10, tag("tenth")),
+        value(11, tag("eleventh")),
+        value(12, tag("twelfth")),
+        value(13, tag("thirteenth")),
+        value(14, tag("fourteenth")),
+        value(15, tag("fifteenth")),
+        value(16, tag("sixteenth")),
+        value(17, tag("seventeenth")),
+        value(18, tag("eighteenth")),
+        value(19, tag("nineteenth")),
+    ))(input)
+}
+
+/// Parses the ordinal form of a multiple of ten from "twentieth" to "ninetieth".
+fn parse_tens_ordinal_word(input: &str) -> IResult<&str, u32> {
+    branch::alt((
+        value(20, tag("twentieth")),
+        value(30, tag("thirtieth")),
+        value(40, tag("fortieth")),
+        value(50, tag("fiftieth")),
+        value(60, tag("sixtieth")),
+        value(70, tag("seventieth")),
+        value(80, tag("eightieth")),
+        value(90, tag("ninetieth")),
+    ))(input)
+}
+
+/// Parses an ordinal word from "first" to "ninety-ninth". Compound ordinals can be written with
+/// a hyphen or a space, eg. "twenty-seventh" or "twenty seventh".
+pub(crate) fn parse_ordinal_word(input: &str) -> IResult<&str, u32> {
+    branch::alt((
+        map(
+            separated_pair(
+                parse_tens_word,
+                branch::alt((char('-'), char(' '))),
+                parse_ones_ordinal_word,
+            ),
+            |(tens, ones)| tens + ones,
+        ),
+        parse_tens_ordinal_word,
+        // teens come first so that eg. "sixteenth" isn't cut short at "six"
+        parse_teen_ordinal_word,
+        parse_ones_ordinal_word,
+    ))(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cardinal_word() {
+        assert_eq!(parse_cardinal_word("one"), Ok(("", 1)));
+        assert_eq!(parse_cardinal_word("seventeen"), Ok(("", 17)));
+        assert_eq!(parse_cardinal_word("forty"), Ok(("", 40)));
+        assert_eq!(parse_cardinal_word("twenty-one"), Ok(("", 21)));
+        assert_eq!(parse_cardinal_word("ninety nine"), Ok(("", 99)));
+
+        // a tens word followed by something else is just the tens
+        assert_eq!(parse_cardinal_word("thirty days"), Ok((" days", 30)));
+        assert!(parse_cardinal_word("first").is_err());
+    }
+
+    #[test]
+    fn test_parse_ordinal_word() {
+        assert_eq!(parse_ordinal_word("first"), Ok(("", 1)));
+        assert_eq!(parse_ordinal_word("twelfth"), Ok(("", 12)));
+        assert_eq!(parse_ordinal_word("seventeenth"), Ok(("", 17)));
+        assert_eq!(parse_ordinal_word("twentieth"), Ok(("", 20)));
+        assert_eq!(parse_ordinal_word("twenty-seventh"), Ok(("", 27)));
+        assert_eq!(parse_ordinal_word("thirty first"), Ok(("", 31)));
+        assert_eq!(parse_ordinal_word("ninety-ninth"), Ok(("", 99)));
+
+        // only the ones place can follow the tens
+        assert!(parse_ordinal_word("twenty-tenth").is_err());
+        assert!(parse_ordinal_word("twenty").is_err());
+        assert!(parse_ordinal_word("seven").is_err());
+    }
+}
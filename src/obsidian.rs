@@ -0,0 +1,123 @@
+use crate::{
+    parser::{parse_flex_date_exact, parse_recurrence_exact},
+    KeyedDate, ParseOptions, Recurrence,
+};
+
+/// The emoji that the Obsidian Tasks plugin puts in front of each kind of date, along with the
+/// key that the date gets.
+const DATE_MARKERS: [(&str, &str); 3] = [("📅", "due"), ("⏳", "scheduled"), ("🛫", "start")];
+
+/// The emoji that the Obsidian Tasks plugin puts in front of a recurrence.
+const RECURRENCE_MARKER: &str = "🔁";
+
+/// The dates in a task written with the conventions of the
+/// [Obsidian Tasks](https://publish.obsidian.md/tasks) plugin, eg.
+/// "- [ ] water plants 🔁 every week 📅 2024-01-27".
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ObsidianTask {
+    /// The task's dates, keyed by their kind: "due" (📅), "scheduled" (⏳), or "start" (🛫).
+    pub dates: Vec<KeyedDate>,
+
+    /// How the task repeats (🔁), if it does.
+    pub recurrence: Option<Recurrence>,
+}
+
+impl ObsidianTask {
+    /// Parses the dates out of an Obsidian task. Fails (returns `None`) if the task doesn't have
+    /// any dates or a recurrence.
+    ///
+    /// ```rust
+    /// # use smart_date::{FlexibleDate, Frequency, ObsidianTask};
+    /// # fn main() {
+    /// let task = ObsidianTask::parse_from_str("- [ ] water plants 🔁 every week ⏳ tomorrow").unwrap();
+    /// assert_eq!(task.dates[0].key, "scheduled");
+    /// assert_eq!(task.dates[0].date, FlexibleDate::Tomorrow);
+    /// assert_eq!(task.recurrence.unwrap().frequency, Frequency::Weekly(None));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn parse_from_str(text: &str) -> Option<ObsidianTask> {
+        Self::parse_from_str_with_options(text, &ParseOptions::default())
+    }
+
+    /// Same as [`ObsidianTask::parse_from_str`], but with control over how the text is parsed.
+    #[must_use]
+    pub fn parse_from_str_with_options(text: &str, options: &ParseOptions) -> Option<ObsidianTask> {
+        let text = text.to_lowercase();
+        let mut task = ObsidianTask {
+            dates: Vec::new(),
+            recurrence: None,
+        };
+
+        for (index, _) in text.char_indices() {
+            let rest = &text[index..];
+            if let Some(value) = rest.strip_prefix(RECURRENCE_MARKER) {
+                if let Ok((_, recurrence)) = parse_recurrence_exact(value.trim_start(), options) {
+                    task.recurrence = Some(recurrence.data);
+                }
+            }
+            for (marker, key) in DATE_MARKERS {
+                let Some(value) = rest.strip_prefix(marker) else {
+                    continue;
+                };
+                // some editors put a variation selector after the emoji
+                let value = value.trim_start_matches('\u{fe0f}').trim_start();
+                if let Ok((_, date)) = parse_flex_date_exact(value, options) {
+                    task.dates.push(KeyedDate {
+                        key: key.into(),
+                        date: date.data,
+                    });
+                }
+            }
+        }
+
+        (!task.dates.is_empty() || task.recurrence.is_some()).then_some(task)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::{FlexibleDate, Frequency, Month};
+
+    #[test]
+    fn test_parse_obsidian_task() {
+        let task = ObsidianTask::parse_from_str(
+            "- [ ] pay rent 🔁 every month on the 1st 🛫 2024-01-25 📅 2024-02-01",
+        )
+        .unwrap();
+        assert_eq!(
+            task.dates,
+            vec![
+                KeyedDate {
+                    key: "start".into(),
+                    date: FlexibleDate::CalendarDate {
+                        year: 2024,
+                        month: Month::January,
+                        day: 25,
+                    },
+                },
+                KeyedDate {
+                    key: "due".into(),
+                    date: FlexibleDate::CalendarDate {
+                        year: 2024,
+                        month: Month::February,
+                        day: 1,
+                    },
+                },
+            ]
+        );
+        assert_eq!(
+            task.recurrence.unwrap().frequency,
+            Frequency::Monthly(Some(1))
+        );
+
+        let task = ObsidianTask::parse_from_str("- [ ] call mom ⏳\u{fe0f} friday").unwrap();
+        assert_eq!(task.dates[0].key, "scheduled");
+
+        assert!(ObsidianTask::parse_from_str("- [ ] call mom friday").is_none());
+        assert!(ObsidianTask::parse_from_str("- [ ] call mom 📅 someday").is_none());
+    }
+}
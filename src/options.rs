@@ -0,0 +1,327 @@
+use crate::{
+    FlexibleTime, Keywords, LeapDayPolicy, Locale, MidnightPolicy, Rule, SectionPolicy,
+    ThisWeekPolicy, TimeOnlyPolicy, WeekdayPolicy, WeekdayScope, WindowEnd, YearPolicy,
+};
+use chrono::Duration;
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+};
+
+/// Options that control how dates are recognized in text.
+///
+/// ```rust
+/// # use smart_date::{FlexibleDate, FuzzyQuantities, ParseOptions};
+/// # fn main() {
+/// let options = ParseOptions {
+///     fuzzy_quantities: FuzzyQuantities {
+///         several: 4,
+///         ..FuzzyQuantities::default()
+///     },
+///     ..ParseOptions::default()
+/// };
+///
+/// let result = FlexibleDate::parse_from_str_with_options("in several days", &options).unwrap();
+/// assert_eq!(result, FlexibleDate::DaysFromNow(4));
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools)] // the flags are independent of each other
+pub struct ParseOptions {
+    /// The amounts that colloquial quantities like "a couple" stand for.
+    pub fuzzy_quantities: FuzzyQuantities,
+
+    /// How many days after today are covered by "soon". Defaults to 3.
+    pub soon_days: u32,
+
+    /// What "this week" means as a single date. Defaults to [`ThisWeekPolicy::WholeWeek`], so
+    /// it's only a range.
+    pub this_week: ThisWeekPolicy,
+
+    /// Which occurrence a weekday on its own, like "friday", stands for. Defaults to
+    /// [`WeekdayScope::Upcoming`].
+    pub weekday_scope: WeekdayScope,
+
+    /// What "next" means in front of a weekday, as in "next friday". Defaults to
+    /// [`WeekdayPolicy::FollowingWeek`].
+    pub next_weekday: WeekdayPolicy,
+
+    /// How many days after today "later this week" means, though never past the end of the
+    /// week. Defaults to 2.
+    pub later_this_week_days: u32,
+
+    /// What "the beginning", "the middle", and "the end" of a month, quarter, or year mean.
+    /// Defaults to [`SectionPolicy::Boundaries`].
+    pub period_sections: SectionPolicy,
+
+    /// What a year on its own, like "in 2026", means as a single date. Defaults to
+    /// [`YearPolicy::FirstDay`].
+    pub year_only: YearPolicy,
+
+    /// Where windows of time like "for the next two weeks" end. Defaults to
+    /// [`WindowEnd::Through`].
+    pub window_end: WindowEnd,
+
+    /// Hours given without AM or PM, like the "5" in "tomorrow at 5", are assumed to fall
+    /// within the twelve hours starting at this hour. Defaults to 7, so "at 5" means 17:00 and
+    /// "at 9" means 09:00.
+    pub bare_hour_window_start: u32,
+
+    /// How far off an approximate time like "around 3" or "noonish" might be, in either
+    /// direction. Defaults to 30 minutes.
+    pub approximate_time_tolerance: Duration,
+
+    /// How far off an approximate date like "around the 15th" might be, in either direction.
+    /// Defaults to 2 days.
+    pub approximate_date_tolerance: Duration,
+
+    /// Whether "noon" and "midnight" can be preceded by "12", as in "12 noon". Defaults to
+    /// `true`. Either way, "12am" is midnight and "12pm" is noon.
+    pub twelve_with_words: bool,
+
+    /// Which midnight "midnight" means, eg. in "friday at midnight". Defaults to
+    /// [`MidnightPolicy::EndOfDay`]. "12am" always means the start of the day.
+    pub midnight: MidnightPolicy,
+
+    /// Which day a time given without a date means, as in "call mom at 5". Defaults to
+    /// [`TimeOnlyPolicy::Upcoming`].
+    pub time_only: TimeOnlyPolicy,
+
+    /// The start of the workday, which "first thing" stands for. Defaults to 09:00.
+    pub workday_start: FlexibleTime,
+
+    /// The end of the workday, which "cob" and "close of business" stand for. Defaults to 17:00.
+    pub close_of_business: FlexibleTime,
+
+    /// What yearly recurrences on February 29 do in years without one. Defaults to
+    /// [`LeapDayPolicy::Skip`].
+    pub leap_day_policy: LeapDayPolicy,
+
+    /// Whether dotted dates can write their month as a roman numeral, as in "27.IX.2024".
+    /// Defaults to `false`.
+    pub roman_numeral_months: bool,
+
+    /// Whether [`FlexibleDate::find_and_check_in_str_with_options`] fails when the text has
+    /// dates that disagree, rather than returning the first one with a warning. Defaults to
+    /// `false`.
+    ///
+    /// [`FlexibleDate::find_and_check_in_str_with_options`]: crate::FlexibleDate::find_and_check_in_str_with_options
+    pub strict_conflicts: bool,
+
+    /// Whether the functions that find dates within text only look at the text after one of the
+    /// locale's [date triggers](Locale::date_triggers), like "due". This avoids false positives
+    /// like the "sat" in "sat down". Defaults to `false`.
+    pub only_after_triggers: bool,
+
+    /// How many bytes at the start of the text the functions that find dates within text look
+    /// at. Dates that run past the limit aren't found. Defaults to `None`, for no limit.
+    pub max_scan_len: Option<usize>,
+
+    /// A token that stops the functions that find dates within text when it's cancelled, eg.
+    /// from another thread once the user has typed something new. A cancelled search finds
+    /// nothing, or only the dates found before it was cancelled. Defaults to `None`.
+    pub cancel: Option<CancelToken>,
+
+    /// Which match the functions that find dates within text return when there are several,
+    /// eg. the last one, since the real due date often comes at the end of a task title.
+    /// Defaults to the first match.
+    pub search: SearchOptions,
+
+    /// What to do with names like "sat" and "may" that might not be dates, when nothing around
+    /// them suggests that they are. Defaults to [`AmbiguousNames::Flag`].
+    pub ambiguous_names: AmbiguousNames,
+
+    /// Whether the functions that find dates within text also read common misspellings of date
+    /// words, like "tommorow" or "wensday", when the text doesn't make a date as written.
+    /// Matches that needed a correction lose some [confidence](crate::Parsed::confidence), so
+    /// callers can decide whether to trust them. Defaults to `false`.
+    pub typo_tolerance: bool,
+
+    /// Grammar rules to skip, for applications that would rather miss some dates than misread
+    /// text that isn't one. Defaults to none.
+    pub disabled_rules: Vec<Rule>,
+
+    /// The words used in date phrases. Defaults to [`Locale::english`].
+    pub locale: Locale,
+
+    /// More locales for the functions that find dates within text to try, in order, when the
+    /// main locale doesn't produce a match. This helps with text that mixes languages. Defaults
+    /// to none.
+    pub extra_locales: Vec<Locale>,
+
+    /// Words and phrases registered by the application, like named times of day. Defaults to
+    /// [`Keywords::default`], which only has the built-in idioms.
+    pub keywords: Keywords,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            fuzzy_quantities: FuzzyQuantities::default(),
+            soon_days: 3,
+            this_week: ThisWeekPolicy::WholeWeek,
+            weekday_scope: WeekdayScope::Upcoming,
+            next_weekday: WeekdayPolicy::FollowingWeek,
+            later_this_week_days: 2,
+            period_sections: SectionPolicy::Boundaries,
+            year_only: YearPolicy::FirstDay,
+            window_end: WindowEnd::Through,
+            bare_hour_window_start: 7,
+            approximate_time_tolerance: Duration::minutes(30),
+            approximate_date_tolerance: Duration::days(2),
+            twelve_with_words: true,
+            midnight: MidnightPolicy::EndOfDay,
+            time_only: TimeOnlyPolicy::Upcoming,
+            workday_start: FlexibleTime { hour: 9, minute: 0 },
+            close_of_business: FlexibleTime {
+                hour: 17,
+                minute: 0,
+            },
+            leap_day_policy: LeapDayPolicy::Skip,
+            roman_numeral_months: false,
+            strict_conflicts: false,
+            only_after_triggers: false,
+            max_scan_len: None,
+            cancel: None,
+            search: SearchOptions::default(),
+            ambiguous_names: AmbiguousNames::Flag,
+            typo_tolerance: false,
+            disabled_rules: Vec::new(),
+            locale: Locale::english(),
+            extra_locales: Vec::new(),
+            keywords: Keywords::default(),
+        }
+    }
+}
+
+/// How readily the functions that find dates within text accept dates written with names that
+/// are also common words, like "sat" in "sat down" or "may" in "may need to".
+///
+/// These names are trusted when the text around them suggests a date: a following day, as in
+/// "may 5", capitalization partway through a sentence, as in "see you Sat", or a word like "on"
+/// right before them (see [`Locale::date_prepositions`]).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AmbiguousNames {
+    /// Always read them as dates, without flagging them.
+    Accept,
+
+    /// Read them as dates, but mark the result as [ambiguous](crate::Parsed::ambiguous) when
+    /// nothing suggests a date.
+    Flag,
+
+    /// Skip over them when nothing suggests a date.
+    RequireContext,
+}
+
+/// A handle for stopping a search for dates partway through, set as
+/// [`ParseOptions::cancel`]. Clones share the same flag, so cancelling any of them stops every
+/// search using them.
+///
+/// ```rust
+/// # use smart_date::{CancelToken, FlexibleDate, ParseOptions};
+/// # fn main() {
+/// let token = CancelToken::new();
+/// let options = ParseOptions {
+///     cancel: Some(token.clone()),
+///     ..ParseOptions::default()
+/// };
+///
+/// token.cancel();
+/// let result = FlexibleDate::find_and_parse_in_str_with_options("due tomorrow", &options);
+/// assert_eq!(result, None);
+/// # }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stops every search using this token.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    #[must_use]
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Tokens are equal when they're clones of each other.
+impl PartialEq for CancelToken {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for CancelToken {}
+
+/// Controls which match wins when the functions that find dates within text come across
+/// several, set as [`ParseOptions::search`].
+///
+/// ```rust
+/// # use smart_date::{FlexibleDate, MatchPreference, ParseOptions, SearchOptions, Weekday};
+/// # fn main() {
+/// let options = ParseOptions {
+///     search: SearchOptions {
+///         prefer: MatchPreference::Last,
+///     },
+///     ..ParseOptions::default()
+/// };
+///
+/// let result = FlexibleDate::find_and_parse_in_str_with_options(
+///     "started today, due friday",
+///     &options,
+/// );
+/// assert_eq!(result.unwrap().data, FlexibleDate::Weekday(Weekday::Friday));
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SearchOptions {
+    /// Which of the matches to return. Defaults to [`MatchPreference::First`].
+    pub prefer: MatchPreference,
+}
+
+/// Which of several matches within text wins. Only matches that don't overlap are compared,
+/// found one after another from the start of the text.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum MatchPreference {
+    /// The one closest to the start of the text, which is the cheapest to find.
+    #[default]
+    First,
+
+    /// The one closest to the end of the text.
+    Last,
+
+    /// The one covering the most text, eg. "next friday at 5" over "today". Ties go to the
+    /// earlier match.
+    Longest,
+}
+
+/// Maps colloquial quantity words onto concrete amounts. Dates parsed using one of
+/// these words are marked as approximate.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FuzzyQuantities {
+    /// "a couple", "a couple of", or "couple" as in "the next couple of days". Defaults to 2.
+    pub couple: u32,
+
+    /// "a few", or "few" as in "the next few days". Defaults to 3.
+    pub few: u32,
+
+    /// "several". Defaults to 5.
+    pub several: u32,
+}
+
+impl Default for FuzzyQuantities {
+    fn default() -> Self {
+        Self {
+            couple: 2,
+            few: 3,
+            several: 5,
+        }
+    }
+}
@@ -1,14 +1,129 @@
-use crate::{FlexibleDate, Parsed, Weekday};
+use crate::numbers::{parse_cardinal_word, parse_ordinal_word};
+use crate::suggest::correct_typos;
+use crate::{
+    AmbiguousNames, CancelToken, DateList, DateOrder, DateProblem, DateUnit, FlexibleDate,
+    FlexibleDateRange, FlexibleDateTime, FlexibleDuration, FlexibleTime, Frequency,
+    FuzzyQuantities, IdiomTime, Interpretations, KeyedDate, MatchPreference, MidnightPolicy, Month,
+    NamedTime, OpenDateRange, ParseOptions, Parsed, Period, PeriodUnit, Precision, Recurrence,
+    RecurrenceEnd, Rule, Schedule, SectionPolicy, Segment, ThisWeekPolicy, TimeOnlyPolicy, Weekday,
+    WeekdayPolicy, WeekdayScope, WindowEnd, YearPolicy,
+};
+use chrono::{Duration, NaiveDate};
 use nom::{
     branch,
-    bytes::complete::{is_not, tag},
-    character::complete::space1,
-    combinator::value,
+    bytes::complete::{is_not, tag, take_while1, take_while_m_n},
+    character::complete::{digit1, one_of, satisfy, space0, space1},
+    combinator::{all_consuming, map, map_opt, map_res, not, opt, peek, recognize, value, verify},
     error::{Error, ErrorKind},
-    sequence::tuple,
+    multi::{many0, many1, separated_list1},
+    sequence::{preceded, terminated, tuple},
     Err, IResult,
 };
 
+/// Something produced by one of the grammar rules, along with what the rule knows about how
+/// precisely the input described it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Matched<T> {
+    pub data: T,
+    pub approximate: bool,
+    pub inferred: bool,
+    pub tolerance: Option<Duration>,
+    pub ambiguous: bool,
+
+    /// The lowest [specificity](Rule::specificity) of the rules that produced the match.
+    pub specificity: u8,
+
+    /// The finest precision of the rules that produced the match. Dates are to the day unless
+    /// their rule says otherwise.
+    pub precision: Precision,
+}
+
+impl<T> Matched<T> {
+    fn exact(data: T) -> Self {
+        Self {
+            data,
+            approximate: false,
+            inferred: false,
+            tolerance: None,
+            ambiguous: false,
+            specificity: 100,
+            precision: Precision::Day,
+        }
+    }
+
+    fn approximate(data: T) -> Self {
+        Self {
+            approximate: true,
+            ..Self::exact(data)
+        }
+    }
+
+    fn inferred(data: T) -> Self {
+        Self {
+            inferred: true,
+            ..Self::exact(data)
+        }
+    }
+
+    /// Marks the match as approximate, give or take `tolerance`.
+    fn within(self, tolerance: Duration) -> Self {
+        Self {
+            approximate: true,
+            tolerance: Some(tolerance),
+            ..self
+        }
+    }
+
+    /// Marks the match as ambiguous if it came from a word that often isn't a date, like "sat"
+    /// or "may".
+    fn ambiguous_if(self, ambiguous: bool) -> Self {
+        Self {
+            ambiguous: self.ambiguous || ambiguous,
+            ..self
+        }
+    }
+
+    /// Sets how finely the match pins down the data, for rules that aren't to the day.
+    fn precise(self, precision: Precision) -> Self {
+        Self { precision, ..self }
+    }
+
+    /// Sets the specificity of the match, which tests use to spell out what a rule produces.
+    #[cfg(test)]
+    fn specific(self, specificity: u8) -> Self {
+        Self {
+            specificity,
+            ..self
+        }
+    }
+
+    /// Transforms the data in the match, keeping its flags.
+    fn map<U>(self, transform: impl FnOnce(T) -> U) -> Matched<U> {
+        Matched {
+            data: transform(self.data),
+            approximate: self.approximate,
+            inferred: self.inferred,
+            tolerance: self.tolerance,
+            ambiguous: self.ambiguous,
+            specificity: self.specificity,
+            precision: self.precision,
+        }
+    }
+
+    /// Combines two matches into one, keeping the flags of both.
+    fn and<U, V>(self, other: Matched<U>, combine: impl FnOnce(T, U) -> V) -> Matched<V> {
+        Matched {
+            data: combine(self.data, other.data),
+            approximate: self.approximate || other.approximate,
+            inferred: self.inferred || other.inferred,
+            tolerance: self.tolerance.max(other.tolerance),
+            ambiguous: self.ambiguous || other.ambiguous,
+            specificity: self.specificity.min(other.specificity),
+            precision: self.precision.max(other.precision),
+        }
+    }
+}
+
 fn not_whitespace(input: &str) -> IResult<&str, &str> {
     is_not(" \t")(input)
 }
@@ -24,169 +139,6463 @@ fn parse_tomorrow(input: &str) -> IResult<&str, FlexibleDate> {
     )(input)
 }
 
-fn parse_weekday(input: &str) -> IResult<&str, FlexibleDate> {
+/// Succeeds without consuming anything as long as `input` doesn't carry on the word before it,
+/// eg. after the "sat" in "sat at 5" but not in "satisfy".
+fn word_end(input: &str) -> IResult<&str, ()> {
+    not(satisfy(char::is_alphabetic))(input)
+}
+
+/// Every way of writing each weekday, along with whether it's also a common word that isn't a
+/// date, like "sat" in "sat down".
+const WEEKDAY_NAMES: [(&str, Weekday, bool); 14] = [
+    ("sunday", Weekday::Sunday, false),
+    ("sun", Weekday::Sunday, true),
+    ("monday", Weekday::Monday, false),
+    ("mon", Weekday::Monday, false),
+    ("tuesday", Weekday::Tuesday, false),
+    ("tue", Weekday::Tuesday, false),
+    ("wednesday", Weekday::Wednesday, false),
+    ("wed", Weekday::Wednesday, true),
+    ("thursday", Weekday::Thursday, false),
+    ("thurs", Weekday::Thursday, false),
+    ("friday", Weekday::Friday, false),
+    ("fri", Weekday::Friday, false),
+    ("saturday", Weekday::Saturday, false),
+    ("sat", Weekday::Saturday, true),
+];
+
+/// Every way of writing each month, along with whether it's also a common word that isn't a
+/// date, like "may" in "may need to".
+const MONTH_NAMES: [(&str, Month, bool); 24] = [
+    ("january", Month::January, false),
+    ("jan", Month::January, false),
+    ("february", Month::February, false),
+    ("feb", Month::February, false),
+    ("march", Month::March, false),
+    ("mar", Month::March, true),
+    ("april", Month::April, false),
+    ("apr", Month::April, false),
+    ("may", Month::May, true),
+    ("june", Month::June, false),
+    ("jun", Month::June, false),
+    ("july", Month::July, false),
+    ("jul", Month::July, false),
+    ("august", Month::August, false),
+    ("aug", Month::August, false),
+    ("september", Month::September, false),
+    ("sept", Month::September, false),
+    ("sep", Month::September, false),
+    ("october", Month::October, false),
+    ("oct", Month::October, false),
+    ("november", Month::November, false),
+    ("nov", Month::November, false),
+    ("december", Month::December, false),
+    ("dec", Month::December, false),
+];
+
+/// The whole words that date phrases are made of, which misspelled words are compared against.
+/// Abbreviations are left out, since short words like "sun" are too close to too many others.
+pub(crate) fn date_words() -> impl Iterator<Item = &'static str> {
+    WEEKDAY_NAMES
+        .iter()
+        .map(|(name, ..)| *name)
+        .chain(MONTH_NAMES.iter().map(|(name, ..)| *name))
+        .chain([
+            "today", "tomorrow", "next", "week", "weekend", "month", "year",
+        ])
+        .filter(|word| word.len() > 3)
+}
+
+/// Parses one of `names` followed by `suffix` as a whole word, so that abbreviations don't match
+/// the start of longer words like "monitor" or "mayor". Gives the value of the name and whether
+/// it's ambiguous.
+fn parse_name<'a, T: Clone>(
+    input: &'a str,
+    names: &[(&str, T, bool)],
+    suffix: &str,
+) -> IResult<&'a str, (T, bool)> {
+    names
+        .iter()
+        .find_map(|(name, value, ambiguous)| {
+            tuple((tag::<_, _, Error<&str>>(*name), tag(suffix), word_end))(input)
+                .ok()
+                .map(|(rest, _)| (rest, (value.clone(), *ambiguous)))
+        })
+        .ok_or(Err::Error(Error {
+            input,
+            code: ErrorKind::Tag,
+        }))
+}
+
+fn parse_weekday_name(input: &str) -> IResult<&str, Weekday> {
+    map(
+        |input| parse_name(input, &WEEKDAY_NAMES, ""),
+        |(day, _)| day,
+    )(input)
+}
+
+/// Parses a weekday name in the plural, as in "weekly on thursdays".
+fn parse_weekday_plural(input: &str) -> IResult<&str, Weekday> {
+    map(
+        |input| parse_name(input, &WEEKDAY_NAMES, "s"),
+        |(day, _)| day,
+    )(input)
+}
+
+fn parse_month_name(input: &str) -> IResult<&str, Month> {
+    map(
+        |input| parse_name(input, &MONTH_NAMES, ""),
+        |(month, _)| month,
+    )(input)
+}
+
+/// Parses a number that isn't part of a larger number, fraction, or percentage, so that eg. the
+/// "3" in "3/4 cup", "3.5", or "3%" isn't read as a day or an hour.
+fn parse_standalone_number(input: &str) -> IResult<&str, u32> {
+    terminated(
+        map_res(digit1, str::parse::<u32>),
+        not(branch::alt((
+            value((), satisfy(|c| c.is_ascii_digit())),
+            value((), tuple((one_of(".,:"), satisfy(|c| c.is_ascii_digit())))),
+            value((), one_of("/%")),
+        ))),
+    )(input)
+}
+
+/// Parses a day of the month as a number with an optional ordinal suffix, eg. "5" or "5th", or
+/// as an ordinal word, eg. "fifth".
+fn parse_day_number(input: &str) -> IResult<&str, u32> {
+    verify(
+        branch::alt((
+            map(
+                tuple((
+                    parse_standalone_number,
+                    opt(branch::alt((tag("st"), tag("nd"), tag("rd"), tag("th")))),
+                )),
+                |(day, _)| day,
+            ),
+            parse_ordinal_word,
+        )),
+        |day| (1..=31).contains(day),
+    )(input)
+}
+
+/// Parses a day of the month on its own, eg. "the twenty-seventh".
+fn parse_day_of_month(input: &str) -> IResult<&str, FlexibleDate> {
+    map(
+        tuple((tag("the"), space1, parse_day_number, not_of_month)),
+        |(_, _, day, ())| FlexibleDate::DayOfMonth(day),
+    )(input)
+}
+
+/// Parses a day of the month written as a bare ordinal with digits, eg. "27th" or "3rd". The
+/// suffix has to suit the number, so "3th" doesn't count. These often aren't dates, as in "2nd
+/// try", so they're [ambiguous](Matched::ambiguous).
+fn parse_bare_ordinal(input: &str) -> IResult<&str, Matched<FlexibleDate>> {
+    let (remainder, day) = verify(parse_standalone_number, |day| (1..=31).contains(day))(input)?;
+    let suffix = match day % 10 {
+        1 if day != 11 => "st",
+        2 if day != 12 => "nd",
+        3 if day != 13 => "rd",
+        _ => "th",
+    };
+    let (remainder, _) = tuple((tag(suffix), word_end, not_of_month))(remainder)?;
+    Ok((
+        remainder,
+        Matched::exact(FlexibleDate::DayOfMonth(day)).ambiguous_if(true),
+    ))
+}
+
+/// Parses a day of a month counted from this one, eg. "the 15th of next month", "the 5th next
+/// month", or "the 1st after next".
+fn parse_day_in_month(input: &str) -> IResult<&str, Matched<FlexibleDate>> {
+    let month_offset = branch::alt((
+        map(
+            verify(parse_relative_period, |period| {
+                period.unit == PeriodUnit::Month
+            }),
+            |period| period.offset,
+        ),
+        map(
+            verify(parse_period_after_next, |period| {
+                period.unit == PeriodUnit::Month
+            }),
+            |period| period.offset,
+        ),
+        // "the 1st after next" can only mean months
+        value(2, tag("after next")),
+    ));
+    map(
+        tuple((
+            tag("the"),
+            space1,
+            parse_day_number,
+            space1,
+            opt(tag("of ")),
+            month_offset,
+        )),
+        |(_, _, day, _, _, offset)| Matched::exact(FlexibleDate::DayInMonth { day, offset }),
+    )(input)
+}
+
+/// Parses a month and a day in it, either with the month first, eg. "march 5th" or "march the
+/// 5th", or with the day first, eg. "the 5th of march" or "fifth of march".
+fn parse_month_and_day(input: &str) -> IResult<&str, (Month, u32)> {
+    let (input, (month, day)) = verify(
+        branch::alt((
+            map(
+                tuple((parse_month_name, space1, opt(tag("the ")), parse_day_number)),
+                |(month, _, _, day)| (month, day),
+            ),
+            map(
+                tuple((
+                    parse_day_before_month,
+                    space1,
+                    tag("of"),
+                    space1,
+                    parse_month_name,
+                )),
+                |(day, _, _, _, month)| (month, day),
+            ),
+        )),
+        |(month, day)| *day <= month.max_days(),
+    )(input)?;
+    Ok((input, (month, day)))
+}
+
+/// Parses the day in "the 5th of march" or "fifth of march". Without "the", the day has to be
+/// an ordinal, so that eg. "5 of march" isn't a date.
+fn parse_day_before_month(input: &str) -> IResult<&str, u32> {
+    verify(
+        branch::alt((
+            preceded(tuple((tag("the"), space1)), parse_day_number),
+            parse_ordinal_number,
+        )),
+        |day| (1..=31).contains(day),
+    )(input)
+}
+
+/// Succeeds if a month isn't coming up, as in the " of march" of "the 5th of march", so that
+/// a day that doesn't exist in that month isn't read as a day of some other month.
+fn not_of_month(input: &str) -> IResult<&str, ()> {
+    not(tuple((space1, tag("of"), space1, parse_month_name)))(input)
+}
+
+/// Parses a month and a day on their own, eg. "january 27" or "jan 27th".
+fn parse_month_day(input: &str) -> IResult<&str, Matched<FlexibleDate>> {
+    map(parse_month_and_day, |(month, day)| {
+        Matched::exact(FlexibleDate::MonthDay { month, day })
+    })(input)
+}
+
+/// The value of a roman numeral like "ix", or `None` if it isn't written the standard way.
+fn roman_numeral_value(numeral: &str) -> Option<u32> {
+    const DIGITS: [(&str, u32); 13] = [
+        ("m", 1000),
+        ("cm", 900),
+        ("d", 500),
+        ("cd", 400),
+        ("c", 100),
+        ("xc", 90),
+        ("l", 50),
+        ("xl", 40),
+        ("x", 10),
+        ("ix", 9),
+        ("v", 5),
+        ("iv", 4),
+        ("i", 1),
+    ];
+
+    let mut rest = numeral;
+    let mut value = 0;
+    for (digit, digit_value) in DIGITS {
+        while let Some(stripped) = rest.strip_prefix(digit) {
+            rest = stripped;
+            value += digit_value;
+        }
+    }
+    if !rest.is_empty() || value == 0 {
+        return None;
+    }
+
+    // writing the value back out catches numerals like "iiii" or "ixi" that aren't standard
+    let mut standard = String::new();
+    let mut remaining = value;
+    for (digit, digit_value) in DIGITS {
+        while remaining >= digit_value {
+            standard.push_str(digit);
+            remaining -= digit_value;
+        }
+    }
+    (standard == numeral).then_some(value)
+}
+
+/// Parses a (lowercase) roman numeral, eg. "xii".
+fn parse_roman_numeral(input: &str) -> IResult<&str, u32> {
+    map_opt(take_while1(|c| "ivxlcdm".contains(c)), roman_numeral_value)(input)
+}
+
+/// Parses an ISO 8601 date, eg. "2024-01-27".
+fn parse_iso_date(input: &str) -> IResult<&str, Matched<FlexibleDate>> {
+    map_opt(
+        tuple((
+            verify(digit1, |year: &str| year.len() == 4),
+            tag("-"),
+            map_res(digit1, str::parse::<u32>),
+            tag("-"),
+            map_res(digit1, str::parse::<u32>),
+        )),
+        |(year, _, month, _, day)| {
+            let year = year.parse().ok()?;
+            NaiveDate::from_ymd_opt(year, month, day)?;
+            let month = Month::from_number(month)?;
+            Some(Matched::exact(FlexibleDate::CalendarDate {
+                year,
+                month,
+                day,
+            }))
+        },
+    )(input)
+}
+
+/// Parses a date separated by slashes, with or without a year, eg. "01/27" or "01/27/2024". The
+/// locale's [`DateOrder`] says whether the month or the day comes first, and two-digit years are
+/// in the 2000s. Dates without a year are matched to their next occurrence. When both numbers are
+/// single digits, the date might be a fraction: it isn't matched when a word follows it, as in
+/// "3/4 cup" (other than "at", as in "3/4 at noon"), and is [ambiguous](Matched::ambiguous)
+/// otherwise.
+fn parse_slash_date<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleDate>> {
+    let part = || take_while_m_n(1, 2, |c: char| c.is_ascii_digit());
+    let year = branch::alt((
+        take_while_m_n(4, 4, |c: char| c.is_ascii_digit()),
+        take_while_m_n(2, 2, |c: char| c.is_ascii_digit()),
+    ));
+    map_opt(
+        tuple((
+            part(),
+            tag("/"),
+            part(),
+            opt(tuple((tag("/"), year))),
+            not(satisfy(|c| c.is_ascii_digit() || c == '/')),
+            peek(opt(preceded(space1, take_while1(char::is_alphabetic)))),
+        )),
+        |(first, _, second, year, (), next_word): (&str, _, &str, Option<(_, &str)>, _, _)| {
+            let (month, day) = match options.locale.date_order {
+                DateOrder::MonthFirst => (first, second),
+                DateOrder::DayFirst => (second, first),
+            };
+            let (month, day): (u32, u32) = (month.parse().ok()?, day.parse().ok()?);
+            if let Some((_, year)) = year {
+                let year = match year.parse::<i32>().ok()? {
+                    year if year < 100 => 2000 + year,
+                    year => year,
+                };
+                NaiveDate::from_ymd_opt(year, month, day)?;
+                return Some(Matched::exact(FlexibleDate::CalendarDate {
+                    year,
+                    month: Month::from_number(month)?,
+                    day,
+                }));
+            }
+            let month = Month::from_number(month)?;
+            if !(1..=month.max_days()).contains(&day) {
+                return None;
+            }
+            let fraction_like = first.len() == 1 && second.len() == 1;
+            if fraction_like && next_word.is_some_and(|word| word != "at") {
+                return None;
+            }
+            Some(Matched::exact(FlexibleDate::MonthDay { month, day }).ambiguous_if(fraction_like))
+        },
+    )(input)
+}
+
+/// Parses a day-first date separated by dots, eg. "27.09.2024". The month can also be a roman
+/// numeral if the options allow it. The year needs all four digits and can't run on into another
+/// number, so that version numbers like "1.2.3" or "1.2.2024.5" aren't dates.
+fn parse_dotted_date<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleDate>> {
+    fn part(input: &str) -> IResult<&str, u32> {
+        map_res(
+            take_while_m_n(1, 2, |c: char| c.is_ascii_digit()),
+            str::parse::<u32>,
+        )(input)
+    }
+    let month_number = |input| {
+        if options.roman_numeral_months {
+            branch::alt((part, parse_roman_numeral))(input)
+        } else {
+            part(input)
+        }
+    };
+    map_opt(
+        tuple((
+            part,
+            tag("."),
+            month_number,
+            tag("."),
+            map_res(
+                verify(recognize(parse_standalone_number), |year: &str| {
+                    year.len() == 4
+                }),
+                str::parse::<i32>,
+            ),
+        )),
+        |(day, _, month, _, year)| {
+            NaiveDate::from_ymd_opt(year, month, day)?;
+            let month = Month::from_number(month)?;
+            Some(Matched::exact(FlexibleDate::CalendarDate {
+                year,
+                month,
+                day,
+            }))
+        },
+    )(input)
+}
+
+/// Parses the first of `words` that the input starts with.
+fn parse_one_of<'a>(input: &'a str, words: &[String]) -> IResult<&'a str, &'a str> {
+    words
+        .iter()
+        .find_map(|word| tag::<_, _, Error<&str>>(word.as_str())(input).ok())
+        .ok_or(Err::Error(Error {
+            input,
+            code: ErrorKind::Tag,
+        }))
+}
+
+/// Parses "next" and a weekday, eg. "next friday", which means whatever the
+/// `ParseOptions::next_weekday` policy says.
+fn parse_next_weekday<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleDate>> {
+    map(
+        tuple((tag("next"), space1, parse_weekday_name)),
+        |(_, _, day)| {
+            Matched::exact(match options.next_weekday {
+                WeekdayPolicy::NextOccurrence => FlexibleDate::NextWeekday(day),
+                WeekdayPolicy::FollowingWeek => FlexibleDate::Offset {
+                    amount: 1,
+                    unit: DateUnit::Week,
+                    anchor: Box::new(FlexibleDate::WeekdayThisWeek(day)),
+                },
+            })
+        },
+    )(input)
+}
+
+/// Parses "this" and a weekday, eg. "this saturday", which is the one in the current week even
+/// if it's already passed.
+fn parse_this_weekday(input: &str) -> IResult<&str, Matched<FlexibleDate>> {
+    map(
+        tuple((tag("this"), space1, parse_weekday_name)),
+        |(_, _, day)| Matched::exact(FlexibleDate::WeekdayThisWeek(day)),
+    )(input)
+}
+
+/// Parses a weekday that isn't part of a longer phrase, which might not be a date at all if it's
+/// written like "sat".
+fn parse_lone_weekday<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleDate>> {
+    map(
+        |input| parse_name(input, &WEEKDAY_NAMES, ""),
+        |(day, ambiguous)| {
+            let date = match options.weekday_scope {
+                WeekdayScope::Upcoming => FlexibleDate::Weekday(day),
+                WeekdayScope::CurrentWeek => FlexibleDate::WeekdayThisWeek(day),
+            };
+            Matched::exact(date).ambiguous_if(ambiguous)
+        },
+    )(input)
+}
+
+/// Parses a colloquial quantity like "a couple" into the amount configured for it.
+fn parse_fuzzy_quantity<'a>(input: &'a str, quantities: &FuzzyQuantities) -> IResult<&'a str, u32> {
     branch::alt((
+        // the "a" is left out after eg. "the next", as in "the next few days"
         value(
-            FlexibleDate::Weekday(Weekday::Sunday),
-            branch::alt((tag("sunday"), tag("sun"))),
+            quantities.couple,
+            tuple((opt(tag("a ")), tag("couple"), opt(tag(" of")))),
         ),
-        value(
-            FlexibleDate::Weekday(Weekday::Monday),
-            branch::alt((tag("monday"), tag("mon"))),
+        value(quantities.few, tuple((opt(tag("a ")), tag("few")))),
+        value(quantities.several, tag("several")),
+    ))(input)
+}
+
+/// Parses an amount of something, either as a number or as a colloquial quantity. Colloquial
+/// quantities are approximate.
+fn parse_quantity<'a>(input: &'a str, options: &ParseOptions) -> IResult<&'a str, Matched<u32>> {
+    branch::alt((
+        map(parse_standalone_number, Matched::exact),
+        map(parse_cardinal_word, Matched::exact),
+        map(
+            |input| parse_fuzzy_quantity(input, &options.fuzzy_quantities),
+            Matched::approximate,
         ),
+        // has to come after the fuzzy quantities so that "a few" isn't read as "a"
+        value(Matched::exact(1), branch::alt((tag("an"), tag("a")))),
+    ))(input)
+}
+
+fn parse_date_unit(input: &str) -> IResult<&str, DateUnit> {
+    branch::alt((
         value(
-            FlexibleDate::Weekday(Weekday::Tuesday),
-            branch::alt((tag("tuesday"), tag("tue"))),
+            DateUnit::Day,
+            branch::alt((tag("days"), tag("day"), tag("d"))),
         ),
         value(
-            FlexibleDate::Weekday(Weekday::Wednesday),
-            branch::alt((tag("wednesday"), tag("wed"))),
+            DateUnit::Week,
+            branch::alt((tag("weeks"), tag("week"), tag("wks"), tag("wk"), tag("w"))),
         ),
         value(
-            FlexibleDate::Weekday(Weekday::Thursday),
-            branch::alt((tag("thursday"), tag("thurs"))),
+            DateUnit::Month,
+            branch::alt((
+                tag("months"),
+                tag("month"),
+                tag("mths"),
+                tag("mth"),
+                tag("mos"),
+                tag("mo"),
+            )),
         ),
         value(
-            FlexibleDate::Weekday(Weekday::Friday),
-            branch::alt((tag("friday"), tag("fri"))),
+            DateUnit::Year,
+            branch::alt((tag("years"), tag("year"), tag("yrs"), tag("yr"), tag("y"))),
         ),
-        value(
-            FlexibleDate::Weekday(Weekday::Saturday),
-            branch::alt((tag("saturday"), tag("sat"))),
+    ))(input)
+}
+
+/// The date that's `amount` of `unit` after today.
+fn from_now(amount: u32, unit: &DateUnit) -> FlexibleDate {
+    match unit {
+        DateUnit::Day => FlexibleDate::DaysFromNow(amount),
+        DateUnit::Week => FlexibleDate::WeeksFromNow(amount),
+        DateUnit::Month => FlexibleDate::MonthsFromNow(amount),
+        DateUnit::Year => FlexibleDate::YearsFromNow(amount),
+    }
+}
+
+/// Parses a number of business days from today, eg. "in 5 business days" or "in 5 days
+/// skipping weekends".
+fn parse_business_days_from_now<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleDate>> {
+    let days = || branch::alt((tag("days"), tag("day")));
+    map(
+        tuple((
+            tag("in"),
+            space1,
+            |input| parse_quantity(input, options),
+            space1,
+            branch::alt((
+                value(
+                    (),
+                    tuple((
+                        branch::alt((tag("business"), tag("working"), tag("work"))),
+                        space1,
+                        days(),
+                    )),
+                ),
+                value(
+                    (),
+                    tuple((
+                        days(),
+                        space1,
+                        branch::alt((tag("skipping"), tag("excluding"), tag("not counting"))),
+                        space1,
+                        tag("weekends"),
+                    )),
+                ),
+            )),
+        )),
+        |(_, _, days, _, ())| days.map(FlexibleDate::BusinessDaysFromNow),
+    )(input)
+}
+
+/// Parses an amount of time from today, eg. "in 3 weeks", "in a few days", "a week from now",
+/// or just "2d".
+fn parse_offset_from_now<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleDate>> {
+    branch::alt((
+        map(
+            tuple((
+                tag("in"),
+                space1,
+                |input| parse_quantity(input, options),
+                space0,
+                parse_date_unit,
+            )),
+            |(_, _, amount, _, unit)| amount.map(|amount| from_now(amount, &unit)),
+        ),
+        map(
+            tuple((
+                |input| parse_quantity(input, options),
+                space1,
+                parse_date_unit,
+                space1,
+                tag("from now"),
+            )),
+            |(amount, _, unit, _, _)| amount.map(|amount| from_now(amount, &unit)),
+        ),
+        // CLI-style shorthand like "2d" has to be glued together
+        map(
+            tuple((map_res(digit1, str::parse::<u32>), parse_date_unit)),
+            |(amount, unit)| Matched::exact(from_now(amount, &unit)),
         ),
     ))(input)
 }
 
+/// Parses an amount of time counted from another date, eg. "3 weeks from friday", "two weeks
+/// from tomorrow", or "3 days after jan 5". The anchor can be any date, including another one of
+/// these, so "a day after 2 weeks from friday" works too.
+fn parse_anchored_offset<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleDate>> {
+    map(
+        tuple((
+            |input| parse_quantity(input, options),
+            space1,
+            parse_date_unit,
+            space1,
+            branch::alt((tag("from"), tag("after"))),
+            space1,
+            |input| parse_flex_date_exact(input, options),
+        )),
+        |(amount, _, unit, _, _, _, anchor)| {
+            let (specificity, ambiguous) = (amount.specificity, amount.ambiguous);
+            let offset = amount.and(anchor, |amount, anchor| FlexibleDate::Offset {
+                amount,
+                unit,
+                anchor: Box::new(anchor),
+            });
+            // after "from", the anchor is clearly a date, even a weekday written like "sat"
+            Matched {
+                ambiguous,
+                specificity,
+                ..offset
+            }
+        },
+    )(input)
+}
+
+/// Parses an amount of time counted from the start of a period, eg. "2 weeks into next quarter"
+/// or "3 days into the next month".
+fn parse_offset_into_period<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleDate>> {
+    map(
+        tuple((
+            |input| parse_quantity(input, options),
+            space1,
+            parse_date_unit,
+            space1,
+            tag("into"),
+            space1,
+            opt(tag("the ")),
+            parse_relative_period,
+        )),
+        |(amount, _, unit, _, _, _, _, period)| {
+            amount.map(|amount| FlexibleDate::Offset {
+                amount,
+                unit,
+                anchor: Box::new(FlexibleDate::StartOf(period)),
+            })
+        },
+    )(input)
+}
+
+/// Parses a weekday phrased to skip its nearest occurrence, eg. "not this friday, the next one"
+/// or "the friday after this one".
+fn parse_skipped_weekday(input: &str) -> IResult<&str, Matched<FlexibleDate>> {
+    let not_this_one = |input| {
+        let (input, (_, _, _, _, weekday, _, _, _, _)) = tuple((
+            tag("not"),
+            space1,
+            tag("this"),
+            space1,
+            parse_weekday_name,
+            opt(tag(",")),
+            space1,
+            opt(tag("the ")),
+            tag("next"),
+        ))(input)?;
+        let (input, _) = tuple((
+            space1,
+            branch::alt((
+                value((), tag("one")),
+                value(
+                    (),
+                    verify(parse_weekday_name, |repeated| *repeated == weekday),
+                ),
+            )),
+        ))(input)?;
+        Ok((input, weekday))
+    };
+    let after_this_one = map(
+        tuple((
+            opt(tag("the ")),
+            parse_weekday_name,
+            space1,
+            tag("after this"),
+            opt(tag(" one")),
+        )),
+        |(_, weekday, _, _, _)| weekday,
+    );
+
+    map(branch::alt((not_this_one, after_this_one)), |weekday| {
+        Matched::exact(FlexibleDate::Offset {
+            amount: 1,
+            unit: DateUnit::Week,
+            anchor: Box::new(FlexibleDate::Weekday(weekday)),
+        })
+    })(input)
+}
+
+/// Parses a weekday in a numbered week, eg. "the wednesday of week 14", or in planner shorthand
+/// like "fri w42", "fri wk42", or "tue cw3".
+fn parse_weekday_of_week(input: &str) -> IResult<&str, Matched<FlexibleDate>> {
+    let week_number = || {
+        verify(map_res(digit1, str::parse::<u32>), |week| {
+            (1..=53).contains(week)
+        })
+    };
+    map(
+        branch::alt((
+            map(
+                tuple((
+                    opt(tag("the ")),
+                    parse_weekday_name,
+                    space1,
+                    tag("of"),
+                    space1,
+                    tag("week"),
+                    space1,
+                    week_number(),
+                )),
+                |(_, weekday, _, _, _, _, _, week)| (weekday, week),
+            ),
+            map(
+                tuple((
+                    parse_weekday_name,
+                    space1,
+                    branch::alt((tag("cw"), tag("wk"), tag("w"))),
+                    space0,
+                    week_number(),
+                )),
+                |(weekday, _, _, _, week)| (weekday, week),
+            ),
+        )),
+        |(weekday, week)| Matched::exact(FlexibleDate::WeekdayOfWeek { weekday, week }),
+    )(input)
+}
+
 /// Try to parse a string into a `FlexibleDate` starting at the beginning of the string
 ///
 /// NOTE: This expects `input` to have be converted to lower case
-pub(crate) fn parse_flex_date_exact(input: &str) -> IResult<&str, FlexibleDate> {
-    branch::alt((parse_today, parse_tomorrow, parse_weekday))(input)
+pub(crate) fn parse_flex_date_exact<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleDate>> {
+    branch::alt((
+        rule(Rule::ApproximateDate, options, |input| {
+            parse_approximate_date(input, options)
+        }),
+        rule(Rule::IsoDate, options, parse_iso_date),
+        rule(Rule::DottedDate, options, |input| {
+            parse_dotted_date(input, options)
+        }),
+        rule(Rule::SlashDate, options, |input| {
+            parse_slash_date(input, options)
+        }),
+        rule(Rule::BusinessDaysFromNow, options, |input| {
+            parse_business_days_from_now(input, options)
+        }),
+        rule(Rule::OffsetFromNow, options, |input| {
+            parse_offset_from_now(input, options)
+        }),
+        // has to come before "this week"
+        rule(
+            Rule::Weekend,
+            options,
+            map(parse_this_or_next_weekend, Matched::exact),
+        ),
+        rule(Rule::ThisWeek, options, |input| {
+            parse_this_week(input, options)
+        }),
+        rule(Rule::LastBusinessDay, options, parse_last_business_day),
+        rule(Rule::PeriodSegment, options, parse_period_segment),
+        // has to come before "the middle of" midpoints
+        branch::alt((
+            rule(Rule::PeriodSection, options, |input| {
+                parse_period_section(input, options)
+            }),
+            rule(Rule::Midpoint, options, parse_midpoint),
+        )),
+        rule(
+            Rule::PeriodAfterNext,
+            options,
+            parse_start_of_period_after_next,
+        ),
+        rule(Rule::OffsetFromWeekday, options, |input| {
+            parse_anchored_offset(input, options)
+        }),
+        rule(Rule::OffsetIntoPeriod, options, |input| {
+            parse_offset_into_period(input, options)
+        }),
+        rule(Rule::WeekdayOfWeek, options, parse_weekday_of_week),
+        rule(Rule::SkippedWeekday, options, parse_skipped_weekday),
+        rule(Rule::NextWeekday, options, |input| {
+            parse_next_weekday(input, options)
+        }),
+        rule(Rule::Weekday, options, |input| {
+            parse_lone_weekday(input, options)
+        }),
+        rule(Rule::MonthDay, options, parse_month_day),
+        // has to come before plain days of the month
+        rule(Rule::DayInMonth, options, parse_day_in_month),
+        branch::alt((
+            rule(Rule::ThisWeekday, options, parse_this_weekday),
+            rule(Rule::LaterThisWeek, options, |input| {
+                parse_later_this_week(input, options)
+            }),
+            rule(Rule::EndOfWeek, options, |input| {
+                parse_end_of_week(input, options)
+            }),
+            rule(Rule::Today, options, map(parse_today, Matched::exact)),
+            rule(Rule::Tomorrow, options, map(parse_tomorrow, Matched::exact)),
+            // has to come before "next year"
+            rule(Rule::MonthInYear, options, parse_month_in_year),
+            rule(Rule::NextMonthOrYear, options, parse_next_month_or_year),
+            rule(
+                Rule::DayOfMonth,
+                options,
+                map(parse_day_of_month, Matched::exact),
+            ),
+            rule(Rule::BareOrdinal, options, parse_bare_ordinal),
+            rule(Rule::Year, options, |input| parse_year(input, options)),
+        )),
+    ))(input)
 }
 
-/// Try to parse a string into a `FlexibleDate` starting at the beginning of the string.
-/// Only succeeds if it can parse the date as a complete collection of tokens.
-fn parse_flex_date_with_suffix(input: &str) -> IResult<&str, FlexibleDate> {
-    let (remainder, date) = parse_flex_date_exact(input)?;
+/// Parses a date marked as approximate with a leading word, eg. "around the 15th" or "about a
+/// week from now".
+fn parse_approximate_date<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleDate>> {
+    let tolerance = options.approximate_date_tolerance;
+    map(
+        tuple((
+            branch::alt((
+                tag("around"),
+                tag("about"),
+                tag("approximately"),
+                tag("roughly"),
+            )),
+            space1,
+            |input| parse_flex_date_exact(input, options),
+        )),
+        move |(_, _, date)| date.within(tolerance),
+    )(input)
+}
 
-    // make sure that the next character in the output (if there is one) is a space
-    if remainder.is_empty() || remainder.chars().next().is_some_and(char::is_whitespace) {
-        Ok((remainder, date))
-    } else {
-        // gross
-        Err(Err::Error(Error {
-            input,
-            code: ErrorKind::Char,
-        }))
-    }
+fn parse_soon<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleDateRange>> {
+    value(
+        Matched::approximate(FlexibleDateRange {
+            start: FlexibleDate::Today,
+            end: FlexibleDate::DaysFromNow(options.soon_days),
+        }),
+        tag("soon"),
+    )(input)
 }
 
-// TODO: docs
-pub(crate) fn parse_flex_date(input: &str) -> Option<Parsed<FlexibleDate>> {
-    let mut input = &input.to_lowercase()[..];
-    let mut offset = 0;
-    while parse_flex_date_with_suffix(input).is_err() && !input.is_empty() {
-        // eat a token
-        let (remainder, (token, space)) = tuple((not_whitespace, space1))(input).ok()?;
-        input = remainder;
-        offset += token.len() + space.len();
-    }
-    parse_flex_date_exact(input)
-        .ok()
-        .map(|(remainder, date)| Parsed {
-            data: date,
-            range: offset..(offset + input.len() - remainder.len()),
-        })
+fn parse_period_unit(input: &str) -> IResult<&str, PeriodUnit> {
+    branch::alt((
+        value(PeriodUnit::Weekend, tag("weekend")),
+        value(PeriodUnit::Week, tag("week")),
+        value(PeriodUnit::Month, tag("month")),
+        value(PeriodUnit::Quarter, tag("quarter")),
+        value(PeriodUnit::Year, tag("year")),
+    ))(input)
 }
 
-#[cfg(test)]
-mod tests {
-    #![allow(clippy::unwrap_used)]
+/// Parses a period relative to the current one, eg. "next week"
+fn parse_relative_period(input: &str) -> IResult<&str, Period> {
+    map(
+        tuple((
+            branch::alt((value(0, tag("this")), value(1, tag("next")))),
+            space1,
+            parse_period_unit,
+        )),
+        |(offset, _, unit)| Period { unit, offset },
+    )(input)
+}
 
-    use crate::Weekday;
+/// Parses a period that skips over the next one, eg. "the weekend after next (weekend)"
+fn parse_period_after_next(input: &str) -> IResult<&str, Period> {
+    let (input, (_, unit, _, _, _)) = tuple((
+        opt(tag("the ")),
+        parse_period_unit,
+        space1,
+        tag("after"),
+        space1,
+    ))(input)?;
+    let (input, _) = tag("next")(input)?;
 
-    use super::*;
+    // the unit can optionally be repeated, but only if it's the same one
+    let (input, _) = opt(tuple((space1, |input| {
+        let (remainder, repeated) = parse_period_unit(input)?;
+        if repeated == unit {
+            Ok((remainder, repeated))
+        } else {
+            Err(Err::Error(Error {
+                input,
+                code: ErrorKind::Tag,
+            }))
+        }
+    })))(input)?;
 
-    #[test]
-    fn test_parse_today() {
-        let (_, result) = parse_today("today").unwrap();
-        assert_eq!(result, FlexibleDate::Today);
+    Ok((input, Period { unit, offset: 2 }))
+}
 
-        let (_, result) = parse_today("tod").unwrap();
-        assert_eq!(result, FlexibleDate::Today);
-    }
+fn parse_start_of_period_after_next(input: &str) -> IResult<&str, Matched<FlexibleDate>> {
+    map(parse_period_after_next, |period| {
+        Matched::exact(FlexibleDate::StartOf(period))
+    })(input)
+}
 
-    #[test]
-    fn test_parse_tomorrow() {
-        let (_, result) = parse_tomorrow("tomorrow").unwrap();
-        assert_eq!(result, FlexibleDate::Tomorrow);
+/// Parses the last business day of a period, eg. "the last weekday of the month" or "last
+/// business day of next month".
+fn parse_last_business_day(input: &str) -> IResult<&str, Matched<FlexibleDate>> {
+    map(
+        tuple((
+            opt(tag("the ")),
+            tag("last"),
+            space1,
+            branch::alt((
+                tag("weekday"),
+                tag("business day"),
+                tag("working day"),
+                tag("workday"),
+            )),
+            space1,
+            tag("of"),
+            space1,
+            branch::alt((
+                parse_relative_period,
+                map(
+                    tuple((tag("the"), space1, parse_period_unit)),
+                    |(_, _, unit)| Period { unit, offset: 0 },
+                ),
+            )),
+        )),
+        |(_, _, _, _, _, _, _, period)| Matched::exact(FlexibleDate::LastBusinessDayOf(period)),
+    )(input)
+}
 
-        let (_, result) = parse_tomorrow("tom").unwrap();
-        assert_eq!(result, FlexibleDate::Tomorrow);
+fn parse_segment(input: &str) -> IResult<&str, Segment> {
+    branch::alt((
+        value(Segment::Early, tag("early")),
+        value(Segment::Mid, tag("mid")),
+        value(Segment::Late, tag("late")),
+    ))(input)
+}
 
-        let (_, result) = parse_tomorrow("tmrw").unwrap();
-        assert_eq!(result, FlexibleDate::Tomorrow);
+/// Parses a rough part of a period or a month, eg. "early next month" or "mid-january".
+fn parse_period_segment(input: &str) -> IResult<&str, Matched<FlexibleDate>> {
+    let (input, (segment, _)) = tuple((parse_segment, branch::alt((space1, tag("-")))))(input)?;
+    if let Ok((input, period)) = parse_relative_period(input) {
+        return Ok((
+            input,
+            Matched::approximate(FlexibleDate::SegmentOf(segment, period)),
+        ));
     }
+    let (input, month) = parse_month_name(input)?;
+    Ok((
+        input,
+        Matched::approximate(FlexibleDate::SegmentOfMonth(segment, month)),
+    ))
+}
 
-    #[test]
-    fn test_parse_weekday() {
-        let (_, result) = parse_weekday("sunday").unwrap();
-        assert_eq!(result, FlexibleDate::Weekday(crate::Weekday::Sunday));
+/// Parses the beginning, middle, or end of a month, quarter, or year, eg. "the end of the year" or
+/// "the beginning of next month". What these mean depends on `ParseOptions::period_sections`.
+fn parse_period_section<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleDate>> {
+    let (input, (_, segment, _, _, _, period)) = tuple((
+        opt(tag("the ")),
+        branch::alt((
+            value(
+                Segment::Early,
+                branch::alt((tag("beginning"), tag("start"))),
+            ),
+            value(Segment::Mid, tag("middle")),
+            value(Segment::Late, tag("end")),
+        )),
+        space1,
+        tag("of"),
+        space1,
+        // weeks have their own rules for this
+        verify(parse_named_period, |period| {
+            matches!(
+                period.unit,
+                PeriodUnit::Month | PeriodUnit::Quarter | PeriodUnit::Year
+            )
+        }),
+    ))(input)?;
+    let date = match options.period_sections {
+        SectionPolicy::Boundaries => Matched::exact(match segment {
+            Segment::Early => FlexibleDate::StartOf(period),
+            Segment::Mid => FlexibleDate::MidpointOf(period),
+            Segment::Late => FlexibleDate::EndOf(period),
+        }),
+        SectionPolicy::Segments => Matched::approximate(FlexibleDate::SegmentOf(segment, period)),
+    };
+    Ok((input, date))
+}
 
-        let (_, result) = parse_weekday("sat").unwrap();
-        assert_eq!(result, FlexibleDate::Weekday(crate::Weekday::Saturday));
-    }
+/// Parses the middle of a period, eg. "halfway through the month", "the middle of next week", or
+/// "midway through q2".
+fn parse_midpoint(input: &str) -> IResult<&str, Matched<FlexibleDate>> {
+    let (input, ()) = branch::alt((
+        value(
+            (),
+            tuple((
+                branch::alt((tag("halfway"), tag("half way"), tag("midway"))),
+                space1,
+                tag("through"),
+                space1,
+            )),
+        ),
+        value(
+            (),
+            tuple((opt(tag("the ")), tag("middle"), space1, tag("of"), space1)),
+        ),
+    ))(input)?;
+    branch::alt((
+        map(
+            tuple((
+                tag("q"),
+                verify(parse_day_number, |quarter| (1..=4).contains(quarter)),
+            )),
+            |(_, quarter)| Matched::exact(FlexibleDate::MidpointOfQuarter(quarter)),
+        ),
+        map(parse_named_period, |period| {
+            Matched::exact(FlexibleDate::MidpointOf(period))
+        }),
+    ))(input)
+}
 
-    #[test]
-    fn test_parse_flex_date_exact() {
-        let (_, result) = parse_flex_date_exact("tomorrow").unwrap();
-        assert_eq!(result, FlexibleDate::Tomorrow);
+/// Parses a period that's being divided up, eg. the "next week" in "the middle of next week",
+/// or "the month", meaning this one.
+fn parse_named_period(input: &str) -> IResult<&str, Period> {
+    branch::alt((
+        parse_relative_period,
+        parse_period_after_next,
+        map(
+            tuple((tag("the"), space1, parse_period_unit)),
+            |(_, _, unit)| Period { unit, offset: 0 },
+        ),
+    ))(input)
+}
 
-        let (_, result) = parse_flex_date_exact("tod").unwrap();
-        assert_eq!(result, FlexibleDate::Today);
-    }
+fn parse_sometime(input: &str) -> IResult<&str, Matched<FlexibleDateRange>> {
+    map(
+        tuple((
+            branch::alt((tag("sometime"), tag("some time"))),
+            space1,
+            parse_relative_period,
+        )),
+        |(_, _, period)| {
+            let precision = period.unit.precision();
+            Matched::approximate(FlexibleDateRange {
+                start: FlexibleDate::StartOf(period.clone()),
+                end: FlexibleDate::EndOf(period),
+            })
+            .precise(precision)
+        },
+    )(input)
+}
 
-    #[test]
-    fn test_parse_flex_date_substring() {
-        let Parsed { data, range } = parse_flex_date("tomorrow after").unwrap();
-        assert_eq!(data, FlexibleDate::Tomorrow);
-        assert_eq!(range, (0..8));
+/// Parses "this weekend" or "next weekend".
+fn parse_this_or_next_weekend(input: &str) -> IResult<&str, FlexibleDate> {
+    map(
+        tuple((
+            branch::alt((
+                value(FlexibleDate::ThisWeekend, tag("this")),
+                value(FlexibleDate::NextWeekend, tag("next")),
+            )),
+            space1,
+            tag("weekend"),
+            word_end,
+        )),
+        |(date, _, _, ())| date,
+    )(input)
+}
 
-        let Parsed { data, range } = parse_flex_date("before tomorrow").unwrap();
-        assert_eq!(data, FlexibleDate::Tomorrow);
-        assert_eq!(range, (7..15));
+/// Parses "next month" or "next year" as a single date, which is only as precise as the month or
+/// year.
+fn parse_next_month_or_year(input: &str) -> IResult<&str, Matched<FlexibleDate>> {
+    map(
+        tuple((
+            tag("next"),
+            space1,
+            branch::alt((
+                value((FlexibleDate::NextMonth, Precision::Month), tag("month")),
+                value((FlexibleDate::NextYear, Precision::Year), tag("year")),
+            )),
+            word_end,
+        )),
+        |(_, _, (date, precision), ())| Matched::exact(date).precise(precision),
+    )(input)
+}
 
-        let input = "before tomorrow after";
-        let Parsed { data, range } = parse_flex_date(input).unwrap();
-        assert_eq!(data, FlexibleDate::Tomorrow);
-        assert_eq!(range, (7..15));
-        assert_eq!(&input[range], "tomorrow");
+/// Parses a month in a year counted from the current one, eg. "march next year", "in march this
+/// year", or "next year in march".
+fn parse_month_in_year(input: &str) -> IResult<&str, Matched<FlexibleDate>> {
+    let year = || {
+        branch::alt((
+            value(0, tuple((tag("this"), space1, tag("year"), word_end))),
+            value(1, tuple((tag("next"), space1, tag("year"), word_end))),
+        ))
+    };
+    map(
+        branch::alt((
+            map(
+                tuple((
+                    opt(tuple((tag("in"), space1))),
+                    parse_month_name,
+                    space1,
+                    year(),
+                )),
+                |(_, month, _, offset)| (month, offset),
+            ),
+            map(
+                tuple((year(), space1, tag("in"), space1, parse_month_name)),
+                |(offset, _, _, _, month)| (month, offset),
+            ),
+        )),
+        |(month, offset)| {
+            Matched::exact(FlexibleDate::MonthInYear { month, offset }).precise(Precision::Month)
+        },
+    )(input)
+}
 
-        let Parsed { data, range } = parse_flex_date("do a barrel roll tod").unwrap();
-        assert_eq!(data, FlexibleDate::Today);
-        assert_eq!(range, (17..20));
+/// Parses a four-digit number as a year, eg. the "2026" in "in 2026".
+fn parse_year_digits(input: &str) -> IResult<&str, i32> {
+    terminated(
+        map_res(
+            verify(parse_standalone_number, |year| {
+                (1000..10_000).contains(year)
+            }),
+            i32::try_from,
+        ),
+        word_end,
+    )(input)
+}
 
-        let Parsed { data, range } = parse_flex_date("go home fri okay").unwrap();
-        assert_eq!(data, FlexibleDate::Weekday(Weekday::Friday));
-        assert_eq!(range, (8..11));
+/// Parses the number of a year on its own after a word that introduces a date, eg. "in 2026",
+/// "by 2027", or "due 2026". A number without one is left alone, since it's more often some
+/// other number, like the ones in "room 1999" or "buy 2000 screws".
+fn parse_year_number<'a>(input: &'a str, options: &ParseOptions) -> IResult<&'a str, Matched<i32>> {
+    let locale = &options.locale;
+    map(
+        preceded(
+            tuple((
+                branch::alt((
+                    |input| parse_one_of(input, &locale.date_prepositions),
+                    |input| parse_one_of(input, &locale.date_triggers),
+                )),
+                space1,
+            )),
+            parse_year_digits,
+        ),
+        |year| Matched::exact(year).precise(Precision::Year),
+    )(input)
+}
+
+/// The single date that a year on its own means under `ParseOptions::year_only`, if it means one.
+fn year_as_date(options: &ParseOptions) -> Option<fn(i32) -> FlexibleDate> {
+    match options.year_only {
+        YearPolicy::FirstDay => Some(FlexibleDate::StartOfYear),
+        YearPolicy::LastDay => Some(FlexibleDate::EndOfYear),
+        YearPolicy::WholeYear => None,
     }
+}
 
-    #[test]
-    fn text_parse_variations() {
-        let Parsed { data, .. } = parse_flex_date("Today").unwrap();
-        assert_eq!(data, FlexibleDate::Today);
+/// The range covering the whole of a year.
+fn whole_year(year: i32) -> Matched<FlexibleDateRange> {
+    Matched::exact(FlexibleDateRange {
+        start: FlexibleDate::StartOfYear(year),
+        end: FlexibleDate::EndOfYear(year),
+    })
+    .precise(Precision::Year)
+}
 
-        let Parsed { data, .. } = parse_flex_date("toMorRoW").unwrap();
-        assert_eq!(data, FlexibleDate::Tomorrow);
+/// Parses a year on its own as a single date, which depends on `ParseOptions::year_only`.
+fn parse_year<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleDate>> {
+    let date = year_as_date(options);
+    map_opt(
+        |input| parse_year_number(input, options),
+        move |year| date.map(|date| year.map(date)),
+    )(input)
+}
+
+/// Parses a year on its own as a range covering the whole year, eg. "in 2026" or "during 2027".
+fn parse_whole_year(input: &str) -> IResult<&str, Matched<FlexibleDateRange>> {
+    map(
+        preceded(
+            tuple((branch::alt((tag("in"), tag("during"))), space1)),
+            parse_year_digits,
+        ),
+        whole_year,
+    )(input)
+}
+
+/// Parses text that's nothing but a year, eg. "2026", as a single date like [`parse_year`]. The
+/// other parsers leave out bare years, since they also scan text for dates, where a number on
+/// its own is more often something else. Text that's only a year can't be anything else.
+pub(crate) fn parse_lone_year<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleDate>> {
+    let date = year_as_date(options);
+    rule(
+        Rule::Year,
+        options,
+        map_opt(
+            all_consuming(terminated(parse_year_digits, space0)),
+            move |year| date.map(|date| Matched::exact(date(year)).precise(Precision::Year)),
+        ),
+    )(input)
+}
+
+/// Parses text that's nothing but a year, eg. "2026", as a range covering the whole year, like
+/// [`parse_lone_year`].
+pub(crate) fn parse_lone_whole_year(input: &str) -> IResult<&str, Matched<FlexibleDateRange>> {
+    map(
+        all_consuming(terminated(parse_year_digits, space0)),
+        whole_year,
+    )(input)
+}
+
+/// Parses "this week" as a single date, which only exists for some `ParseOptions::this_week`
+/// policies.
+fn parse_this_week<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleDate>> {
+    let date = match &options.this_week {
+        ThisWeekPolicy::WholeWeek => None,
+        ThisWeekPolicy::NextWorkday => Some(FlexibleDate::NextBusinessDay),
+        ThisWeekPolicy::EndOfWeek(day) => Some(FlexibleDate::WeekdayThisWeek(day.clone())),
+    };
+    map_opt(tuple((tag("this"), space1, tag("week"))), move |_| {
+        date.clone().map(Matched::exact)
+    })(input)
+}
+
+/// Parses "later this week", which is `ParseOptions::later_this_week_days` days from today at
+/// the latest, and approximate.
+fn parse_later_this_week<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleDate>> {
+    value(
+        Matched::approximate(FlexibleDate::LaterThisWeek(options.later_this_week_days)),
+        tuple((
+            tag("later"),
+            space1,
+            tag("this"),
+            space1,
+            tag("week"),
+            word_end,
+        )),
+    )(input)
+}
+
+/// Parses "end of the week" or "end of week", which is the last day of the week unless the
+/// `ParseOptions::this_week` policy says that the week ends on a particular day.
+fn parse_end_of_week<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleDate>> {
+    let date = match &options.this_week {
+        ThisWeekPolicy::EndOfWeek(day) => FlexibleDate::WeekdayThisWeek(day.clone()),
+        ThisWeekPolicy::WholeWeek | ThisWeekPolicy::NextWorkday => FlexibleDate::EndOf(Period {
+            unit: PeriodUnit::Week,
+            offset: 0,
+        }),
+    };
+    value(
+        Matched::exact(date),
+        tuple((
+            opt(tag("the ")),
+            tag("end of"),
+            space1,
+            opt(tag("the ")),
+            tag("week"),
+            word_end,
+        )),
+    )(input)
+}
+
+/// Parses "this week" as a range covering the whole week.
+fn parse_whole_this_week(input: &str) -> IResult<&str, Matched<FlexibleDateRange>> {
+    let this_week = Period {
+        unit: PeriodUnit::Week,
+        offset: 0,
+    };
+    value(
+        Matched::exact(FlexibleDateRange {
+            start: FlexibleDate::StartOf(this_week.clone()),
+            end: FlexibleDate::EndOf(this_week),
+        })
+        .precise(Precision::Week),
+        tuple((tag("this"), space1, tag("week"))),
+    )(input)
+}
+
+/// Parses the first or last week of a month as a range, eg. "the first week of march" or "the last
+/// week of the month". It's the whole week that has the month's first or last day in it, starting
+/// on the locale's [`week_start`](crate::Locale::week_start), so it can run into the month next
+/// to it.
+fn parse_week_of_month<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleDateRange>> {
+    let (input, (_, first, _, _, _, _, _, (first_day, last_day))) = tuple((
+        opt(tag("the ")),
+        branch::alt((
+            value(true, branch::alt((tag("first"), tag("1st")))),
+            value(false, tag("last")),
+        )),
+        space1,
+        tag("week"),
+        space1,
+        tag("of"),
+        space1,
+        branch::alt((
+            map(
+                verify(parse_named_period, |period| {
+                    period.unit == PeriodUnit::Month
+                }),
+                |period| {
+                    (
+                        FlexibleDate::StartOf(period.clone()),
+                        FlexibleDate::EndOf(period),
+                    )
+                },
+            ),
+            map_opt(parse_month_name, |month| {
+                let next_month = Month::from_number(month.number() % 12 + 1)?;
+                Some((
+                    FlexibleDate::MonthDay { month, day: 1 },
+                    FlexibleDate::DayBefore(Box::new(FlexibleDate::MonthDay {
+                        month: next_month,
+                        day: 1,
+                    })),
+                ))
+            }),
+        )),
+    ))(input)?;
+
+    let start = FlexibleDate::WeekdayOnOrBefore {
+        weekday: options.locale.week_start.clone(),
+        anchor: Box::new(if first { first_day } else { last_day }),
+    };
+    let end = FlexibleDate::Offset {
+        amount: 6,
+        unit: DateUnit::Day,
+        anchor: Box::new(start.clone()),
+    };
+    let range = Matched::exact(FlexibleDateRange { start, end }).precise(Precision::Week);
+    Ok((input, range))
+}
+
+fn parse_whole_period_after_next(input: &str) -> IResult<&str, Matched<FlexibleDateRange>> {
+    map(parse_period_after_next, |period| {
+        let precision = period.unit.precision();
+        Matched::exact(FlexibleDateRange {
+            start: FlexibleDate::StartOf(period.clone()),
+            end: FlexibleDate::EndOf(period),
+        })
+        .precise(precision)
+    })(input)
+}
+
+/// Parses a range between two dates, eg. "from monday to friday", "june 5 through june 9", or
+/// "june 5 - june 9". Without a "from", the dates have to be joined by something other than
+/// "to", so that eg. "move tuesday to friday" isn't a range.
+fn parse_between_dates<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleDateRange>> {
+    let through = || {
+        branch::alt((
+            value(
+                (),
+                tuple((
+                    space1,
+                    branch::alt((tag("through"), tag("thru"), tag("until"), tag("till"))),
+                    space1,
+                )),
+            ),
+            value((), tuple((space0, one_of("-\u{2013}"), space0))),
+        ))
+    };
+    let (input, from) = opt(tuple((tag("from"), space1)))(input)?;
+    let (input, start) = parse_flex_date_exact(input, options)?;
+    let (input, ()) = if from.is_some() {
+        branch::alt((value((), tuple((space1, tag("to"), space1))), through()))(input)?
+    } else {
+        through()(input)?
+    };
+    let (input, end) = parse_flex_date_exact(input, options)?;
+    Ok((
+        input,
+        start.and(end, |start, end| FlexibleDateRange { start, end }),
+    ))
+}
+
+/// Parses a window of time starting today, eg. "for the next two weeks", "in the next 5 days",
+/// or "within 3 days". Where the window ends depends on `ParseOptions::window_end`.
+fn parse_upcoming_window<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleDateRange>> {
+    let quantity = |input| {
+        map(
+            tuple((|input| parse_quantity(input, options), space1)),
+            |(amount, _)| amount,
+        )(input)
+    };
+    map(
+        branch::alt((
+            map(
+                tuple((
+                    branch::alt((tag("for"), tag("over"), tag("in"))),
+                    space1,
+                    tag("the next"),
+                    space1,
+                    opt(quantity),
+                    parse_date_unit,
+                )),
+                |(_, _, _, _, amount, unit)| (amount.unwrap_or(Matched::exact(1)), unit),
+            ),
+            map(
+                tuple((tag("within"), space1, quantity, parse_date_unit)),
+                |(_, _, amount, unit)| (amount, unit),
+            ),
+        )),
+        |(amount, unit)| {
+            amount.map(|amount| FlexibleDateRange {
+                start: FlexibleDate::Today,
+                end: window_end(amount, &unit, &options.window_end),
+            })
+        },
+    )(input)
+}
+
+/// The last day of a window that's `amount` of `unit` long and starts today.
+fn window_end(amount: u32, unit: &DateUnit, end: &WindowEnd) -> FlexibleDate {
+    let through = from_now(amount, unit);
+    match end {
+        WindowEnd::Through => through,
+        WindowEnd::Before => FlexibleDate::DayBefore(Box::new(through)),
+    }
+}
+
+/// Try to parse a string into a `FlexibleDateRange` starting at the beginning of the string
+///
+/// NOTE: This expects `input` to have be converted to lower case
+pub(crate) fn parse_flex_date_range_exact<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleDateRange>> {
+    branch::alt((
+        |input| parse_between_dates(input, options),
+        |input| parse_week_of_month(input, options),
+        |input| parse_soon(input, options),
+        |input| parse_upcoming_window(input, options),
+        parse_whole_this_week,
+        parse_sometime,
+        parse_whole_period_after_next,
+        parse_whole_year,
+    ))(input)
+}
+
+/// Parses a range with only one bound, eg. "anytime before friday", "after the 15th", or
+/// "by tomorrow". "Before" and "after" leave out the date itself, while "by" and "until"
+/// include it.
+pub(crate) fn parse_open_date_range_exact<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<OpenDateRange>> {
+    #[derive(Clone)]
+    enum Bound {
+        Before,
+        Until,
+        After,
+        From,
+    }
+
+    let (input, _) = opt(tuple((
+        branch::alt((tag("anytime"), tag("any time"), tag("sometime"))),
+        space1,
+    )))(input)?;
+    let (input, (bound, _, date)) = tuple((
+        branch::alt((
+            value(Bound::Until, tag("on or before")),
+            value(Bound::From, tag("on or after")),
+            value(Bound::Before, tag("before")),
+            value(Bound::Until, branch::alt((tag("until"), tag("by")))),
+            value(Bound::After, tag("after")),
+        )),
+        space1,
+        |input| parse_flex_date_exact(input, options),
+    ))(input)?;
+
+    let range = date.map(|date| match bound {
+        Bound::Before => OpenDateRange {
+            start: None,
+            end: Some(FlexibleDate::DayBefore(Box::new(date))),
+        },
+        Bound::Until => OpenDateRange {
+            start: None,
+            end: Some(date),
+        },
+        Bound::After => OpenDateRange {
+            start: Some(FlexibleDate::Offset {
+                amount: 1,
+                unit: DateUnit::Day,
+                anchor: Box::new(date),
+            }),
+            end: None,
+        },
+        Bound::From => OpenDateRange {
+            start: Some(date),
+            end: None,
+        },
+    });
+    Ok((input, range))
+}
+
+/// Finds the first `OpenDateRange` in `input`.
+pub(crate) fn parse_open_date_range(
+    input: &str,
+    options: &ParseOptions,
+) -> Option<Parsed<OpenDateRange>> {
+    find_in_str(input, options, parse_open_date_range_exact)
+}
+
+/// Parses an hour with no minutes or meridiem, eg. the "5" in "tomorrow at 5". Hours that could
+/// be either AM or PM are resolved using `ParseOptions::bare_hour_window_start` and marked as
+/// inferred.
+fn parse_bare_hour<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleTime>> {
+    let (remainder, hour) = parse_standalone_number(input)?;
+    match hour {
+        1..=12 => Ok((
+            remainder,
+            Matched::inferred(FlexibleTime {
+                hour: hour_in_window(hour, options),
+                minute: 0,
+            }),
+        )),
+        0 | 13..=23 => Ok((remainder, Matched::exact(FlexibleTime { hour, minute: 0 }))),
+        _ => Err(Err::Error(Error {
+            input,
+            code: ErrorKind::Verify,
+        })),
+    }
+}
+
+/// Puts an hour from 1 to 12 on a 24-hour clock, within the twelve hours starting at
+/// `ParseOptions::bare_hour_window_start`.
+fn hour_in_window(hour: u32, options: &ParseOptions) -> u32 {
+    let am_hour = hour % 12;
+    let hours_into_window = (am_hour + 24 - options.bare_hour_window_start % 24) % 24;
+    if hours_into_window < 12 {
+        am_hour
+    } else {
+        am_hour + 12
+    }
+}
+
+/// Parses a time with minutes, eg. "5:30pm", "17:00", or "09:15". Like bare hours, hours from 1
+/// to 12 without a meridiem or a leading zero, as in "5:30", are placed using
+/// `ParseOptions::bare_hour_window_start` and marked as inferred.
+fn parse_clock_time<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleTime>> {
+    let digits = |count| take_while_m_n(count, 2, |c: char| c.is_ascii_digit());
+    let (remainder, (hour_digits, _, minute, meridiem)) = tuple((
+        digits(1),
+        tag(":"),
+        map_res(
+            terminated(digits(2), not(satisfy(|c| c.is_ascii_digit()))),
+            str::parse::<u32>,
+        ),
+        opt(preceded(space0, parse_meridiem)),
+    ))(input)?;
+    let hour = hour_digits.parse::<u32>().unwrap_or(u32::MAX);
+    let time = |hour| FlexibleTime { hour, minute };
+    let matched = match (meridiem, hour) {
+        (Some(meridiem), 1..=12) if minute < 60 => {
+            Some(Matched::exact(time(meridiem.to_24_hour(hour))))
+        }
+        (None, 1..=12) if minute < 60 && !hour_digits.starts_with('0') => {
+            Some(Matched::inferred(time(hour_in_window(hour, options))))
+        }
+        (None, 0..=23) if minute < 60 => Some(Matched::exact(time(hour))),
+        _ => None,
+    };
+    match matched {
+        Some(matched) => Ok((remainder, matched.precise(Precision::Minute))),
+        None => Err(Err::Error(Error {
+            input,
+            code: ErrorKind::Verify,
+        })),
+    }
+}
+
+/// Marks a time as at least as precise as its hour, since times that don't give minutes, like
+/// "5pm" or "noon", still name an hour.
+fn to_the_hour(time: Matched<FlexibleTime>) -> Matched<FlexibleTime> {
+    let precision = time.precision.max(Precision::Hour);
+    time.precise(precision)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Meridiem {
+    Am,
+    Pm,
+}
+
+impl Meridiem {
+    /// Converts an hour on a 12-hour clock to one on a 24-hour clock.
+    fn to_24_hour(self, hour: u32) -> u32 {
+        match self {
+            Meridiem::Am => hour % 12,
+            Meridiem::Pm => hour % 12 + 12,
+        }
+    }
+}
+
+/// Parses a meridiem, with or without periods, eg. "pm", "p.m." or "p. m.".
+fn parse_meridiem(input: &str) -> IResult<&str, Meridiem> {
+    map(
+        tuple((
+            branch::alt((value(Meridiem::Am, tag("a")), value(Meridiem::Pm, tag("p")))),
+            opt(tuple((tag("."), space0))),
+            tag("m"),
+            opt(tag(".")),
+        )),
+        |(meridiem, _, _, _)| meridiem,
+    )(input)
+}
+
+/// Parses an hour followed by a meridiem, eg. "9am" or "5 pm".
+fn parse_meridiem_hour(input: &str) -> IResult<&str, Matched<FlexibleTime>> {
+    map(
+        tuple((
+            verify(parse_standalone_number, |hour| (1..=12).contains(hour)),
+            space0,
+            parse_meridiem,
+        )),
+        |(hour, _, meridiem)| {
+            Matched::exact(FlexibleTime {
+                hour: meridiem.to_24_hour(hour),
+                minute: 0,
+            })
+        },
+    )(input)
+}
+
+/// Settles the meridiem of a time whose meridiem was inferred, using some other hint in the
+/// input (eg. "evening").
+fn settle_meridiem(time: Matched<FlexibleTime>, meridiem: Meridiem) -> Matched<FlexibleTime> {
+    if time.inferred {
+        Matched {
+            data: FlexibleTime {
+                hour: meridiem.to_24_hour(time.data.hour),
+                ..time.data
+            },
+            inferred: false,
+            ..time
+        }
+    } else {
+        time
+    }
+}
+
+/// Parses "noon" or "midnight", optionally preceded by "12" if the options allow it.
+fn parse_noon_or_midnight<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleTime>> {
+    let (input, _) = if options.twelve_with_words {
+        opt(tuple((tag("12"), space1)))(input)?
+    } else {
+        (input, None)
+    };
+    branch::alt((
+        value(
+            Matched::exact(FlexibleTime {
+                hour: 12,
+                minute: 0,
+            }),
+            tag("noon"),
+        ),
+        value(
+            Matched::exact(match options.midnight {
+                MidnightPolicy::EndOfDay => FlexibleTime {
+                    hour: 24,
+                    minute: 0,
+                },
+                MidnightPolicy::StartOfDay => FlexibleTime { hour: 0, minute: 0 },
+            }),
+            tag("midnight"),
+        ),
+    ))(input)
+}
+
+/// Parses the end of the workday, eg. "cob" or "close of business".
+fn parse_close_of_business<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleTime>> {
+    value(
+        Matched::exact(options.close_of_business.clone()),
+        branch::alt((tag("cob"), tag("close of business"), tag("end of business"))),
+    )(input)
+}
+
+/// Parses one of the idioms registered in the options, eg. "first thing" or "end of play".
+fn parse_idiom<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleTime>> {
+    options
+        .keywords
+        .idioms
+        .iter()
+        .find_map(|idiom| {
+            tag::<_, _, Error<&str>>(idiom.phrase.as_str())(input)
+                .ok()
+                .map(|(rest, _)| (rest, idiom))
+        })
+        .map(|(rest, idiom)| {
+            let time = match &idiom.time {
+                IdiomTime::WorkdayStart => options.workday_start.clone(),
+                IdiomTime::CloseOfBusiness => options.close_of_business.clone(),
+                IdiomTime::At(time) => time.clone(),
+            };
+            (rest, Matched::exact(time))
+        })
+        .ok_or(Err::Error(Error {
+            input,
+            code: ErrorKind::Tag,
+        }))
+}
+
+fn parse_exact_time<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleTime>> {
+    branch::alt((
+        |input| parse_noon_or_midnight(input, options),
+        |input| parse_clock_time(input, options),
+        parse_meridiem_hour,
+        rule(Rule::BareHour, options, |input| {
+            parse_bare_hour(input, options)
+        }),
+    ))(input)
+}
+
+/// Parses a time marked as approximate, either with a leading word ("around 3") or with an "ish"
+/// suffix ("noonish", "5-ish").
+fn parse_approximate_time<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleTime>> {
+    let tolerance = options.approximate_time_tolerance;
+    branch::alt((
+        map(
+            tuple((
+                branch::alt((
+                    tag("around"),
+                    tag("about"),
+                    tag("approximately"),
+                    tag("roughly"),
+                )),
+                space1,
+                |input| parse_exact_time(input, options),
+            )),
+            move |(_, _, time)| time.within(tolerance),
+        ),
+        map(
+            tuple((
+                |input| parse_exact_time(input, options),
+                opt(tag("-")),
+                tag("ish"),
+            )),
+            move |(time, _, _)| time.within(tolerance),
+        ),
+    ))(input)
+}
+
+/// Parses the name of one of the named times registered in the options, eg. "lunch".
+fn parse_named_time<'a, 'o>(
+    input: &'a str,
+    options: &'o ParseOptions,
+) -> IResult<&'a str, &'o NamedTime> {
+    let (input, _) = opt(tag("the "))(input)?;
+    options
+        .keywords
+        .named_times
+        .iter()
+        .find_map(|named| {
+            tag::<_, _, Error<&str>>(named.name.as_str())(input)
+                .ok()
+                .map(|(rest, _)| (rest, named))
+        })
+        .ok_or(Err::Error(Error {
+            input,
+            code: ErrorKind::Tag,
+        }))
+}
+
+/// Parses a time relative to a named time, eg. "after lunch" or "before work".
+fn parse_relative_named_time<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleTime>> {
+    let (input, (after, _)) = tuple((
+        branch::alt((value(true, tag("after")), value(false, tag("before")))),
+        space1,
+    ))(input)?;
+    let (input, named) = parse_named_time(input, options)?;
+    let time = match (&named.end, after) {
+        (Some(end), true) => end.clone(),
+        _ => named.start.clone(),
+    };
+    Ok((input, Matched::approximate(time)))
+}
+
+fn parse_flex_time<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleTime>> {
+    branch::alt((
+        |input| parse_approximate_time(input, options),
+        |input| parse_exact_time(input, options),
+        |input| parse_close_of_business(input, options),
+        |input| parse_idiom(input, options),
+        map(
+            |input| parse_named_time(input, options),
+            |named| Matched::exact(named.start.clone()),
+        ),
+    ))(input)
+}
+
+/// Parses a time of day on its own, eg. "5pm", "at 17:00", or "noon". Bare hours like "5" need
+/// an "at" in front of them, or to be marked as approximate like "around 5" or "5ish", since
+/// otherwise they're usually just numbers.
+pub(crate) fn parse_standalone_time_exact<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleTime>> {
+    let mut without_bare_hours = options.clone();
+    without_bare_hours.disabled_rules.push(Rule::BareHour);
+    let result = map(
+        branch::alt((
+            map(
+                tuple((tag("at"), space1, |input| parse_flex_time(input, options))),
+                |(_, _, time)| time,
+            ),
+            |input| parse_approximate_time(input, options),
+            |input| parse_flex_time(input, &without_bare_hours),
+        )),
+        to_the_hour,
+    )(input);
+    result
+}
+
+/// Parses a part of the day that trails a date or time, eg. " in the morning" or " at night".
+/// Along with the meridiem it implies, gives a representative time for when there's no other
+/// time to go by.
+fn parse_day_part_qualifier(input: &str) -> IResult<&str, (FlexibleTime, Meridiem)> {
+    let at = |hour| FlexibleTime { hour, minute: 0 };
+    let (input, _) = space1(input)?;
+    branch::alt((
+        map(
+            tuple((
+                tag("in the"),
+                space1,
+                branch::alt((
+                    value((at(9), Meridiem::Am), tag("morning")),
+                    value((at(15), Meridiem::Pm), tag("afternoon")),
+                    value((at(19), Meridiem::Pm), tag("evening")),
+                )),
+            )),
+            |(_, _, day_part)| day_part,
+        ),
+        value((at(21), Meridiem::Pm), tag("at night")),
+    ))(input)
+}
+
+/// Parses a part of today, eg. "tonight" or "this morning", along with a representative time and
+/// the meridiem it implies, like [`parse_day_part_qualifier`].
+fn parse_part_of_today(input: &str) -> IResult<&str, (FlexibleTime, Meridiem)> {
+    let at = |hour| FlexibleTime { hour, minute: 0 };
+    branch::alt((
+        value((at(21), Meridiem::Pm), tag("tonight")),
+        map(
+            tuple((
+                tag("this"),
+                space1,
+                branch::alt((
+                    value((at(9), Meridiem::Am), tag("morning")),
+                    value((at(15), Meridiem::Pm), tag("afternoon")),
+                    value((at(19), Meridiem::Pm), tag("evening")),
+                )),
+            )),
+            |(_, _, day_part)| day_part,
+        ),
+    ))(input)
+}
+
+/// Parses the date that follows a time, like the "tomorrow" in "midnight tomorrow" or the "on the
+/// 3rd" in "9am on the 3rd". A part of today, as in "midnight tonight", comes with the meridiem
+/// it implies.
+fn parse_date_after_time<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, (Matched<FlexibleDate>, Option<Meridiem>)> {
+    branch::alt((
+        map(
+            tuple((opt(tag("on ")), |input| {
+                parse_flex_date_exact(input, options)
+            })),
+            |(_, date)| (date, None),
+        ),
+        map(
+            rule(
+                Rule::Today,
+                options,
+                map(parse_part_of_today, Matched::exact),
+            ),
+            |part_of_today| {
+                let (_, meridiem) = part_of_today.data.clone();
+                (part_of_today.map(|_| FlexibleDate::Today), Some(meridiem))
+            },
+        ),
+    ))(input)
+}
+
+/// Parses the time that follows a date, like the "at 3pm" in "tomorrow at 3pm". Only times that
+/// can't be mistaken for other numbers can leave out the "at", as in "tomorrow 3pm".
+fn parse_time_after_date<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleTime>> {
+    branch::alt((
+        // "at 3pm", "@ 3pm", "@3pm"
+        map(
+            tuple((
+                branch::alt((terminated(tag("at"), space1), terminated(tag("@"), space0))),
+                |input| parse_flex_time(input, options),
+            )),
+            |(_, time)| time,
+        ),
+        // approximate times like "around 3" don't need an "at"
+        |input| parse_approximate_time(input, options),
+        |input| parse_relative_named_time(input, options),
+        |input| parse_close_of_business(input, options),
+        |input| parse_idiom(input, options),
+        // neither do times that can't be mistaken for other numbers, like "2pm"
+        |input| parse_noon_or_midnight(input, options),
+        |input| parse_clock_time(input, options),
+        parse_meridiem_hour,
+    ))(input)
+}
+
+/// Try to parse a string into a `FlexibleDateTime` starting at the beginning of the string
+///
+/// NOTE: This expects `input` to have be converted to lower case
+pub(crate) fn parse_flex_datetime_exact<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleDateTime>> {
+    let combine = |date: Matched<FlexibleDate>, time| {
+        date.and(to_the_hour(time), |date, time| FlexibleDateTime {
+            date,
+            time,
+            all_day: false,
+        })
+    };
+    branch::alt((
+        map(
+            tuple((
+                |input| parse_flex_date_exact(input, options),
+                opt(tag(",")),
+                space1,
+                |input| parse_time_after_date(input, options),
+                opt(parse_day_part_qualifier),
+            )),
+            move |(date, _, _, time, day_part)| {
+                let time = match day_part {
+                    Some((_, meridiem)) => settle_meridiem(time, meridiem),
+                    None => time,
+                };
+                combine(date, time)
+            },
+        ),
+        // "friday in the morning", "friday, in the evening"
+        map(
+            tuple((
+                |input| parse_flex_date_exact(input, options),
+                opt(tag(",")),
+                parse_day_part_qualifier,
+            )),
+            move |(date, _, (time, _))| combine(date, Matched::approximate(time)),
+        ),
+        // "cob friday", "midnight tomorrow", "first thing monday", "9am on the 3rd", "midnight
+        // tonight"
+        map(
+            tuple((
+                branch::alt((
+                    |input| parse_close_of_business(input, options),
+                    |input| parse_noon_or_midnight(input, options),
+                    |input| parse_idiom(input, options),
+                    |input| parse_clock_time(input, options),
+                    parse_meridiem_hour,
+                    // "at 5 tomorrow"
+                    map(
+                        tuple((tag("at"), space1, |input| parse_flex_time(input, options))),
+                        |(_, _, time)| time,
+                    ),
+                )),
+                space1,
+                |input| parse_date_after_time(input, options),
+            )),
+            move |(time, _, (date, meridiem))| {
+                let time = match meridiem {
+                    Some(meridiem) => settle_meridiem(time, meridiem),
+                    None => time,
+                };
+                combine(date, time)
+            },
+        ),
+        // "tonight", "this morning at 10"
+        map(
+            tuple((
+                rule(
+                    Rule::Today,
+                    options,
+                    map(parse_part_of_today, Matched::exact),
+                ),
+                opt(tuple((space1, tag("at"), space1, |input| {
+                    parse_flex_time(input, options)
+                }))),
+            )),
+            move |(part_of_today, time)| {
+                let (default_time, meridiem) = part_of_today.data.clone();
+                let time = match time {
+                    Some((_, _, _, time)) => settle_meridiem(time, meridiem),
+                    None => Matched::approximate(default_time),
+                };
+                combine(part_of_today.map(|_| FlexibleDate::Today), time)
+            },
+        ),
+        // a date on its own lasts all day
+        map(
+            |input| parse_flex_date_exact(input, options),
+            |date| {
+                date.map(|date| FlexibleDateTime {
+                    date,
+                    time: FlexibleTime { hour: 0, minute: 0 },
+                    all_day: true,
+                })
+            },
+        ),
+        // "at 5", "5pm"
+        map(
+            |input| parse_standalone_time_exact(input, options),
+            move |time| {
+                let date = match options.time_only {
+                    TimeOnlyPolicy::Upcoming => FlexibleDate::TodayOrTomorrow,
+                    TimeOnlyPolicy::Today => FlexibleDate::Today,
+                };
+                combine(Matched::exact(date), time)
+            },
+        ),
+    ))(input)
+}
+
+/// Parses the part of a recurrence after "every", eg. "monday" or "evening". A part of the day
+/// doesn't narrow down which days the recurrence happens on, but it does hint at the meridiem
+/// of its time.
+fn parse_frequency(input: &str) -> IResult<&str, (Frequency, Option<Meridiem>)> {
+    let yearly = |(month, day)| (Frequency::Yearly { month, day }, None);
+    branch::alt((
+        // "month" comes before weekdays so that it isn't read as "mon"
+        map(
+            tuple((tag("month"), word_end, opt(parse_on_day_of_month))),
+            |(_, (), day)| (Frequency::Monthly(day), None),
+        ),
+        map(parse_weekday_name, |day| {
+            (Frequency::Weekly(Some(day)), None)
+        }),
+        value(
+            (Frequency::BusinessDaily, None),
+            tuple((
+                branch::alt((
+                    tag("weekday"),
+                    tag("workday"),
+                    tag("working day"),
+                    tag("business day"),
+                )),
+                opt(tag("s")),
+                word_end,
+            )),
+        ),
+        value(
+            (Frequency::Weekly(None), None),
+            tuple((tag("week"), word_end)),
+        ),
+        map(
+            tuple((tag("year"), space1, tag("on"), space1, parse_month_and_day)),
+            move |(_, _, _, _, month_and_day)| yearly(month_and_day),
+        ),
+        map(parse_month_and_day, yearly),
+        value((Frequency::Daily, None), tuple((tag("day"), word_end))),
+        value(
+            (Frequency::Daily, Some(Meridiem::Am)),
+            tuple((tag("morning"), word_end)),
+        ),
+        value(
+            (Frequency::Daily, Some(Meridiem::Pm)),
+            tuple((
+                branch::alt((tag("afternoon"), tag("evening"), tag("night"))),
+                word_end,
+            )),
+        ),
+    ))(input)
+}
+
+/// Parses the day that a monthly recurrence happens on, eg. " on the 15th".
+fn parse_on_day_of_month(input: &str) -> IResult<&str, u32> {
+    map(
+        tuple((
+            space1,
+            tag("on"),
+            space1,
+            opt(tag("the ")),
+            parse_day_number,
+        )),
+        |(_, _, _, _, day)| day,
+    )(input)
+}
+
+/// Parses an ordinal number, either with digits like "3rd" or as a word like "third".
+fn parse_ordinal_number(input: &str) -> IResult<&str, u32> {
+    branch::alt((
+        map(
+            tuple((
+                map_res(digit1, str::parse::<u32>),
+                branch::alt((tag("st"), tag("nd"), tag("rd"), tag("th"))),
+            )),
+            |(number, _)| number,
+        ),
+        parse_ordinal_word,
+    ))(input)
+}
+
+/// Parses a frequency with an interval, eg. the "3rd day" in "every 3rd day", the "other week"
+/// in "every other week", or the "2 weeks" in "every 2 weeks". Only units of time can follow an
+/// ordinal or a count, so eg. "2nd tuesday" isn't an interval, but "other tuesday" is.
+fn parse_interval_frequency(input: &str) -> IResult<&str, (Frequency, u32)> {
+    let unit = |plural| {
+        move |input| {
+            let (input, frequency) = branch::alt((
+                value(Frequency::Daily, tag("day")),
+                value(Frequency::Weekly(None), tag("week")),
+                map(tag("month"), |_| Frequency::Monthly(None)),
+            ))(input)?;
+            let (input, _) = if plural {
+                tag("s")(input)?
+            } else {
+                (input, "")
+            };
+            let (input, ()) = word_end(input)?;
+            match frequency {
+                Frequency::Monthly(_) => map(opt(parse_on_day_of_month), Frequency::Monthly)(input),
+                frequency => Ok((input, frequency)),
+            }
+        }
+    };
+    branch::alt((
+        map(
+            tuple((
+                verify(parse_ordinal_number, |interval| *interval > 0),
+                space1,
+                unit(false),
+            )),
+            |(interval, _, frequency)| (frequency, interval),
+        ),
+        map(
+            tuple((
+                tag("other"),
+                space1,
+                branch::alt((
+                    unit(false),
+                    map(parse_weekday_name, |day| Frequency::Weekly(Some(day))),
+                )),
+            )),
+            |(_, _, frequency)| (frequency, 2),
+        ),
+        map(
+            tuple((
+                verify(
+                    branch::alt((map_res(digit1, str::parse::<u32>), parse_cardinal_word)),
+                    |interval| *interval > 1,
+                ),
+                space1,
+                unit(true),
+            )),
+            |(interval, _, frequency)| (frequency, interval),
+        ),
+    ))(input)
+}
+
+/// Parses a frequency written as an adverb, eg. "weekly on thursdays" or "monthly on the 15th".
+/// This is an alternative to "every" followed by a frequency.
+fn parse_adverb_frequency(input: &str) -> IResult<&str, (Frequency, Option<Meridiem>)> {
+    branch::alt((
+        value((Frequency::Daily, None), tag("daily")),
+        map(
+            tuple((
+                tag("weekly"),
+                opt(map(
+                    tuple((
+                        space1,
+                        tag("on"),
+                        space1,
+                        branch::alt((parse_weekday_plural, parse_weekday_name)),
+                    )),
+                    |(_, _, _, day)| day,
+                )),
+            )),
+            |(_, day)| (Frequency::Weekly(day), None),
+        ),
+        map(
+            tuple((tag("monthly"), opt(parse_on_day_of_month))),
+            |(_, day)| (Frequency::Monthly(day), None),
+        ),
+        map(
+            tuple((
+                branch::alt((tag("yearly"), tag("annually"))),
+                space1,
+                tag("on"),
+                space1,
+                parse_month_and_day,
+            )),
+            |(_, _, _, _, (month, day))| (Frequency::Yearly { month, day }, None),
+        ),
+    ))(input)
+}
+
+/// Parses the time that a recurrence happens at, eg. " at 9am".
+fn parse_recurrence_time<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleTime>> {
+    map(
+        tuple((space1, tag("at"), space1, |input| {
+            parse_flex_time(input, options)
+        })),
+        |(_, _, _, time)| time,
+    )(input)
+}
+
+/// Parses the date that a recurrence starts on, eg. " starting tomorrow" or " from friday".
+fn parse_recurrence_start<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleDate>> {
+    map(
+        tuple((
+            space1,
+            branch::alt((tag("starting"), tag("beginning"), tag("from"))),
+            space1,
+            opt(tuple((branch::alt((tag("on"), tag("from"))), space1))),
+            |input| parse_flex_date_exact(input, options),
+        )),
+        |(_, _, _, _, date)| date,
+    )(input)
+}
+
+/// Parses an exception for holidays at the end of a recurrence, eg. " except holidays".
+fn parse_except_holidays(input: &str) -> IResult<&str, ()> {
+    value(
+        (),
+        tuple((
+            space1,
+            branch::alt((tag("except"), tag("excluding"), tag("but not"))),
+            space1,
+            opt(tag("on ")),
+            branch::alt((
+                tag("holidays"),
+                tag("public holidays"),
+                tag("bank holidays"),
+            )),
+        )),
+    )(input)
+}
+
+/// Parses when a recurrence stops, eg. " until june 1" or " for 5 weeks".
+fn parse_recurrence_end<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<RecurrenceEnd>> {
+    preceded(
+        space1,
+        branch::alt((
+            map(
+                tuple((
+                    branch::alt((tag("until"), tag("till"), tag("through"))),
+                    space1,
+                    |input| parse_flex_date_exact(input, options),
+                )),
+                |(_, _, date)| date.map(RecurrenceEnd::Until),
+            ),
+            map(
+                tuple((tag("for"), space1, |input| {
+                    parse_flex_duration_exact(input, options)
+                })),
+                |(_, _, duration)| duration.map(RecurrenceEnd::For),
+            ),
+        )),
+    )(input)
+}
+
+/// Try to parse a string into a `Recurrence` starting at the beginning of the string
+///
+/// NOTE: This expects `input` to have be converted to lower case
+pub(crate) fn parse_recurrence_exact<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<Recurrence>> {
+    let (input, (frequency, meridiem, interval)) = branch::alt((
+        map(
+            tuple((
+                |input| parse_one_of(input, &options.locale.recurrence_triggers),
+                space1,
+                parse_interval_frequency,
+            )),
+            |(_, _, (frequency, interval))| (frequency, None, interval),
+        ),
+        map(
+            tuple((
+                |input| parse_one_of(input, &options.locale.recurrence_triggers),
+                space1,
+                parse_frequency,
+            )),
+            |(_, _, (frequency, meridiem))| (frequency, meridiem, 1),
+        ),
+        map(parse_adverb_frequency, |(frequency, meridiem)| {
+            (frequency, meridiem, 1)
+        }),
+    ))(input)?;
+
+    // the time can come before or after the start and end dates
+    let (input, time) = opt(|input| parse_recurrence_time(input, options))(input)?;
+    let (input, start) = opt(|input| parse_recurrence_start(input, options))(input)?;
+    let (input, time) = match time {
+        Some(time) => (input, Some(time)),
+        None => opt(|input| parse_recurrence_time(input, options))(input)?,
+    };
+    let (input, end) = opt(|input| parse_recurrence_end(input, options))(input)?;
+    let (input, time) = match time {
+        Some(time) => (input, Some(time)),
+        None => opt(|input| parse_recurrence_time(input, options))(input)?,
+    };
+    let (input, except_holidays) = opt(parse_except_holidays)(input)?;
+    let (input, time) = match time {
+        Some(time) => (input, Some(time)),
+        None => opt(|input| parse_recurrence_time(input, options))(input)?,
+    };
+
+    let mut recurrence = Matched::exact(Recurrence {
+        interval,
+        except_holidays: except_holidays.is_some(),
+        leap_day_policy: options.leap_day_policy.clone(),
+        ..Recurrence::new(frequency)
+    });
+    if let Some(time) = time {
+        let time = match meridiem {
+            Some(meridiem) => settle_meridiem(time, meridiem),
+            None => time,
+        };
+        recurrence = recurrence.and(time, |recurrence, time| Recurrence {
+            time: Some(time),
+            ..recurrence
+        });
+    }
+    if let Some(start) = start {
+        recurrence = recurrence.and(start, |recurrence, start| Recurrence {
+            start: Some(start),
+            ..recurrence
+        });
+    }
+    if let Some(end) = end {
+        recurrence = recurrence.and(end, |recurrence, end| Recurrence {
+            end: Some(end),
+            ..recurrence
+        });
+    }
+    Ok((input, recurrence))
+}
+
+/// Try to parse a string into a `Schedule` starting at the beginning of the string. A time or
+/// end written after the last recurrence also applies to the ones before it that don't have
+/// their own, eg. both recurrences in "every monday and every thursday at 9" are at 9.
+///
+/// NOTE: This expects `input` to have be converted to lower case
+pub(crate) fn parse_schedule_exact<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<Schedule>> {
+    let separator = branch::alt((
+        value(
+            (),
+            tuple((tag(","), space1, opt(tuple((tag("and"), space1))))),
+        ),
+        value((), tuple((space1, tag("and"), space1))),
+    ));
+    map(
+        tuple((
+            |input| parse_recurrence_exact(input, options),
+            many0(map(
+                tuple((separator, |input| parse_recurrence_exact(input, options))),
+                |((), recurrence)| recurrence,
+            )),
+        )),
+        |(first, rest)| {
+            let first = first.map(|recurrence| Schedule {
+                recurrences: vec![recurrence],
+            });
+            let mut schedule = rest.into_iter().fold(first, |schedule, recurrence| {
+                schedule.and(recurrence, |mut schedule, recurrence| {
+                    schedule.recurrences.push(recurrence);
+                    schedule
+                })
+            });
+            if let Some(last) = schedule.data.recurrences.last().cloned() {
+                for recurrence in &mut schedule.data.recurrences {
+                    recurrence.time = recurrence.time.take().or_else(|| last.time.clone());
+                    recurrence.end = recurrence.end.take().or_else(|| last.end.clone());
+                }
+            }
+            schedule
+        },
+    )(input)
+}
+
+/// Runs `parser`, unless `rule` is one of the [`ParseOptions::disabled_rules`], and holds the
+/// match to the rule's [specificity](Rule::specificity).
+fn rule<'a, T>(
+    rule: Rule,
+    options: &ParseOptions,
+    mut parser: impl FnMut(&'a str) -> IResult<&'a str, Matched<T>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Matched<T>> {
+    let disabled = options.disabled_rules.contains(&rule);
+    move |input| {
+        if disabled {
+            return Err(Err::Error(Error {
+                input,
+                code: ErrorKind::Not,
+            }));
+        }
+        let (remainder, matched) = parser(input)?;
+        let specificity = matched.specificity.min(rule.specificity());
+        Ok((
+            remainder,
+            Matched {
+                specificity,
+                ..matched
+            },
+        ))
+    }
+}
+
+/// How sure we are that a match really is the date or time it was read as, from 0 to 100. This
+/// starts from the match's specificity, and drops for each of its flags, for matches too short
+/// to say much, like "tom" or "2d", and for matches that needed a misspelling corrected.
+fn confidence<T>(matched: &Matched<T>, text: &str, typo: bool) -> u8 {
+    let penalties = [
+        (matched.ambiguous, 40),
+        (typo, 20),
+        (matched.approximate, 15),
+        (matched.inferred, 10),
+        (text.chars().count() <= 3, 10),
+    ];
+    penalties
+        .iter()
+        .filter(|(applies, _)| *applies)
+        .fold(matched.specificity, |confidence, (_, penalty)| {
+            confidence.saturating_sub(*penalty)
+        })
+}
+
+/// Try to parse a string using `parser` starting at the beginning of the string.
+/// Only succeeds if it can parse the string as a complete collection of tokens.
+fn parse_with_suffix<'a, T>(
+    input: &'a str,
+    parser: &mut impl FnMut(&'a str) -> IResult<&'a str, T>,
+) -> IResult<&'a str, T> {
+    let (remainder, data) = parser(input)?;
+
+    // make sure that the next character in the output (if there is one) is a space, allowing
+    // for punctuation that ends a phrase like the comma in "tomorrow, at the latest"
+    let after_punctuation = remainder
+        .strip_prefix(|c| matches!(c, ',' | '.' | ';' | '!' | '?'))
+        .unwrap_or(remainder);
+    if after_punctuation.is_empty()
+        || after_punctuation
+            .chars()
+            .next()
+            .is_some_and(char::is_whitespace)
+    {
+        Ok((remainder, data))
+    } else {
+        // gross
+        Err(Err::Error(Error {
+            input,
+            code: ErrorKind::Char,
+        }))
+    }
+}
+
+/// Scans through `input` for matches of `parser`, returning the one that
+/// [`ParseOptions::search`] prefers.
+fn find_in_str<T>(
+    text: &str,
+    options: &ParseOptions,
+    parser: impl for<'a> Fn(&'a str, &ParseOptions) -> IResult<&'a str, Matched<T>>,
+) -> Option<Parsed<T>> {
+    match options.search.prefer {
+        MatchPreference::First => find_first_in_str(text, options, parser),
+        MatchPreference::Last => find_all_in_str(text, options, parser).pop(),
+        MatchPreference::Longest => {
+            find_all_in_str(text, options, parser)
+                .into_iter()
+                .reduce(|longest, parsed| {
+                    if parsed.range.len() > longest.range.len() {
+                        parsed
+                    } else {
+                        longest
+                    }
+                })
+        }
+    }
+}
+
+/// Scans through `input` one token at a time, returning the first match of `parser` that
+/// covers a complete collection of tokens. With [`ParseOptions::only_after_triggers`], scanning
+/// starts after the first trigger word instead of at the beginning.
+///
+/// At each token, the parser runs with the main locale and then with each of the
+/// [`ParseOptions::extra_locales`] in turn, and the first locale to produce a match wins.
+/// Matches that are [ambiguous](Matched::ambiguous) are settled using the text around them,
+/// according to [`ParseOptions::ambiguous_names`]. With [`ParseOptions::typo_tolerance`], a token
+/// where nothing matches gets another try with its misspelled words corrected.
+///
+/// Only the first [`ParseOptions::max_scan_len`] bytes are scanned, and scanning stops between
+/// tokens once [`ParseOptions::cancel`] is cancelled.
+fn find_first_in_str<T>(
+    text: &str,
+    options: &ParseOptions,
+    parser: impl for<'a> Fn(&'a str, &ParseOptions) -> IResult<&'a str, Matched<T>>,
+) -> Option<Parsed<T>> {
+    let extra_options: Vec<_> = options
+        .extra_locales
+        .iter()
+        .map(|locale| ParseOptions {
+            locale: locale.clone(),
+            extra_locales: Vec::new(),
+            ..options.clone()
+        })
+        .collect();
+    let all_options: Vec<_> = std::iter::once(options).chain(&extra_options).collect();
+
+    let text = truncate(text, options.max_scan_len);
+    let (lowercase, original_offsets) = lowercase_with_offsets(text);
+    let corrected = options.typo_tolerance.then(|| correct_typos(&lowercase));
+    let mut input = &lowercase[..];
+    if options.only_after_triggers {
+        input = all_options
+            .iter()
+            .filter_map(|options| skip_past_trigger(input, &options.locale.date_triggers))
+            .max_by_key(|rest| rest.len())?;
+    }
+    let mut offset = lowercase.len() - input.len();
+    loop {
+        if options
+            .cancel
+            .as_ref()
+            .is_some_and(CancelToken::is_cancelled)
+        {
+            return None;
+        }
+        for options in &all_options {
+            let parse = |input| parse_with_suffix(input, &mut |input| parser(input, options));
+            let (len, mut matched, typo) = if let Ok((remainder, matched)) = parse(input) {
+                (input.len() - remainder.len(), matched, false)
+            } else {
+                // the corrected text lines up with the original at the start of each token
+                let Some((corrected, corrected_offsets)) = &corrected else {
+                    continue;
+                };
+                let start = corrected_offsets.partition_point(|&o| o < offset);
+                let Ok((remainder, matched)) = parse(&corrected[start..]) else {
+                    continue;
+                };
+                let end = corrected.len() - remainder.len();
+                let len = corrected_offsets[end] - offset;
+                let typo = corrected[start..end] != lowercase[offset..offset + len];
+                (len, matched, typo)
+            };
+            if matched.ambiguous
+                && all_options.iter().any(|options| {
+                    let original = &text[original_offsets[offset]..];
+                    has_date_context(original, &lowercase, offset, options)
+                })
+            {
+                matched.ambiguous = false;
+            }
+            let keep = match options.ambiguous_names {
+                AmbiguousNames::Accept => {
+                    matched.ambiguous = false;
+                    true
+                }
+                AmbiguousNames::Flag => true,
+                AmbiguousNames::RequireContext => !matched.ambiguous,
+            };
+            if keep {
+                let range = offset..(offset + len);
+                return Some(Parsed {
+                    confidence: confidence(&matched, &lowercase[range.clone()], typo),
+                    precision: matched.precision,
+                    data: matched.data,
+                    range: original_offsets[range.start]..original_offsets[range.end],
+                    approximate: matched.approximate,
+                    inferred: matched.inferred,
+                    tolerance: matched.tolerance,
+                    ambiguous: matched.ambiguous,
+                    locale: options.locale.name.clone(),
+                });
+            }
+        }
+
+        // eat a token
+        let (remainder, (token, space)) = tuple((not_whitespace, space1))(input).ok()?;
+        input = remainder;
+        offset += token.len() + space.len();
+    }
+}
+
+/// The start of `text`, up to `max_len` bytes long without splitting a character.
+fn truncate(text: &str, max_len: Option<usize>) -> &str {
+    let Some(mut end) = max_len.filter(|max_len| *max_len < text.len()) else {
+        return text;
+    };
+    while !text.is_char_boundary(end) {
+        end -= 1;
+    }
+    &text[..end]
+}
+
+/// Lowercases `text`, along with where each byte of the lowercased text (and its end) came from
+/// in `text`. The two can differ in length, since some characters change length when they're
+/// lowercased, like "İ".
+fn lowercase_with_offsets(text: &str) -> (String, Vec<usize>) {
+    let mut lowercase = String::with_capacity(text.len());
+    let mut original_offsets = Vec::with_capacity(text.len() + 1);
+    for (index, c) in text.char_indices() {
+        for lower in c.to_lowercase() {
+            lowercase.push(lower);
+        }
+        original_offsets.resize(lowercase.len(), index);
+    }
+    original_offsets.push(text.len());
+    (lowercase, original_offsets)
+}
+
+/// Whether the text around a match starting at `offset` suggests that it really is a date: it's
+/// capitalized somewhere other than the start of a sentence, as in "see you Sat", or it comes
+/// right after a word like "on" or "due". `original` is the text from the match onwards, as it
+/// was written.
+fn has_date_context(
+    original: &str,
+    lowercase: &str,
+    offset: usize,
+    options: &ParseOptions,
+) -> bool {
+    let before = lowercase[..offset].trim_end();
+    let starts_sentence = before.is_empty() || before.ends_with(['.', '!', '?']);
+    let capitalized = original.chars().next().is_some_and(char::is_uppercase);
+
+    let locale = &options.locale;
+    let previous_word = before
+        .rsplit(char::is_whitespace)
+        .next()
+        .unwrap_or_default();
+    let after_preposition = locale
+        .date_prepositions
+        .iter()
+        .any(|word| word == previous_word);
+    let after_trigger = locale.date_triggers.iter().any(|trigger| {
+        before
+            .strip_suffix(trigger.as_str())
+            .is_some_and(|rest| rest.is_empty() || rest.ends_with(char::is_whitespace))
+    });
+
+    (capitalized && !starts_sentence) || after_preposition || after_trigger
+}
+
+/// Finds the first of `triggers` in `input` that's a complete collection of tokens, and
+/// returns the text after it.
+fn skip_past_trigger<'a>(mut input: &'a str, triggers: &[String]) -> Option<&'a str> {
+    loop {
+        if let Ok((rest, _)) = parse_with_suffix(input, &mut |input| parse_one_of(input, triggers))
+        {
+            return Some(rest.trim_start());
+        }
+        let (remainder, _) = tuple((not_whitespace, space1))(input).ok()?;
+        input = remainder;
+    }
+}
+
+/// Finds the first `FlexibleDate` in `input`.
+pub(crate) fn parse_flex_date(input: &str, options: &ParseOptions) -> Option<Parsed<FlexibleDate>> {
+    find_in_str(input, options, parse_flex_date_exact)
+}
+
+/// Finds every `FlexibleDate` in `input`, in the order they appear.
+pub(crate) fn parse_all_flex_dates(
+    input: &str,
+    options: &ParseOptions,
+) -> Vec<Parsed<FlexibleDate>> {
+    find_all_in_str(input, options, parse_flex_date_exact)
+}
+
+/// Finds every `FlexibleDate` in `input`, along with the phrases that are written like dates but
+/// don't name a real day, like "feb 30". Phrases that overlap a date aren't reported.
+pub(crate) fn scan_flex_dates(
+    input: &str,
+    options: &ParseOptions,
+) -> (Vec<Parsed<FlexibleDate>>, Vec<Parsed<DateProblem>>) {
+    let dates = parse_all_flex_dates(input, options);
+    let problems = find_all_in_str(input, options, parse_invalid_date_exact)
+        .into_iter()
+        .filter(|problem| {
+            !dates.iter().any(|date| {
+                date.range.start < problem.range.end && problem.range.start < date.range.end
+            })
+        })
+        .collect();
+    (dates, problems)
+}
+
+/// Scans through `input` like [`find_first_in_str`], returning every match of `parser` in the order
+/// they appear. With [`ParseOptions::only_after_triggers`], only the first match needs a trigger
+/// in front of it.
+fn find_all_in_str<T>(
+    input: &str,
+    options: &ParseOptions,
+    parser: impl for<'a> Fn(&'a str, &ParseOptions) -> IResult<&'a str, Matched<T>>,
+) -> Vec<Parsed<T>> {
+    let Some(first) = find_first_in_str(input, options, &parser) else {
+        return Vec::new();
+    };
+
+    let mut options = ParseOptions {
+        only_after_triggers: false,
+        ..options.clone()
+    };
+    let max_scan_len = options.max_scan_len;
+    let mut offset = first.range.end;
+    let mut matches = vec![first];
+    loop {
+        let rest = input[offset..].trim_start();
+        offset = input.len() - rest.len();
+        // the limit is counted from the start of the whole text
+        options.max_scan_len = max_scan_len.map(|max_len| max_len.saturating_sub(offset));
+        let Some(mut parsed) = find_first_in_str(rest, &options, &parser) else {
+            return matches;
+        };
+        parsed.range = (parsed.range.start + offset)..(parsed.range.end + offset);
+        offset = parsed.range.end;
+        matches.push(parsed);
+    }
+}
+
+/// What's wrong with a date given as numbers, if anything. Without a year, the day only has to
+/// exist in some year.
+fn check_date(year: Option<i32>, month: u32, day: u32) -> Option<DateProblem> {
+    let Some(named_month) = Month::from_number(month) else {
+        return Some(DateProblem::NoSuchMonth(month));
+    };
+    let exists = match year {
+        Some(year) => NaiveDate::from_ymd_opt(year, month, day).is_some(),
+        None => (1..=named_month.max_days()).contains(&day),
+    };
+    (!exists).then_some(DateProblem::NoSuchDay {
+        year,
+        month: named_month,
+        day,
+    })
+}
+
+/// Parses something written like a date that doesn't name a real day, eg. "feb 30",
+/// "2023-02-29", or "13/01" with [`DateOrder::MonthFirst`]. Days have to be ones that some month
+/// could have, so that eg. "may 45 minutes" isn't taken for a broken date.
+fn parse_invalid_date_exact<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<DateProblem>> {
+    let number = || map_res(digit1, str::parse::<u32>);
+    let four_digit_year = || map_res(verify(digit1, |year: &str| year.len() == 4), str::parse);
+    let (remainder, (year, month, day)) = branch::alt((
+        map(
+            tuple((four_digit_year(), tag("-"), number(), tag("-"), number())),
+            |(year, _, month, _, day)| (Some(year), month, day),
+        ),
+        map(
+            tuple((number(), tag("."), number(), tag("."), four_digit_year())),
+            |(day, _, month, _, year)| (Some(year), month, day),
+        ),
+        map(
+            tuple((
+                number(),
+                tag("/"),
+                number(),
+                opt(tuple((tag("/"), four_digit_year()))),
+            )),
+            |(first, _, second, year)| {
+                let (month, day) = match options.locale.date_order {
+                    DateOrder::MonthFirst => (first, second),
+                    DateOrder::DayFirst => (second, first),
+                };
+                (year.map(|(_, year)| year), month, day)
+            },
+        ),
+        map(
+            tuple((parse_month_name, space1, parse_day_number)),
+            |(month, _, day)| (None, month.number(), day),
+        ),
+    ))(input)?;
+    let plausible_day = (1..=31).contains(&day) && (1..=31).contains(&month);
+    match check_date(year, month, day) {
+        Some(problem) if plausible_day => Ok((remainder, Matched::exact(problem))),
+        _ => Err(Err::Error(Error {
+            input,
+            code: ErrorKind::Verify,
+        })),
+    }
+}
+
+/// Parses one or more dates offered as alternatives, eg. "tuesday or wednesday".
+pub(crate) fn parse_interpretations_exact<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<Interpretations>> {
+    map(
+        separated_list1(tuple((space1, tag("or"), space1)), |input| {
+            parse_flex_date_exact(input, options)
+        }),
+        |dates| {
+            let from_alternatives = dates.len() > 1;
+            let empty = Matched::exact(Interpretations {
+                dates: Vec::new(),
+                from_alternatives,
+            });
+            dates.into_iter().fold(empty, |interpretations, date| {
+                interpretations.and(date, |mut interpretations, date| {
+                    interpretations.dates.push(date);
+                    interpretations
+                })
+            })
+        },
+    )(input)
+}
+
+/// Parses a list of two or more weekdays, eg. "mon, wed and fri" or "tuesday and thursday".
+fn parse_weekday_list<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<DateList>> {
+    let separator = branch::alt((
+        value(
+            (),
+            tuple((tag(","), space1, opt(tuple((tag("and"), space1))))),
+        ),
+        value((), tuple((space1, tag("and"), space1))),
+    ));
+    map(
+        tuple((
+            |input| parse_lone_weekday(input, options),
+            many1(map(
+                tuple((separator, |input| parse_lone_weekday(input, options))),
+                |((), day)| day,
+            )),
+        )),
+        |(first, rest)| {
+            let first = first.map(|date| DateList { dates: vec![date] });
+            rest.into_iter().fold(first, |list, date| {
+                list.and(date, |mut list, date| {
+                    list.dates.push(date);
+                    list
+                })
+            })
+        },
+    )(input)
+}
+
+/// Parses a number of upcoming weekdays or weekends, eg. "the next two mondays" or "next few
+/// weekends". Each weekend is given by its Saturday.
+fn parse_next_weekdays<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<DateList>> {
+    map(
+        tuple((
+            opt(tag("the ")),
+            tag("next"),
+            space1,
+            |input| parse_quantity(input, options),
+            space1,
+            branch::alt((
+                parse_weekday_plural,
+                value(Weekday::Saturday, tag("weekends")),
+            )),
+        )),
+        |(_, _, _, count, _, weekday)| {
+            count.map(|count| {
+                let dates = (0..count)
+                    .map(|weeks| match weeks {
+                        0 => FlexibleDate::Weekday(weekday.clone()),
+                        weeks => FlexibleDate::Offset {
+                            amount: weeks,
+                            unit: DateUnit::Week,
+                            anchor: Box::new(FlexibleDate::Weekday(weekday.clone())),
+                        },
+                    })
+                    .collect();
+                DateList { dates }
+            })
+        },
+    )(input)
+}
+
+/// Try to parse a string into a `DateList` starting at the beginning of the string
+///
+/// NOTE: This expects `input` to have be converted to lower case
+pub(crate) fn parse_date_list_exact<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<DateList>> {
+    branch::alt((
+        |input| parse_next_weekdays(input, options),
+        |input| parse_weekday_list(input, options),
+    ))(input)
+}
+
+/// Parses a date given as a `key:value` attribute, eg. "due:fri" or `due:"in 3 days"`. The whole
+/// value has to be a date.
+pub(crate) fn parse_keyed_date_exact<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<KeyedDate>> {
+    let (input, (key, _)) = tuple((
+        take_while1(|c: char| c.is_alphanumeric() || c == '_'),
+        tag(":"),
+    ))(input)?;
+    let (input, value) = branch::alt((
+        map(
+            tuple((tag("\""), is_not("\""), tag("\""))),
+            |(_, value, _)| value,
+        ),
+        is_not(" \t\r\n"),
+    ))(input)?;
+    let (_, date) = all_consuming(|value| parse_flex_date_exact(value, options))(value)?;
+    Ok((
+        input,
+        date.map(|date| KeyedDate {
+            key: key.to_string(),
+            date,
+        }),
+    ))
+}
+
+/// Parses a unit of time in a duration, giving one of that unit, eg. "hours" or "wks".
+fn parse_duration_unit(input: &str) -> IResult<&str, FlexibleDuration> {
+    let minutes = |minutes| FlexibleDuration {
+        minutes,
+        ..FlexibleDuration::default()
+    };
+    let days = |days| FlexibleDuration {
+        days,
+        ..FlexibleDuration::default()
+    };
+    let months = |months| FlexibleDuration {
+        months,
+        ..FlexibleDuration::default()
+    };
+    branch::alt((
+        value(
+            minutes(1),
+            branch::alt((tag("minutes"), tag("minute"), tag("mins"), tag("min"))),
+        ),
+        value(
+            minutes(60),
+            branch::alt((tag("hours"), tag("hour"), tag("hrs"), tag("hr"))),
+        ),
+        value(days(1), branch::alt((tag("days"), tag("day")))),
+        value(
+            days(7),
+            branch::alt((tag("weeks"), tag("week"), tag("wks"), tag("wk"))),
+        ),
+        value(
+            months(1),
+            branch::alt((tag("months"), tag("month"), tag("mos"), tag("mo"))),
+        ),
+        value(
+            months(12),
+            branch::alt((tag("years"), tag("year"), tag("yrs"), tag("yr"))),
+        ),
+    ))(input)
+}
+
+/// Half of a unit of time, eg. 30 minutes for an hour. Fails (returns `None`) for units that
+/// can't be split evenly, like a month.
+fn half_duration(unit: &FlexibleDuration) -> Option<FlexibleDuration> {
+    if !unit.months.is_multiple_of(2) || !unit.minutes.is_multiple_of(2) {
+        return None;
+    }
+    Some(FlexibleDuration {
+        months: unit.months / 2,
+        days: unit.days / 2,
+        minutes: unit.minutes / 2 + unit.days % 2 * 12 * 60,
+    })
+}
+
+/// Parses a length of time, eg. "90 minutes", "for two weeks", or "an hour and a half".
+pub(crate) fn parse_flex_duration_exact<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> IResult<&'a str, Matched<FlexibleDuration>> {
+    let and_a_half = || tuple((space1, tag("and"), space1, tag("a"), space1, tag("half")));
+    preceded(
+        opt(tag("for ")),
+        branch::alt((
+            // "half an hour", "half a day"
+            map_opt(
+                tuple((
+                    tag("half"),
+                    space1,
+                    opt(tuple((branch::alt((tag("an"), tag("a"))), space1))),
+                    parse_duration_unit,
+                )),
+                |(_, _, _, unit)| half_duration(&unit).map(Matched::exact),
+            ),
+            // "3 days", "an hour and a half", "two and a half hours"
+            map_opt(
+                tuple((
+                    |input| parse_quantity(input, options),
+                    opt(and_a_half()),
+                    space1,
+                    parse_duration_unit,
+                    opt(and_a_half()),
+                )),
+                |(amount, half_before, _, unit, half_after)| {
+                    let half = match (half_before, half_after) {
+                        (Some(_), Some(_)) => return None,
+                        (None, None) => FlexibleDuration::default(),
+                        _ => half_duration(&unit)?,
+                    };
+                    // lengths too long to count fail the match
+                    let scaled = |per_unit: u32, half: u32| {
+                        per_unit.checked_mul(amount.data)?.checked_add(half)
+                    };
+                    let duration = FlexibleDuration {
+                        months: scaled(unit.months, half.months)?,
+                        days: scaled(unit.days, half.days)?,
+                        minutes: scaled(unit.minutes, half.minutes)?,
+                    };
+                    Some(amount.map(|_| duration))
+                },
+            ),
+        )),
+    )(input)
+}
+
+/// Finds the first `FlexibleDuration` in `input`.
+pub(crate) fn parse_flex_duration(
+    input: &str,
+    options: &ParseOptions,
+) -> Option<Parsed<FlexibleDuration>> {
+    find_in_str(input, options, parse_flex_duration_exact)
+}
+
+/// Finds the first `KeyedDate` in `input`.
+pub(crate) fn parse_keyed_date(input: &str, options: &ParseOptions) -> Option<Parsed<KeyedDate>> {
+    find_in_str(input, options, parse_keyed_date_exact)
+}
+
+/// Finds the first `Interpretations` in `input`.
+pub(crate) fn parse_interpretations(
+    input: &str,
+    options: &ParseOptions,
+) -> Option<Parsed<Interpretations>> {
+    find_in_str(input, options, parse_interpretations_exact)
+}
+
+/// Finds the first `DateList` in `input`.
+pub(crate) fn parse_date_list(input: &str, options: &ParseOptions) -> Option<Parsed<DateList>> {
+    find_in_str(input, options, parse_date_list_exact)
+}
+
+/// Finds the first `FlexibleTime` in `input` that stands on its own (see
+/// [`parse_standalone_time_exact`]).
+pub(crate) fn parse_standalone_time(
+    input: &str,
+    options: &ParseOptions,
+) -> Option<Parsed<FlexibleTime>> {
+    find_in_str(input, options, parse_standalone_time_exact)
+}
+
+/// Finds the first `FlexibleDateTime` in `input`.
+pub(crate) fn parse_flex_datetime(
+    input: &str,
+    options: &ParseOptions,
+) -> Option<Parsed<FlexibleDateTime>> {
+    find_in_str(input, options, parse_flex_datetime_exact)
+}
+
+/// Finds the first `Recurrence` in `input`.
+pub(crate) fn parse_recurrence(input: &str, options: &ParseOptions) -> Option<Parsed<Recurrence>> {
+    find_in_str(input, options, parse_recurrence_exact)
+}
+
+/// Finds the first `Schedule` in `input`.
+pub(crate) fn parse_schedule(input: &str, options: &ParseOptions) -> Option<Parsed<Schedule>> {
+    find_in_str(input, options, parse_schedule_exact)
+}
+
+/// Finds the first `FlexibleDateRange` in `input`.
+pub(crate) fn parse_flex_date_range(
+    input: &str,
+    options: &ParseOptions,
+) -> Option<Parsed<FlexibleDateRange>> {
+    find_in_str(input, options, parse_flex_date_range_exact)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use crate::{Idiom, Keywords, LeapDayPolicy, Locale, SearchOptions, Weekday};
+
+    use super::*;
+
+    #[test]
+    fn test_parse_today() {
+        let (_, result) = parse_today("today").unwrap();
+        assert_eq!(result, FlexibleDate::Today);
+
+        let (_, result) = parse_today("tod").unwrap();
+        assert_eq!(result, FlexibleDate::Today);
+    }
+
+    #[test]
+    fn test_parse_tomorrow() {
+        let (_, result) = parse_tomorrow("tomorrow").unwrap();
+        assert_eq!(result, FlexibleDate::Tomorrow);
+
+        let (_, result) = parse_tomorrow("tom").unwrap();
+        assert_eq!(result, FlexibleDate::Tomorrow);
+
+        let (_, result) = parse_tomorrow("tmrw").unwrap();
+        assert_eq!(result, FlexibleDate::Tomorrow);
+    }
+
+    #[test]
+    fn test_names_are_whole_words() {
+        assert!(parse_weekday_name("monitor").is_err());
+        assert!(parse_month_name("mayor").is_err());
+        assert_eq!(parse_weekday_name("sat."), Ok((".", Weekday::Saturday)));
+
+        // every abbreviation followed by any letter, unless that makes another name
+        let weekdays = [
+            "sunday",
+            "sun",
+            "monday",
+            "mon",
+            "tuesday",
+            "tue",
+            "wednesday",
+            "wed",
+            "thursday",
+            "thurs",
+            "friday",
+            "fri",
+            "saturday",
+            "sat",
+        ];
+        let months = [
+            "january",
+            "jan",
+            "february",
+            "feb",
+            "march",
+            "mar",
+            "april",
+            "apr",
+            "may",
+            "june",
+            "jun",
+            "july",
+            "jul",
+            "august",
+            "aug",
+            "september",
+            "sept",
+            "sep",
+            "october",
+            "oct",
+            "november",
+            "nov",
+            "december",
+            "dec",
+        ];
+        for name in weekdays.iter().chain(months.iter()) {
+            for letter in 'a'..='z' {
+                let word = format!("{name}{letter}");
+                if weekdays.contains(&word.as_str()) || months.contains(&word.as_str()) {
+                    continue;
+                }
+                assert!(parse_weekday_name(&word).is_err(), "matched {word}");
+                assert!(parse_month_name(&word).is_err(), "matched {word}");
+            }
+        }
+
+        // real words, in the places that dates can appear
+        let options = ParseOptions::default();
+        let words = [
+            "satisfy",
+            "saturn",
+            "monitor",
+            "money",
+            "monkey",
+            "friend",
+            "fried",
+            "fries",
+            "sunny",
+            "sunset",
+            "wedding",
+            "tuesdays",
+            "thursty",
+            "mayor",
+            "maybe",
+            "marching",
+            "decide",
+            "october2",
+            "junior",
+            "julep",
+            "augment",
+            "novel",
+            "january's",
+        ];
+        for word in words {
+            assert!(
+                parse_flex_date_exact(word, &options).is_err(),
+                "matched {word}"
+            );
+            // only the time on its own is found
+            assert_eq!(
+                parse_flex_datetime(&format!("the {word} at 5"), &options)
+                    .map(|parsed| parsed.data.date),
+                Some(FlexibleDate::TodayOrTomorrow),
+                "matched {word}"
+            );
+            assert!(
+                parse_recurrence(&format!("every {word}"), &options).is_none(),
+                "matched {word}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_ambiguous_names() {
+        let options = ParseOptions::default();
+
+        let (_, result) = parse_flex_date_exact("sat", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::exact(FlexibleDate::Weekday(Weekday::Saturday))
+                .ambiguous_if(true)
+                .specific(80)
+        );
+        let (_, result) = parse_flex_date_exact("saturday", &options).unwrap();
+        assert!(!result.ambiguous);
+
+        let Parsed { ambiguous, .. } = parse_flex_datetime("sun at 5", &options).unwrap();
+        assert!(ambiguous);
+        let Parsed { ambiguous, .. } = parse_flex_datetime("fri at 5", &options).unwrap();
+        assert!(!ambiguous);
+
+        // longer phrases make it clear that the word is a date
+        let (_, result) = parse_flex_date_exact("2 weeks from sat", &options).unwrap();
+        assert!(!result.ambiguous);
+    }
+
+    #[test]
+    fn test_ambiguous_name_context() {
+        let options = ParseOptions::default();
+
+        let Parsed { ambiguous, .. } = parse_flex_date("see you Sat", &options).unwrap();
+        assert!(!ambiguous);
+        let Parsed { ambiguous, .. } = parse_flex_date("Sat down to think", &options).unwrap();
+        assert!(ambiguous);
+        let Parsed { ambiguous, .. } = parse_flex_date("brunch on sun", &options).unwrap();
+        assert!(!ambiguous);
+        let Parsed { ambiguous, .. } = parse_flex_date("report due wed", &options).unwrap();
+        assert!(!ambiguous);
+        let Parsed { ambiguous, .. } = parse_flex_date("they wed at noon", &options).unwrap();
+        assert!(ambiguous);
+
+        let options = ParseOptions {
+            ambiguous_names: AmbiguousNames::RequireContext,
+            ..ParseOptions::default()
+        };
+        let Parsed { data, .. } = parse_flex_date("sat down, call on fri", &options).unwrap();
+        assert_eq!(data, FlexibleDate::Weekday(Weekday::Friday));
+        assert!(parse_flex_date("sun is out", &options).is_none());
+        // a following day settles "may"
+        assert!(parse_recurrence("every may 5", &options).is_some());
+
+        let options = ParseOptions {
+            ambiguous_names: AmbiguousNames::Accept,
+            ..ParseOptions::default()
+        };
+        let Parsed { ambiguous, .. } = parse_flex_date("sat down", &options).unwrap();
+        assert!(!ambiguous);
+    }
+
+    #[test]
+    fn test_parse_weekday() {
+        let (_, result) = parse_weekday_name("sunday").unwrap();
+        assert_eq!(result, crate::Weekday::Sunday);
+
+        let (_, result) = parse_weekday_name("sat").unwrap();
+        assert_eq!(result, crate::Weekday::Saturday);
+    }
+
+    #[test]
+    fn test_parse_days_from_now_in_words() {
+        let options = ParseOptions::default();
+
+        for (text, days) in [
+            ("in three days", 3),
+            ("in eleven days", 11),
+            ("in seventy days", 70),
+            ("in twenty-one days", 21),
+            ("in thirty two days", 32),
+            ("in one day", 1),
+        ] {
+            let (rest, result) = parse_flex_date_exact(text, &options).unwrap();
+            assert_eq!(rest, "", "{text}");
+            assert_eq!(
+                result,
+                Matched::exact(FlexibleDate::DaysFromNow(days)).specific(90),
+                "{text}"
+            );
+        }
+
+        let Parsed { data, range, .. } =
+            parse_flex_date("renew the permit in fourteen days", &options).unwrap();
+        assert_eq!(data, FlexibleDate::DaysFromNow(14));
+        assert_eq!(range, (17..33));
+    }
+
+    #[test]
+    fn test_parse_weeks_from_now() {
+        let options = ParseOptions::default();
+
+        for text in [
+            "in 2 weeks",
+            "in two weeks",
+            "2 weeks from now",
+            "two weeks from now",
+        ] {
+            let (rest, result) = parse_flex_date_exact(text, &options).unwrap();
+            assert_eq!(rest, "", "{text}");
+            assert_eq!(
+                result,
+                Matched::exact(FlexibleDate::WeeksFromNow(2)).specific(90),
+                "{text}"
+            );
+        }
+
+        // a Sunday
+        let today = NaiveDate::parse_from_str("2023-10-08", "%Y-%m-%d").unwrap();
+        let date = FlexibleDate::WeeksFromNow(2).into_naive_date(today);
+        assert_eq!(date.to_string(), "2023-10-22");
+    }
+
+    #[test]
+    fn test_parse_months_and_years_from_now() {
+        let options = ParseOptions::default();
+
+        let (_, result) = parse_flex_date_exact("in four months", &options).unwrap();
+        assert_eq!(result.data, FlexibleDate::MonthsFromNow(4));
+        let (_, result) = parse_flex_date_exact("in one year", &options).unwrap();
+        assert_eq!(result.data, FlexibleDate::YearsFromNow(1));
+        let (_, result) = parse_flex_date_exact("a year from now", &options).unwrap();
+        assert_eq!(result.data, FlexibleDate::YearsFromNow(1));
+
+        let (_, result) = parse_flex_date_exact("next month", &options).unwrap();
+        assert_eq!(result.data, FlexibleDate::NextMonth);
+        let (_, result) = parse_flex_date_exact("next year", &options).unwrap();
+        assert_eq!(result.data, FlexibleDate::NextYear);
+        assert!(parse_next_month_or_year("next years").is_err());
+
+        let Parsed { data, range, .. } =
+            parse_flex_date("renew the lease next year", &options).unwrap();
+        assert_eq!(data, FlexibleDate::NextYear);
+        assert_eq!(range, (16..25));
+    }
+
+    #[test]
+    fn test_parse_year() {
+        let options = ParseOptions::default();
+
+        let (rest, result) = parse_flex_date_exact("in 2026", &options).unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(
+            result,
+            Matched::exact(FlexibleDate::StartOfYear(2026))
+                .specific(65)
+                .precise(Precision::Year)
+        );
+
+        let result = parse_flex_date("taxes due 2026", &options).unwrap();
+        assert_eq!(result.data, FlexibleDate::StartOfYear(2026));
+        assert_eq!(result.range, (6..14));
+        assert!(!result.ambiguous);
+        let (_, result) = parse_flex_date_exact("by 2027", &options).unwrap();
+        assert_eq!(result.data, FlexibleDate::StartOfYear(2027));
+
+        // a number without a word like "in" before it is more often something else
+        let result = parse_flex_date("write 1500 words by friday", &options).unwrap();
+        assert_eq!(result.data, FlexibleDate::Weekday(Weekday::Friday));
+        assert_eq!(result.range, (20..26));
+        assert_eq!(parse_flex_date("buy 2000 screws", &options), None);
+        assert_eq!(parse_flex_date("room 1999", &options), None);
+        assert_eq!(parse_flex_date("budget for 2026", &options), None);
+        assert!(parse_flex_date_exact("2026", &options).is_err());
+        assert_eq!(parse_flex_date("2026", &options), None);
+
+        // text that's only a year can't be anything else
+        let (_, result) = parse_lone_year("2026", &options).unwrap();
+        assert_eq!(result.data, FlexibleDate::StartOfYear(2026));
+        assert!(!result.ambiguous);
+        assert!(parse_lone_year("2026 screws", &options).is_err());
+        assert!(parse_lone_year("in 2026", &options).is_err());
+        let no_years = ParseOptions {
+            disabled_rules: vec![Rule::Year],
+            ..ParseOptions::default()
+        };
+        assert!(parse_lone_year("2026", &no_years).is_err());
+        assert!(parse_year_number("in 20265", &options).is_err());
+        assert!(parse_year_number("in 999", &options).is_err());
+        assert!(parse_year_number("in 2026%", &options).is_err());
+        assert!(parse_year_number("into 2026", &options).is_err());
+
+        let options = ParseOptions {
+            year_only: YearPolicy::LastDay,
+            ..ParseOptions::default()
+        };
+        let (_, result) = parse_flex_date_exact("in 2026", &options).unwrap();
+        assert_eq!(result.data, FlexibleDate::EndOfYear(2026));
+
+        // a year can still be a range, even when it isn't a date
+        let options = ParseOptions {
+            year_only: YearPolicy::WholeYear,
+            ..ParseOptions::default()
+        };
+        assert!(parse_flex_date_exact("in 2026", &options).is_err());
+        assert!(parse_lone_year("2026", &options).is_err());
+        let (_, result) = parse_flex_date_range_exact("in 2026", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::exact(FlexibleDateRange {
+                start: FlexibleDate::StartOfYear(2026),
+                end: FlexibleDate::EndOfYear(2026),
+            })
+            .precise(Precision::Year)
+        );
+        let (_, lone) = parse_lone_whole_year("2026").unwrap();
+        assert_eq!(lone, result);
+    }
+
+    #[test]
+    fn test_parse_month_in_year() {
+        let options = ParseOptions::default();
+        let march = |offset| FlexibleDate::MonthInYear {
+            month: Month::March,
+            offset,
+        };
+
+        for (text, expected) in [
+            ("march next year", march(1)),
+            ("in march next year", march(1)),
+            ("next year in march", march(1)),
+            ("mar this year", march(0)),
+        ] {
+            let (rest, result) = parse_flex_date_exact(text, &options).unwrap();
+            assert_eq!(rest, "", "{text}");
+            assert_eq!(
+                result,
+                Matched::exact(expected)
+                    .specific(90)
+                    .precise(Precision::Month),
+                "{text}"
+            );
+        }
+
+        let Parsed { data, range, .. } =
+            parse_flex_date("move out next year in march", &options).unwrap();
+        assert_eq!(data, march(1));
+        assert_eq!(range, (9..27));
+        assert!(parse_month_in_year("march next yearly").is_err());
+    }
+
+    #[test]
+    fn test_parse_offset_from_now() {
+        let options = ParseOptions::default();
+
+        let (_, result) = parse_offset_from_now("in a couple of days", &options).unwrap();
+        assert_eq!(result, Matched::approximate(FlexibleDate::DaysFromNow(2)));
+
+        let (_, result) = parse_offset_from_now("in a couple days", &options).unwrap();
+        assert_eq!(result, Matched::approximate(FlexibleDate::DaysFromNow(2)));
+
+        let (_, result) = parse_offset_from_now("in a few days", &options).unwrap();
+        assert_eq!(result, Matched::approximate(FlexibleDate::DaysFromNow(3)));
+
+        let (_, result) = parse_offset_from_now("in 3 days", &options).unwrap();
+        assert_eq!(result, Matched::exact(FlexibleDate::DaysFromNow(3)));
+
+        let (_, result) = parse_offset_from_now("in a day", &options).unwrap();
+        assert_eq!(result, Matched::exact(FlexibleDate::DaysFromNow(1)));
+
+        let (_, result) = parse_offset_from_now("3 days from now", &options).unwrap();
+        assert_eq!(result, Matched::exact(FlexibleDate::DaysFromNow(3)));
+
+        let (_, result) = parse_offset_from_now("in 1 day", &options).unwrap();
+        assert_eq!(result, Matched::exact(FlexibleDate::DaysFromNow(1)));
+
+        let in_3_weeks = Matched::exact(FlexibleDate::WeeksFromNow(3));
+        let (_, result) = parse_offset_from_now("in 3 wks", &options).unwrap();
+        assert_eq!(result, in_3_weeks);
+        let (_, result) = parse_offset_from_now("in 3w", &options).unwrap();
+        assert_eq!(result, in_3_weeks);
+        let (_, result) = parse_offset_from_now("3wk", &options).unwrap();
+        assert_eq!(result, in_3_weeks);
+
+        let (_, result) = parse_offset_from_now("2d", &options).unwrap();
+        assert_eq!(result, Matched::exact(FlexibleDate::DaysFromNow(2)));
+
+        let (_, result) = parse_offset_from_now("1mo", &options).unwrap();
+        assert_eq!(result.data, FlexibleDate::MonthsFromNow(1));
+
+        let (_, result) = parse_offset_from_now("in 2 yrs", &options).unwrap();
+        assert_eq!(result.data, FlexibleDate::YearsFromNow(2));
+
+        // shorthand without "in" has to be glued together
+        assert!(parse_offset_from_now("2 d", &options).is_err());
+
+        let options = ParseOptions {
+            fuzzy_quantities: FuzzyQuantities {
+                several: 7,
+                ..FuzzyQuantities::default()
+            },
+            ..ParseOptions::default()
+        };
+        let (_, result) = parse_offset_from_now("in several days", &options).unwrap();
+        assert_eq!(result, Matched::approximate(FlexibleDate::DaysFromNow(7)));
+    }
+
+    #[test]
+    fn test_parse_offset_into_period() {
+        let options = ParseOptions::default();
+        // a Sunday
+        let today = NaiveDate::parse_from_str("2023-10-08", "%Y-%m-%d").unwrap();
+
+        let (rest, result) = parse_flex_date_exact("2 weeks into next quarter", &options).unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(
+            result,
+            Matched::exact(FlexibleDate::Offset {
+                amount: 2,
+                unit: DateUnit::Week,
+                anchor: Box::new(FlexibleDate::StartOf(Period {
+                    unit: PeriodUnit::Quarter,
+                    offset: 1,
+                })),
+            })
+            .specific(90)
+        );
+        assert_eq!(result.data.into_naive_date(today).to_string(), "2024-01-15");
+
+        let (_, result) = parse_flex_date_exact("3 days into the next month", &options).unwrap();
+        assert_eq!(result.data.into_naive_date(today).to_string(), "2023-11-04");
+
+        assert!(parse_offset_into_period("3 days into the project", &options).is_err());
+    }
+
+    #[test]
+    fn test_parse_anchored_offset() {
+        let options = ParseOptions::default();
+
+        let (_, result) = parse_anchored_offset("3 weeks from friday", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::exact(FlexibleDate::Offset {
+                amount: 3,
+                unit: DateUnit::Week,
+                anchor: Box::new(FlexibleDate::Weekday(Weekday::Friday)),
+            })
+        );
+
+        let (_, result) = parse_anchored_offset("a few days from mon", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::approximate(FlexibleDate::Offset {
+                amount: 3,
+                unit: DateUnit::Day,
+                anchor: Box::new(FlexibleDate::Weekday(Weekday::Monday)),
+            })
+        );
+
+        let (_, result) = parse_anchored_offset("two weeks from tomorrow", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::exact(FlexibleDate::Offset {
+                amount: 2,
+                unit: DateUnit::Week,
+                anchor: Box::new(FlexibleDate::Tomorrow),
+            })
+        );
+
+        let (_, result) = parse_anchored_offset("3 days after jan 5", &options).unwrap();
+        assert_eq!(
+            result.data,
+            FlexibleDate::Offset {
+                amount: 3,
+                unit: DateUnit::Day,
+                anchor: Box::new(FlexibleDate::MonthDay {
+                    month: Month::January,
+                    day: 5,
+                }),
+            }
+        );
+
+        let (remainder, result) =
+            parse_anchored_offset("a day after 2 weeks from friday", &options).unwrap();
+        assert_eq!(remainder, "");
+        assert_eq!(
+            result.data,
+            FlexibleDate::Offset {
+                amount: 1,
+                unit: DateUnit::Day,
+                anchor: Box::new(FlexibleDate::Offset {
+                    amount: 2,
+                    unit: DateUnit::Week,
+                    anchor: Box::new(FlexibleDate::Weekday(Weekday::Friday)),
+                }),
+            }
+        );
+
+        assert!(parse_anchored_offset("3 weeks from now", &options).is_err());
+        assert!(parse_anchored_offset("3 days after the meeting", &options).is_err());
+    }
+
+    #[test]
+    fn test_parse_skipped_weekday() {
+        let skipped_friday = Matched::exact(FlexibleDate::Offset {
+            amount: 1,
+            unit: DateUnit::Week,
+            anchor: Box::new(FlexibleDate::Weekday(Weekday::Friday)),
+        });
+
+        let (rest, result) = parse_skipped_weekday("not this friday, the next one").unwrap();
+        assert_eq!(result, skipped_friday);
+        assert_eq!(rest, "");
+
+        let (rest, result) = parse_skipped_weekday("not this fri next friday").unwrap();
+        assert_eq!(result, skipped_friday);
+        assert_eq!(rest, "");
+
+        let (_, result) = parse_skipped_weekday("the friday after this one").unwrap();
+        assert_eq!(result, skipped_friday);
+
+        assert!(parse_skipped_weekday("not this friday, next monday").is_err());
+    }
+
+    #[test]
+    fn test_parse_weekday_of_week() {
+        let (_, result) = parse_weekday_of_week("the monday of week 42").unwrap();
+        assert_eq!(
+            result,
+            Matched::exact(FlexibleDate::WeekdayOfWeek {
+                weekday: Weekday::Monday,
+                week: 42
+            })
+        );
+
+        let (_, result) = parse_weekday_of_week("wed of week 1").unwrap();
+        assert_eq!(
+            result.data,
+            FlexibleDate::WeekdayOfWeek {
+                weekday: Weekday::Wednesday,
+                week: 1
+            }
+        );
+
+        assert!(parse_weekday_of_week("the monday of week 54").is_err());
+
+        // planner shorthand
+        for input in ["fri w42", "fri wk42", "friday cw42", "fri w 42"] {
+            let (rest, result) = parse_weekday_of_week(input).unwrap();
+            assert_eq!(rest, "", "{input}");
+            assert_eq!(
+                result.data,
+                FlexibleDate::WeekdayOfWeek {
+                    weekday: Weekday::Friday,
+                    week: 42
+                },
+                "{input}"
+            );
+        }
+        assert!(parse_weekday_of_week("tue cw54").is_err());
+    }
+
+    #[test]
+    fn test_parse_period_segment() {
+        let (_, result) = parse_period_segment("mid next week").unwrap();
+        assert_eq!(
+            result,
+            Matched::approximate(FlexibleDate::SegmentOf(
+                Segment::Mid,
+                Period {
+                    unit: PeriodUnit::Week,
+                    offset: 1
+                }
+            ))
+        );
+
+        let (_, result) = parse_period_segment("early next month").unwrap();
+        assert_eq!(
+            result.data,
+            FlexibleDate::SegmentOf(
+                Segment::Early,
+                Period {
+                    unit: PeriodUnit::Month,
+                    offset: 1
+                }
+            )
+        );
+
+        let (_, result) = parse_period_segment("late-this month").unwrap();
+        assert_eq!(
+            result.data,
+            FlexibleDate::SegmentOf(
+                Segment::Late,
+                Period {
+                    unit: PeriodUnit::Month,
+                    offset: 0
+                }
+            )
+        );
+
+        let options = ParseOptions::default();
+        for (text, segment, month) in [
+            ("mid january", Segment::Mid, Month::January),
+            ("mid-jan", Segment::Mid, Month::January),
+            ("early march", Segment::Early, Month::March),
+            ("late june", Segment::Late, Month::June),
+        ] {
+            let (rest, result) = parse_flex_date_exact(text, &options).unwrap();
+            assert_eq!(rest, "", "{text}");
+            assert_eq!(
+                result,
+                Matched::approximate(FlexibleDate::SegmentOfMonth(segment, month)).specific(85),
+                "{text}"
+            );
+        }
+
+        // the segment isn't the same as an exact day
+        let (_, result) = parse_flex_date_exact("june 25", &options).unwrap();
+        assert!(!result.approximate);
+        assert!(parse_period_segment("mid mayor").is_err());
+    }
+
+    #[test]
+    fn test_parse_period_after_next() {
+        let weekend_after_next = Period {
+            unit: PeriodUnit::Weekend,
+            offset: 2,
+        };
+
+        let (_, result) = parse_period_after_next("weekend after next").unwrap();
+        assert_eq!(result, weekend_after_next);
+
+        let (rest, result) = parse_period_after_next("the weekend after next weekend").unwrap();
+        assert_eq!(result, weekend_after_next);
+        assert_eq!(rest, "");
+
+        // a different unit at the end isn't part of the phrase
+        let (rest, result) = parse_period_after_next("the weekend after next month").unwrap();
+        assert_eq!(result, weekend_after_next);
+        assert_eq!(rest, " month");
+
+        let (_, result) = parse_period_after_next("the week after next").unwrap();
+        assert_eq!(
+            result,
+            Period {
+                unit: PeriodUnit::Week,
+                offset: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_flex_date_range_exact() {
+        let options = ParseOptions::default();
+
+        let (_, result) = parse_flex_date_range_exact("soon", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::approximate(FlexibleDateRange {
+                start: FlexibleDate::Today,
+                end: FlexibleDate::DaysFromNow(3),
+            })
+        );
+
+        let options = ParseOptions {
+            soon_days: 5,
+            ..ParseOptions::default()
+        };
+        let (_, result) = parse_flex_date_range_exact("soon", &options).unwrap();
+        assert_eq!(result.data.end, FlexibleDate::DaysFromNow(5));
+
+        let next_week = Period {
+            unit: PeriodUnit::Week,
+            offset: 1,
+        };
+        let (_, result) = parse_flex_date_range_exact("some time next week", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::approximate(FlexibleDateRange {
+                start: FlexibleDate::StartOf(next_week.clone()),
+                end: FlexibleDate::EndOf(next_week),
+            })
+            .precise(Precision::Week)
+        );
+    }
+
+    #[test]
+    fn test_parse_clock_time() {
+        let options = ParseOptions::default();
+        let at = |hour, minute| FlexibleTime { hour, minute };
+
+        let (_, result) = parse_clock_time("5:30pm", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::exact(at(17, 30)).precise(Precision::Minute)
+        );
+        let (_, result) = parse_clock_time("12:15 a.m.", &options).unwrap();
+        assert_eq!(result, Matched::exact(at(0, 15)).precise(Precision::Minute));
+        let (_, result) = parse_clock_time("17:00", &options).unwrap();
+        assert_eq!(result, Matched::exact(at(17, 0)).precise(Precision::Minute));
+        let (_, result) = parse_clock_time("09:45", &options).unwrap();
+        assert_eq!(result, Matched::exact(at(9, 45)).precise(Precision::Minute));
+
+        // like bare hours, these could be either AM or PM
+        let (_, result) = parse_clock_time("5:30", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::inferred(at(17, 30)).precise(Precision::Minute)
+        );
+
+        assert!(parse_clock_time("17:00pm", &options).is_err());
+        assert!(parse_clock_time("24:00", &options).is_err());
+        assert!(parse_clock_time("5:60", &options).is_err());
+        assert!(parse_clock_time("5:300", &options).is_err());
+    }
+
+    #[test]
+    fn test_parse_standalone_time() {
+        let options = ParseOptions::default();
+
+        let (_, result) = parse_standalone_time_exact("noon", &options).unwrap();
+        assert_eq!(
+            result.data,
+            FlexibleTime {
+                hour: 12,
+                minute: 0
+            }
+        );
+        let (_, result) = parse_standalone_time_exact("at 5", &options).unwrap();
+        assert_eq!(
+            result.data,
+            FlexibleTime {
+                hour: 17,
+                minute: 0
+            }
+        );
+        assert!(parse_standalone_time_exact("5", &options).is_err());
+
+        // marking a bare hour as approximate makes it a time too
+        for (text, hour) in [("around 3", 15), ("3ish", 15), ("5-ish", 17)] {
+            let (rest, result) = parse_standalone_time_exact(text, &options).unwrap();
+            assert_eq!(rest, "", "{text}");
+            assert_eq!(result.data, FlexibleTime { hour, minute: 0 }, "{text}");
+            assert!(result.approximate, "{text}");
+        }
+        let Parsed {
+            data,
+            range,
+            approximate,
+            ..
+        } = parse_standalone_time("call back around 3 if possible", &options).unwrap();
+        assert_eq!(
+            data,
+            FlexibleTime {
+                hour: 15,
+                minute: 0
+            }
+        );
+        assert_eq!(range, (10..18));
+        assert!(approximate);
+        let Parsed { data, range, .. } =
+            parse_flex_datetime("drinks 5-ish with the team", &options).unwrap();
+        assert_eq!(
+            data.time,
+            FlexibleTime {
+                hour: 17,
+                minute: 0
+            }
+        );
+        assert_eq!(range, (7..12));
+
+        // unless bare hours are turned off
+        let no_bare_hours = ParseOptions {
+            disabled_rules: vec![Rule::BareHour],
+            ..ParseOptions::default()
+        };
+        assert!(parse_standalone_time_exact("around 3", &no_bare_hours).is_err());
+
+        let Parsed { data, range, .. } =
+            parse_standalone_time("leave by 5:45pm sharp", &options).unwrap();
+        assert_eq!(
+            data,
+            FlexibleTime {
+                hour: 17,
+                minute: 45
+            }
+        );
+        assert_eq!(range, (9..15));
+
+        let Parsed { data, .. } = parse_flex_datetime("tomorrow at 17:30", &options).unwrap();
+        assert_eq!(
+            data.time,
+            FlexibleTime {
+                hour: 17,
+                minute: 30
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_bare_hour() {
+        let options = ParseOptions::default();
+
+        let (_, result) = parse_bare_hour("5", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::inferred(FlexibleTime {
+                hour: 17,
+                minute: 0
+            })
+        );
+
+        let (_, result) = parse_bare_hour("9", &options).unwrap();
+        assert_eq!(result.data.hour, 9);
+
+        let (_, result) = parse_bare_hour("12", &options).unwrap();
+        assert_eq!(result.data.hour, 12);
+
+        let (_, result) = parse_bare_hour("17", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::exact(FlexibleTime {
+                hour: 17,
+                minute: 0
+            })
+        );
+
+        assert!(parse_bare_hour("24", &options).is_err());
+
+        // a window starting at 10 pushes earlier hours into the evening
+        let options = ParseOptions {
+            bare_hour_window_start: 10,
+            ..ParseOptions::default()
+        };
+        let (_, result) = parse_bare_hour("9", &options).unwrap();
+        assert_eq!(result.data.hour, 21);
+        let (_, result) = parse_bare_hour("10", &options).unwrap();
+        assert_eq!(result.data.hour, 10);
+    }
+
+    #[test]
+    fn test_parse_meridiem_hour() {
+        let (_, result) = parse_meridiem_hour("9am").unwrap();
+        assert_eq!(result, Matched::exact(FlexibleTime { hour: 9, minute: 0 }));
+
+        let (_, result) = parse_meridiem_hour("5 pm").unwrap();
+        assert_eq!(result.data.hour, 17);
+
+        assert!(parse_meridiem_hour("13pm").is_err());
+    }
+
+    #[test]
+    fn test_twelve_oclock() {
+        let options = ParseOptions::default();
+        let midnight = FlexibleTime { hour: 0, minute: 0 };
+        let noon = FlexibleTime {
+            hour: 12,
+            minute: 0,
+        };
+
+        let (_, result) = parse_meridiem_hour("12am").unwrap();
+        assert_eq!(result.data, midnight);
+        let (_, result) = parse_meridiem_hour("12pm").unwrap();
+        assert_eq!(result.data, noon);
+        let (_, result) = parse_meridiem_hour("12 a.m.").unwrap();
+        assert_eq!(result.data, midnight);
+
+        let (_, result) = parse_exact_time("12 noon", &options).unwrap();
+        assert_eq!(result, Matched::exact(noon.clone()));
+        let options = ParseOptions {
+            midnight: MidnightPolicy::StartOfDay,
+            ..ParseOptions::default()
+        };
+        let (_, result) = parse_exact_time("12 midnight", &options).unwrap();
+        assert_eq!(result, Matched::exact(midnight.clone()));
+        let (_, result) = parse_exact_time("midnight", &options).unwrap();
+        assert_eq!(result, Matched::exact(midnight));
+
+        let options = ParseOptions::default();
+        let (_, result) = parse_flex_datetime_exact("friday at 12 noon", &options).unwrap();
+        assert_eq!(result.data.time, noon);
+
+        // without the option, the "12" is just an hour
+        let options = ParseOptions {
+            twelve_with_words: false,
+            ..ParseOptions::default()
+        };
+        let (rest, _) = parse_exact_time("12 noon", &options).unwrap();
+        assert_eq!(rest, " noon");
+        let (rest, _) = parse_flex_datetime_exact("friday at 12 noon", &options).unwrap();
+        assert_eq!(rest, " noon");
+    }
+
+    #[test]
+    fn test_midnight_policy() {
+        let end_of_day = FlexibleTime {
+            hour: 24,
+            minute: 0,
+        };
+
+        let options = ParseOptions::default();
+        let (_, result) = parse_flex_datetime_exact("friday at midnight", &options).unwrap();
+        assert_eq!(result.data.time, end_of_day);
+        let (_, result) = parse_flex_datetime_exact("midnight tomorrow", &options).unwrap();
+        assert_eq!(
+            result.data,
+            FlexibleDateTime {
+                date: FlexibleDate::Tomorrow,
+                time: end_of_day,
+                all_day: false,
+            }
+        );
+
+        // "12am" isn't affected
+        let (_, result) = parse_flex_datetime_exact("friday at 12am", &options).unwrap();
+        assert_eq!(result.data.time, FlexibleTime { hour: 0, minute: 0 });
+
+        let options = ParseOptions {
+            midnight: MidnightPolicy::StartOfDay,
+            ..ParseOptions::default()
+        };
+        let (_, result) = parse_flex_datetime_exact("midnight tomorrow", &options).unwrap();
+        assert_eq!(result.data.time, FlexibleTime { hour: 0, minute: 0 });
+    }
+
+    #[test]
+    fn test_parse_dotted_meridiem() {
+        assert_eq!(parse_meridiem("a.m."), Ok(("", Meridiem::Am)));
+        assert_eq!(parse_meridiem("p.m"), Ok(("", Meridiem::Pm)));
+        assert_eq!(parse_meridiem("p. m."), Ok(("", Meridiem::Pm)));
+
+        let (_, result) = parse_meridiem_hour("5 p.m.").unwrap();
+        assert_eq!(result.data.hour, 17);
+
+        let options = ParseOptions::default();
+        let Parsed { data, range, .. } =
+            parse_flex_datetime("dentist tomorrow at 9 a. m. sharp", &options).unwrap();
+        assert_eq!(data.time, FlexibleTime { hour: 9, minute: 0 });
+        assert_eq!(range, (8..27));
+    }
+
+    #[test]
+    fn test_parse_interval_frequency() {
+        let options = ParseOptions::default();
+
+        let (_, result) = parse_recurrence_exact("every 3rd day", &options).unwrap();
+        assert_eq!(
+            result.data,
+            Recurrence {
+                interval: 3,
+                ..Recurrence::new(Frequency::Daily)
+            }
+        );
+
+        let (_, result) = parse_recurrence_exact("every second week at 10", &options).unwrap();
+        assert_eq!(result.data.frequency, Frequency::Weekly(None));
+        assert_eq!(result.data.interval, 2);
+        assert_eq!(
+            result.data.time,
+            Some(FlexibleTime {
+                hour: 10,
+                minute: 0
+            })
+        );
+
+        let (_, result) = parse_recurrence_exact("every 2nd month on the 1st", &options).unwrap();
+        assert_eq!(result.data.frequency, Frequency::Monthly(Some(1)));
+        assert_eq!(result.data.interval, 2);
+
+        let (_, result) = parse_recurrence_exact("every other week", &options).unwrap();
+        assert_eq!(result.data.frequency, Frequency::Weekly(None));
+        assert_eq!(result.data.interval, 2);
+
+        let (_, result) = parse_recurrence_exact("every other monday", &options).unwrap();
+        assert_eq!(
+            result.data.frequency,
+            Frequency::Weekly(Some(Weekday::Monday))
+        );
+        assert_eq!(result.data.interval, 2);
+
+        let (_, result) = parse_recurrence_exact("every 2 weeks", &options).unwrap();
+        assert_eq!(result.data.frequency, Frequency::Weekly(None));
+        assert_eq!(result.data.interval, 2);
+
+        let (_, result) =
+            parse_recurrence_exact("every three months on the 15th", &options).unwrap();
+        assert_eq!(result.data.frequency, Frequency::Monthly(Some(15)));
+        assert_eq!(result.data.interval, 3);
+
+        // counts need a plural unit
+        assert!(parse_interval_frequency("2 week").is_err());
+        assert!(parse_interval_frequency("1 days").is_err());
+
+        // an ordinal followed by a weekday isn't an interval
+        assert!(parse_interval_frequency("2nd tuesday").is_err());
+        assert!(parse_interval_frequency("0th day").is_err());
+    }
+
+    #[test]
+    fn test_parse_adverb_frequency() {
+        let options = ParseOptions::default();
+
+        let (_, result) = parse_recurrence_exact("weekly on thursdays", &options).unwrap();
+        assert_eq!(
+            result.data.frequency,
+            Frequency::Weekly(Some(Weekday::Thursday))
+        );
+        let (_, result) = parse_recurrence_exact("weekly", &options).unwrap();
+        assert_eq!(result.data.frequency, Frequency::Weekly(None));
+
+        let (_, result) = parse_recurrence_exact("monthly on the 15th", &options).unwrap();
+        assert_eq!(result.data.frequency, Frequency::Monthly(Some(15)));
+        let (_, result) = parse_recurrence_exact("every month on the 15th", &options).unwrap();
+        assert_eq!(result.data.frequency, Frequency::Monthly(Some(15)));
+
+        let (_, result) = parse_recurrence_exact("daily at 8am", &options).unwrap();
+        assert_eq!(result.data.frequency, Frequency::Daily);
+        assert_eq!(result.data.time, Some(FlexibleTime { hour: 8, minute: 0 }));
+
+        let (_, result) = parse_recurrence_exact("annually on march 5", &options).unwrap();
+        assert_eq!(
+            result.data.frequency,
+            Frequency::Yearly {
+                month: Month::March,
+                day: 5
+            }
+        );
+
+        let Parsed { range, .. } =
+            parse_recurrence("standup weekly on mondays at 10", &options).unwrap();
+        assert_eq!(range, (8..31));
+    }
+
+    #[test]
+    fn test_parse_all_day_datetime() {
+        let options = ParseOptions::default();
+
+        let (_, result) = parse_flex_datetime_exact("friday", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::exact(FlexibleDateTime {
+                date: FlexibleDate::Weekday(Weekday::Friday),
+                time: FlexibleTime { hour: 0, minute: 0 },
+                all_day: true,
+            })
+            .specific(80)
+        );
+
+        let (_, result) = parse_flex_datetime_exact("friday at midnight", &options).unwrap();
+        assert!(!result.data.all_day);
+
+        // a time later on still wins over the date on its own
+        let Parsed { data, range, .. } =
+            parse_flex_datetime("call bob tomorrow at 3", &options).unwrap();
+        assert!(!data.all_day);
+        assert_eq!(range, (9..22));
+    }
+
+    #[test]
+    fn test_parse_day_part_qualifiers() {
+        let options = ParseOptions::default();
+
+        let (_, result) = parse_flex_datetime_exact("friday in the morning", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::approximate(FlexibleDateTime {
+                date: FlexibleDate::Weekday(Weekday::Friday),
+                time: FlexibleTime { hour: 9, minute: 0 },
+                all_day: false,
+            })
+            .precise(Precision::Hour)
+            .specific(80)
+        );
+        let (rest, with_comma) =
+            parse_flex_datetime_exact("friday, in the morning", &options).unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(with_comma, result);
+
+        // a qualifier settles the meridiem of a bare hour
+        let (_, result) = parse_flex_datetime_exact("tomorrow at 10 at night", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::exact(FlexibleDateTime {
+                date: FlexibleDate::Tomorrow,
+                time: FlexibleTime {
+                    hour: 22,
+                    minute: 0
+                },
+                all_day: false,
+            })
+            .precise(Precision::Hour)
+            .specific(75)
+        );
+        let (_, result) =
+            parse_flex_datetime_exact("monday at 8 in the morning", &options).unwrap();
+        assert_eq!(result.data.time, FlexibleTime { hour: 8, minute: 0 });
+
+        let Parsed { data, range, .. } =
+            parse_flex_datetime("dinner on the 3rd in the evening", &options).unwrap();
+        assert_eq!(
+            data,
+            FlexibleDateTime {
+                date: FlexibleDate::DayOfMonth(3),
+                time: FlexibleTime {
+                    hour: 19,
+                    minute: 0
+                },
+                all_day: false,
+            }
+        );
+        assert_eq!(range, (10..32));
+    }
+
+    #[test]
+    fn test_parse_this_week() {
+        let this_week = Period {
+            unit: PeriodUnit::Week,
+            offset: 0,
+        };
+
+        let options = ParseOptions::default();
+        assert!(parse_flex_date_exact("this week", &options).is_err());
+        let (_, result) = parse_flex_date_range_exact("this week", &options).unwrap();
+        assert_eq!(
+            result.data,
+            FlexibleDateRange {
+                start: FlexibleDate::StartOf(this_week.clone()),
+                end: FlexibleDate::EndOf(this_week),
+            }
+        );
+
+        let options = ParseOptions {
+            this_week: ThisWeekPolicy::NextWorkday,
+            ..ParseOptions::default()
+        };
+        let (_, result) = parse_flex_date_exact("this week", &options).unwrap();
+        assert_eq!(result.data, FlexibleDate::NextBusinessDay);
+
+        let options = ParseOptions {
+            this_week: ThisWeekPolicy::EndOfWeek(Weekday::Friday),
+            ..ParseOptions::default()
+        };
+        let Parsed { data, range, .. } =
+            parse_flex_date("finish the draft this week", &options).unwrap();
+        assert_eq!(data, FlexibleDate::WeekdayThisWeek(Weekday::Friday));
+        assert_eq!(range, (17..26));
+
+        // the week still ends on its Friday once that's passed, rather than next week's
+        let saturday = NaiveDate::from_ymd_opt(2023, 10, 14).unwrap();
+        assert_eq!(data.into_naive_date(saturday).to_string(), "2023-10-13");
+
+        // "this weekend" is something else
+        let Parsed { data, .. } = parse_flex_date("see you this weekend", &options).unwrap();
+        assert_eq!(data, FlexibleDate::ThisWeekend);
+    }
+
+    #[test]
+    fn test_parse_later_this_week() {
+        let options = ParseOptions::default();
+        let Parsed {
+            data,
+            range,
+            approximate,
+            ..
+        } = parse_flex_date("call them later this week", &options).unwrap();
+        assert_eq!(data, FlexibleDate::LaterThisWeek(2));
+        assert_eq!(range, (10..25));
+        assert!(approximate);
+
+        let options = ParseOptions {
+            later_this_week_days: 3,
+            ..ParseOptions::default()
+        };
+        let (_, result) = parse_flex_date_exact("later this week", &options).unwrap();
+        assert_eq!(result.data, FlexibleDate::LaterThisWeek(3));
+    }
+
+    #[test]
+    fn test_parse_end_of_week() {
+        let this_week = Period {
+            unit: PeriodUnit::Week,
+            offset: 0,
+        };
+        let options = ParseOptions::default();
+        for input in ["end of week", "end of the week", "the end of the week"] {
+            let (remainder, result) = parse_flex_date_exact(input, &options).unwrap();
+            assert_eq!(remainder, "");
+            assert_eq!(result.data, FlexibleDate::EndOf(this_week.clone()));
+        }
+        assert!(parse_flex_date_exact("end of weekend", &options).is_err());
+
+        let options = ParseOptions {
+            this_week: ThisWeekPolicy::EndOfWeek(Weekday::Friday),
+            ..ParseOptions::default()
+        };
+        let (_, result) = parse_flex_date_exact("end of the week", &options).unwrap();
+        assert_eq!(result.data, FlexibleDate::WeekdayThisWeek(Weekday::Friday));
+
+        // on a Saturday, the end of the week was yesterday rather than next Friday
+        let saturday = NaiveDate::from_ymd_opt(2023, 10, 14).unwrap();
+        let date = result.data.into_naive_date(saturday);
+        assert_eq!(date.to_string(), "2023-10-13");
+    }
+
+    #[test]
+    fn test_parse_period_section() {
+        let options = ParseOptions::default();
+        let year = |offset| Period {
+            unit: PeriodUnit::Year,
+            offset,
+        };
+
+        for (text, expected) in [
+            ("the end of the year", FlexibleDate::EndOf(year(0))),
+            ("end of next year", FlexibleDate::EndOf(year(1))),
+            ("the beginning of the year", FlexibleDate::StartOf(year(0))),
+            ("the middle of the year", FlexibleDate::MidpointOf(year(0))),
+            (
+                "start of next month",
+                FlexibleDate::StartOf(Period {
+                    unit: PeriodUnit::Month,
+                    offset: 1,
+                }),
+            ),
+            (
+                "the end of the quarter after next",
+                FlexibleDate::EndOf(Period {
+                    unit: PeriodUnit::Quarter,
+                    offset: 2,
+                }),
+            ),
+        ] {
+            let (rest, result) = parse_flex_date_exact(text, &options).unwrap();
+            assert_eq!(rest, "", "{text}");
+            assert_eq!(result, Matched::exact(expected).specific(90), "{text}");
+        }
+
+        // weeks have their own rule
+        let (_, result) = parse_flex_date_exact("end of the week", &options).unwrap();
+        assert_eq!(
+            result.data,
+            FlexibleDate::EndOf(Period {
+                unit: PeriodUnit::Week,
+                offset: 0
+            })
+        );
+        assert!(parse_period_section("the beginning of next week", &options).is_err());
+
+        // sections can be rough parts instead, like "late next year"
+        let options = ParseOptions {
+            period_sections: SectionPolicy::Segments,
+            ..ParseOptions::default()
+        };
+        let (_, result) = parse_flex_date_exact("the end of next year", &options).unwrap();
+        let (_, late) = parse_flex_date_exact("late next year", &options).unwrap();
+        assert_eq!(result.data, FlexibleDate::SegmentOf(Segment::Late, year(1)));
+        assert!(result.approximate);
+        assert_eq!(result.data, late.data);
+    }
+
+    #[test]
+    fn test_parse_midpoint() {
+        let options = ParseOptions::default();
+        let midpoint_of =
+            |unit, offset| Matched::exact(FlexibleDate::MidpointOf(Period { unit, offset }));
+
+        for (text, expected) in [
+            (
+                "halfway through the month",
+                midpoint_of(PeriodUnit::Month, 0),
+            ),
+            ("the middle of next week", midpoint_of(PeriodUnit::Week, 1)),
+            (
+                "midway through next quarter",
+                midpoint_of(PeriodUnit::Quarter, 1),
+            ),
+            (
+                "middle of the month after next",
+                midpoint_of(PeriodUnit::Month, 2),
+            ),
+            (
+                "halfway through q2",
+                Matched::exact(FlexibleDate::MidpointOfQuarter(2)),
+            ),
+        ] {
+            let (rest, result) = parse_flex_date_exact(text, &options).unwrap();
+            assert_eq!(rest, "", "{text}");
+            assert_eq!(result, expected.specific(90), "{text}");
+        }
+
+        assert!(parse_flex_date_exact("halfway through q5", &options).is_err());
+
+        // quarters work anywhere periods do
+        let (_, result) = parse_flex_date_exact("early next quarter", &options).unwrap();
+        assert_eq!(
+            result.data,
+            FlexibleDate::SegmentOf(
+                Segment::Early,
+                Period {
+                    unit: PeriodUnit::Quarter,
+                    offset: 1
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_day_in_month() {
+        let options = ParseOptions::default();
+        let day_in =
+            |day, offset| Matched::exact(FlexibleDate::DayInMonth { day, offset }).specific(90);
+
+        for (text, expected) in [
+            ("the 15th of next month", day_in(15, 1)),
+            ("the 5th next month", day_in(5, 1)),
+            ("the fifth of this month", day_in(5, 0)),
+            ("the 1st after next", day_in(1, 2)),
+            ("the 1st of the month after next", day_in(1, 2)),
+        ] {
+            let (rest, result) = parse_flex_date_exact(text, &options).unwrap();
+            assert_eq!(rest, "", "{text}");
+            assert_eq!(result, expected, "{text}");
+        }
+
+        // other periods don't have numbered days
+        let (rest, result) = parse_flex_date_exact("the 5th next week", &options).unwrap();
+        assert_eq!(result.data, FlexibleDate::DayOfMonth(5));
+        assert_eq!(rest, " next week");
+    }
+
+    #[test]
+    fn test_parse_this_or_next_weekend() {
+        let options = ParseOptions::default();
+
+        let (_, result) = parse_flex_date_exact("this weekend", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::exact(FlexibleDate::ThisWeekend).specific(90)
+        );
+
+        let Parsed { data, range, .. } =
+            parse_flex_date("camping next weekend?", &options).unwrap();
+        assert_eq!(data, FlexibleDate::NextWeekend);
+        assert_eq!(range, (8..20));
+
+        assert!(parse_this_or_next_weekend("next weekends").is_err());
+    }
+
+    #[test]
+    fn test_parse_next_weekday() {
+        let options = ParseOptions::default();
+        let (_, result) = parse_flex_date_exact("next friday", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::exact(FlexibleDate::Offset {
+                amount: 1,
+                unit: DateUnit::Week,
+                anchor: Box::new(FlexibleDate::WeekdayThisWeek(Weekday::Friday)),
+            })
+            .specific(90)
+        );
+
+        let options = ParseOptions {
+            next_weekday: WeekdayPolicy::NextOccurrence,
+            ..ParseOptions::default()
+        };
+        let Parsed { data, range, .. } = parse_flex_date("due next fri", &options).unwrap();
+        assert_eq!(data, FlexibleDate::NextWeekday(Weekday::Friday));
+        assert_eq!(range, (4..12));
+
+        // "next mon" isn't the start of "next month"
+        let (_, result) = parse_flex_date_exact("next month", &options).unwrap();
+        assert_eq!(result.data, FlexibleDate::NextMonth);
+    }
+
+    #[test]
+    fn test_parse_this_weekday() {
+        let options = ParseOptions::default();
+        let (_, result) = parse_flex_date_exact("this saturday", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::exact(FlexibleDate::WeekdayThisWeek(Weekday::Saturday)).specific(90)
+        );
+
+        // "sun" isn't ambiguous after "this"
+        let Parsed {
+            data, ambiguous, ..
+        } = parse_flex_date("brunch this sun", &options).unwrap();
+        assert_eq!(data, FlexibleDate::WeekdayThisWeek(Weekday::Sunday));
+        assert!(!ambiguous);
+
+        let (_, result) = parse_flex_date_exact("this weekend", &options).unwrap();
+        assert_eq!(result.data, FlexibleDate::ThisWeekend);
+    }
+
+    #[test]
+    fn test_weekday_scope() {
+        let options = ParseOptions {
+            weekday_scope: WeekdayScope::CurrentWeek,
+            ..ParseOptions::default()
+        };
+        let (_, result) = parse_flex_date_exact("friday", &options).unwrap();
+        assert_eq!(result.data, FlexibleDate::WeekdayThisWeek(Weekday::Friday));
+        let (_, result) = parse_date_list_exact("mon and tue", &options).unwrap();
+        assert_eq!(
+            result.data.dates[1],
+            FlexibleDate::WeekdayThisWeek(Weekday::Tuesday)
+        );
+
+        // weekdays that are part of a longer phrase aren't affected
+        let (_, result) = parse_flex_date_exact("3 days from friday", &options).unwrap();
+        assert_eq!(
+            result.data,
+            FlexibleDate::Offset {
+                amount: 3,
+                unit: DateUnit::Day,
+                anchor: Box::new(FlexibleDate::WeekdayThisWeek(Weekday::Friday)),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_weekday_list() {
+        let options = ParseOptions::default();
+        let day = FlexibleDate::Weekday;
+
+        let (_, result) = parse_date_list_exact("mon, wed, and fri", &options).unwrap();
+        assert_eq!(
+            result.data.dates,
+            vec![
+                day(Weekday::Monday),
+                day(Weekday::Wednesday),
+                day(Weekday::Friday),
+            ]
+        );
+
+        let (_, result) = parse_date_list_exact("saturday and sun", &options).unwrap();
+        assert_eq!(
+            result.data.dates,
+            vec![day(Weekday::Saturday), day(Weekday::Sunday)]
+        );
+        assert!(result.ambiguous);
+
+        // a single weekday isn't a list
+        assert!(parse_date_list("call on monday", &options).is_none());
+        assert!(parse_date_list("monday or tuesday", &options).is_none());
+    }
+
+    #[test]
+    fn test_parse_next_weekdays() {
+        let options = ParseOptions::default();
+        let monday = || Box::new(FlexibleDate::Weekday(Weekday::Monday));
+
+        let (_, result) = parse_date_list_exact("the next two mondays", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::exact(DateList {
+                dates: vec![
+                    FlexibleDate::Weekday(Weekday::Monday),
+                    FlexibleDate::Offset {
+                        amount: 1,
+                        unit: DateUnit::Week,
+                        anchor: monday(),
+                    },
+                ]
+            })
+        );
+
+        let (_, result) = parse_date_list_exact("next few weekends", &options).unwrap();
+        assert_eq!(result.data.dates.len(), 3);
+        assert!(result.approximate);
+
+        // a Sunday
+        let today = NaiveDate::parse_from_str("2023-10-08", "%Y-%m-%d").unwrap();
+        let Parsed { data, range, .. } =
+            parse_date_list("hike the next three weekends", &options).unwrap();
+        assert_eq!(range, (5..28));
+        let dates: Vec<_> = data
+            .into_naive_dates(today)
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(dates, vec!["2023-10-14", "2023-10-21", "2023-10-28"]);
+    }
+
+    #[test]
+    fn test_parse_all_flex_dates() {
+        let options = ParseOptions::default();
+
+        let dates = parse_all_flex_dates("tomorrow, 2024-01-27 or friday", &options);
+        let ranges: Vec<_> = dates.iter().map(|parsed| parsed.range.clone()).collect();
+        assert_eq!(ranges, vec![0..8, 10..20, 24..30]);
+        assert_eq!(dates[2].data, FlexibleDate::Weekday(Weekday::Friday));
+
+        assert!(parse_all_flex_dates("nothing to see here", &options).is_empty());
+
+        let options = ParseOptions {
+            only_after_triggers: true,
+            ..ParseOptions::default()
+        };
+        let dates = parse_all_flex_dates("sat down, due tomorrow, 2024-01-27", &options);
+        assert_eq!(dates.len(), 2);
+    }
+
+    #[test]
+    fn test_scan_flex_dates() {
+        let options = ParseOptions::default();
+
+        let (dates, problems) = scan_flex_dates("feb 30 or mar 3", &options);
+        assert_eq!(dates.len(), 1);
+        assert_eq!(dates[0].range, 10..15);
+        assert_eq!(problems[0].range, 0..6);
+
+        let (dates, problems) = scan_flex_dates("due 2023-02-29, tomorrow at the latest", &options);
+        assert_eq!(dates[0].data, FlexibleDate::Tomorrow);
+        assert_eq!(
+            problems[0].data,
+            DateProblem::NoSuchDay {
+                year: Some(2023),
+                month: Month::February,
+                day: 29
+            }
+        );
+
+        let (dates, problems) = scan_flex_dates("13/01 or 31.04.2024", &options);
+        assert!(dates.is_empty());
+        assert_eq!(
+            problems
+                .into_iter()
+                .map(|problem| problem.data)
+                .collect::<Vec<_>>(),
+            vec![
+                DateProblem::NoSuchMonth(13),
+                DateProblem::NoSuchDay {
+                    year: Some(2024),
+                    month: Month::April,
+                    day: 31
+                },
+            ]
+        );
+
+        // real dates and numbers that aren't days aren't diagnosed
+        let (dates, problems) = scan_flex_dates("feb 29, 2024-02-29, may 45 minutes", &options);
+        assert_eq!(dates.len(), 2);
+        assert!(problems.is_empty());
+    }
+
+    #[test]
+    fn test_match_preference() {
+        let options = |prefer| ParseOptions {
+            search: SearchOptions { prefer },
+            ..ParseOptions::default()
+        };
+        let text = "today, or next friday at 5pm, or the 3rd";
+
+        let first = parse_flex_datetime(text, &options(MatchPreference::First)).unwrap();
+        assert_eq!(first.range, (0..5));
+        let last = parse_flex_datetime(text, &options(MatchPreference::Last)).unwrap();
+        assert_eq!(last.range, (33..40));
+        let longest = parse_flex_datetime(text, &options(MatchPreference::Longest)).unwrap();
+        assert_eq!(longest.range, (10..28));
+        assert_eq!(
+            longest.data.time,
+            FlexibleTime {
+                hour: 17,
+                minute: 0
+            }
+        );
+
+        // ties go to the earlier match
+        let longest = parse_flex_date("monday or friday", &options(MatchPreference::Longest));
+        assert_eq!(longest.unwrap().range, (0..6));
+
+        let options = options(MatchPreference::Last);
+        assert_eq!(parse_flex_date("nothing here", &options), None);
+    }
+
+    #[test]
+    fn test_max_scan_len() {
+        let options = ParseOptions {
+            max_scan_len: Some(12),
+            ..ParseOptions::default()
+        };
+        let text = "due tomorrow or friday";
+        assert_eq!(parse_flex_date(text, &options).unwrap().range, 4..12);
+        assert_eq!(parse_all_flex_dates(text, &options).len(), 1);
+
+        // dates running past the limit aren't found
+        let options = ParseOptions {
+            max_scan_len: Some(10),
+            ..ParseOptions::default()
+        };
+        assert_eq!(parse_flex_date(text, &options), None);
+
+        // the limit doesn't split characters
+        let options = ParseOptions {
+            max_scan_len: Some(1),
+            ..ParseOptions::default()
+        };
+        assert_eq!(parse_flex_date("é tomorrow", &options), None);
+    }
+
+    #[test]
+    fn test_cancel() {
+        let token = CancelToken::new();
+        let options = ParseOptions {
+            cancel: Some(token.clone()),
+            ..ParseOptions::default()
+        };
+        assert!(parse_flex_date("due tomorrow", &options).is_some());
+
+        token.cancel();
+        assert_eq!(parse_flex_date("due tomorrow", &options), None);
+        assert!(parse_all_flex_dates("tomorrow or friday", &options).is_empty());
+        assert_eq!(options.cancel, Some(token));
+    }
+
+    #[test]
+    fn test_only_after_triggers() {
+        let options = ParseOptions {
+            only_after_triggers: true,
+            ..ParseOptions::default()
+        };
+
+        let Parsed { data, range, .. } =
+            parse_flex_date("sat down to write the report due friday", &options).unwrap();
+        assert_eq!(data, FlexibleDate::Weekday(Weekday::Friday));
+        assert_eq!(range, (33..39));
+
+        let Parsed { range, .. } =
+            parse_flex_datetime("Remind me to call mom tomorrow at 5", &options).unwrap();
+        assert_eq!(range, (22..35));
+
+        assert!(parse_flex_date("sat down with the team", &options).is_none());
+        // the trigger has to be a whole word
+        assert!(parse_flex_date("overdue since sat", &options).is_none());
+    }
+
+    #[test]
+    fn test_extra_locales() {
+        let spanish = Locale {
+            name: "es".into(),
+            recurrence_triggers: vec!["cada".into()],
+            date_triggers: vec!["para".into()],
+            date_prepositions: vec!["el".into()],
+            date_order: DateOrder::DayFirst,
+            week_start: Weekday::Monday,
+        };
+        let options = ParseOptions {
+            extra_locales: vec![spanish],
+            ..ParseOptions::default()
+        };
+
+        let Parsed { range, locale, .. } =
+            parse_flex_date("llamar a mamá tomorrow", &options).unwrap();
+        assert_eq!(range, (15..23));
+        assert_eq!(&*locale, "en");
+        // the name is shared with the locale, not copied
+        assert!(std::sync::Arc::ptr_eq(&locale, &options.locale.name));
+
+        let Parsed { data, locale, .. } =
+            parse_recurrence("regar las plantas cada day", &options).unwrap();
+        assert_eq!(data.frequency, Frequency::Daily);
+        assert_eq!(&*locale, "es");
+
+        // the main locale comes first
+        let Parsed { locale, .. } = parse_recurrence("every day", &options).unwrap();
+        assert_eq!(&*locale, "en");
+
+        let Parsed { ambiguous, .. } = parse_flex_date("nos vemos el sat", &options).unwrap();
+        assert!(!ambiguous);
+
+        let options = ParseOptions {
+            only_after_triggers: true,
+            ..options
+        };
+        let Parsed { range, .. } = parse_flex_date("sat, para friday", &options).unwrap();
+        assert_eq!(range, (10..16));
+    }
+
+    #[test]
+    fn test_trailing_punctuation() {
+        let options = ParseOptions::default();
+
+        let Parsed { range, .. } = parse_flex_date("is it due tomorrow?", &options).unwrap();
+        assert_eq!(range, (10..18));
+        let Parsed { range, .. } = parse_flex_date("friday. or sooner", &options).unwrap();
+        assert_eq!(range, (0..6));
+
+        // punctuation has to end the phrase
+        assert!(parse_flex_date("the 2.5 release", &options).is_none());
+    }
+
+    #[test]
+    fn test_parse_idiom() {
+        let options = ParseOptions {
+            workday_start: FlexibleTime {
+                hour: 8,
+                minute: 30,
+            },
+            ..ParseOptions::default()
+        };
+
+        let (_, result) = parse_flex_datetime_exact("first thing tomorrow", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::exact(FlexibleDateTime {
+                date: FlexibleDate::Tomorrow,
+                time: FlexibleTime {
+                    hour: 8,
+                    minute: 30
+                },
+                all_day: false,
+            })
+            .precise(Precision::Hour)
+            .specific(95)
+        );
+
+        let (_, result) = parse_flex_datetime_exact("end of play today", &options).unwrap();
+        assert_eq!(result.data.date, FlexibleDate::Today);
+        assert_eq!(
+            result.data.time,
+            FlexibleTime {
+                hour: 17,
+                minute: 0
+            }
+        );
+
+        let (_, result) = parse_flex_datetime_exact("friday eod", &options).unwrap();
+        assert_eq!(result.data.date, FlexibleDate::Weekday(Weekday::Friday));
+        assert_eq!(
+            result.data.time,
+            FlexibleTime {
+                hour: 17,
+                minute: 0
+            }
+        );
+
+        let mut options = ParseOptions::default();
+        options.keywords.idioms = vec![Idiom::new(
+            "after hours",
+            IdiomTime::At(FlexibleTime {
+                hour: 19,
+                minute: 0,
+            }),
+        )];
+        let Parsed { data, range, .. } =
+            parse_flex_datetime("deploy after hours on friday please", &options).unwrap();
+        assert_eq!(
+            data.time,
+            FlexibleTime {
+                hour: 19,
+                minute: 0
+            }
+        );
+        assert_eq!(range, (7..28));
+        assert!(parse_flex_datetime_exact("first thing tomorrow", &options).is_err());
+    }
+
+    #[test]
+    fn test_parse_approximate_date() {
+        let options = ParseOptions::default();
+
+        let (_, result) = parse_flex_date_exact("around the 15th", &options).unwrap();
+        assert_eq!(result.data, FlexibleDate::DayOfMonth(15));
+        assert!(result.approximate);
+        assert_eq!(result.tolerance, Some(Duration::days(2)));
+
+        let (_, result) = parse_flex_date_exact("about a week from now", &options).unwrap();
+        assert_eq!(result.data, FlexibleDate::WeeksFromNow(1));
+        assert!(result.approximate);
+
+        let options = ParseOptions {
+            approximate_date_tolerance: Duration::days(5),
+            ..ParseOptions::default()
+        };
+        let Parsed {
+            range, tolerance, ..
+        } = parse_flex_date("wrap up roughly 3 weeks from friday", &options).unwrap();
+        assert_eq!(range, (8..35));
+        assert_eq!(tolerance, Some(Duration::days(5)));
+    }
+
+    #[test]
+    fn test_parse_open_date_range() {
+        let options = ParseOptions::default();
+        let friday = FlexibleDate::Weekday(Weekday::Friday);
+
+        let (_, result) = parse_open_date_range_exact("anytime before friday", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::exact(OpenDateRange {
+                start: None,
+                end: Some(FlexibleDate::DayBefore(Box::new(friday.clone()))),
+            })
+            .specific(80)
+        );
+
+        let (_, result) = parse_open_date_range_exact("by friday", &options).unwrap();
+        assert_eq!(result.data.end, Some(friday.clone()));
+        let (_, result) = parse_open_date_range_exact("on or after friday", &options).unwrap();
+        assert_eq!(result.data.start, Some(friday));
+
+        let (_, result) = parse_open_date_range_exact("after the 15th", &options).unwrap();
+        assert_eq!(
+            result.data,
+            OpenDateRange {
+                start: Some(FlexibleDate::Offset {
+                    amount: 1,
+                    unit: DateUnit::Day,
+                    anchor: Box::new(FlexibleDate::DayOfMonth(15)),
+                }),
+                end: None,
+            }
+        );
+
+        let Parsed { range, .. } =
+            parse_open_date_range("anything due sometime after tomorrow", &options).unwrap();
+        assert_eq!(range, (13..36));
+    }
+
+    #[test]
+    fn test_parse_between_dates() {
+        let options = ParseOptions::default();
+        let monday_to_friday = FlexibleDateRange {
+            start: FlexibleDate::Weekday(Weekday::Monday),
+            end: FlexibleDate::Weekday(Weekday::Friday),
+        };
+
+        for input in [
+            "from monday to friday",
+            "from monday through friday",
+            "monday through friday",
+            "monday thru friday",
+            "monday until friday",
+            "monday - friday",
+            "monday-friday",
+            "monday \u{2013} friday",
+        ] {
+            let (rest, result) = parse_flex_date_range_exact(input, &options).unwrap();
+            assert_eq!(rest, "", "{input}");
+            assert_eq!(result.data, monday_to_friday, "{input}");
+        }
+
+        let (_, result) = parse_flex_date_range_exact("june 5 - june 9", &options).unwrap();
+        assert_eq!(
+            result.data,
+            FlexibleDateRange {
+                start: FlexibleDate::MonthDay {
+                    month: Month::June,
+                    day: 5
+                },
+                end: FlexibleDate::MonthDay {
+                    month: Month::June,
+                    day: 9
+                },
+            }
+        );
+
+        // "to" needs a "from"
+        assert!(parse_flex_date_range_exact("monday to friday", &options).is_err());
+        let Parsed { range, .. } =
+            parse_flex_date_range("move from tuesday to friday", &options).unwrap();
+        assert_eq!(range, (5..27));
+    }
+
+    #[test]
+    fn test_parse_upcoming_window() {
+        let options = ParseOptions::default();
+
+        let (_, result) = parse_flex_date_range_exact("for the next two weeks", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::exact(FlexibleDateRange {
+                start: FlexibleDate::Today,
+                end: FlexibleDate::WeeksFromNow(2),
+            })
+        );
+
+        let (_, result) = parse_flex_date_range_exact("over the next 3 days", &options).unwrap();
+        assert_eq!(result.data.end, FlexibleDate::DaysFromNow(3));
+
+        let (_, result) = parse_flex_date_range_exact("over the next few days", &options).unwrap();
+        assert!(result.approximate);
+        let (_, result) =
+            parse_flex_date_range_exact("for the next couple of weeks", &options).unwrap();
+        assert!(result.approximate);
+
+        let (_, result) = parse_flex_date_range_exact("for the next month", &options).unwrap();
+        assert_eq!(result.data.end, FlexibleDate::MonthsFromNow(1));
+
+        let options = ParseOptions {
+            window_end: WindowEnd::Before,
+            ..ParseOptions::default()
+        };
+        let (_, result) = parse_flex_date_range_exact("over the next 3 days", &options).unwrap();
+        assert_eq!(
+            result.data.end,
+            FlexibleDate::DayBefore(Box::new(FlexibleDate::DaysFromNow(3)))
+        );
+
+        let Parsed { range, .. } =
+            parse_flex_date_range("no meetings for the next 2 weeks please", &options).unwrap();
+        assert_eq!(range, (12..32));
+    }
+
+    #[test]
+    fn test_parse_bounded_window() {
+        let options = ParseOptions::default();
+
+        let (_, result) = parse_flex_date_range_exact("in the next 5 days", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::exact(FlexibleDateRange {
+                start: FlexibleDate::Today,
+                end: FlexibleDate::DaysFromNow(5),
+            })
+        );
+        let (_, result) = parse_flex_date_range_exact("within 3 days", &options).unwrap();
+        assert_eq!(result.data.end, FlexibleDate::DaysFromNow(3));
+        let (_, result) = parse_flex_date_range_exact("within a couple weeks", &options).unwrap();
+        assert!(result.approximate);
+
+        // "within" needs an amount
+        assert!(parse_flex_date_range_exact("within days", &options).is_err());
+
+        let options = ParseOptions {
+            window_end: WindowEnd::Before,
+            ..ParseOptions::default()
+        };
+        let (_, result) = parse_flex_date_range_exact("within 3 days", &options).unwrap();
+        let today = NaiveDate::parse_from_str("2023-10-09", "%Y-%m-%d").unwrap();
+        let range = result.data.into_naive_date_range(today);
+        assert_eq!(range.end().to_string(), "2023-10-11");
+    }
+
+    #[test]
+    fn test_parse_close_of_business() {
+        let options = ParseOptions::default();
+        let cob_friday = Matched::exact(FlexibleDateTime {
+            date: FlexibleDate::Weekday(Weekday::Friday),
+            time: FlexibleTime {
+                hour: 17,
+                minute: 0,
+            },
+            all_day: false,
+        })
+        .precise(Precision::Hour)
+        .specific(80);
+
+        let (_, result) = parse_flex_datetime_exact("cob friday", &options).unwrap();
+        assert_eq!(result, cob_friday);
+        let (_, result) = parse_flex_datetime_exact("friday cob", &options).unwrap();
+        assert_eq!(result, cob_friday);
+        let (_, result) =
+            parse_flex_datetime_exact("friday at close of business", &options).unwrap();
+        assert_eq!(result, cob_friday);
+        let (_, result) = parse_flex_datetime_exact("end of business on friday", &options).unwrap();
+        assert_eq!(result, cob_friday);
+
+        let options = ParseOptions {
+            close_of_business: FlexibleTime {
+                hour: 18,
+                minute: 30,
+            },
+            ..ParseOptions::default()
+        };
+        let Parsed { data, range, .. } =
+            parse_flex_datetime("send the report by cob tomorrow", &options).unwrap();
+        assert_eq!(
+            data,
+            FlexibleDateTime {
+                date: FlexibleDate::Tomorrow,
+                time: FlexibleTime {
+                    hour: 18,
+                    minute: 30
+                },
+                all_day: false,
+            }
+        );
+        assert_eq!(range, (19..31));
+    }
+
+    #[test]
+    fn test_parse_named_times() {
+        let options = ParseOptions {
+            keywords: Keywords {
+                named_times: vec![
+                    NamedTime::at(
+                        "lunch",
+                        FlexibleTime {
+                            hour: 12,
+                            minute: 30,
+                        },
+                    ),
+                    NamedTime::between(
+                        "work",
+                        FlexibleTime { hour: 9, minute: 0 },
+                        FlexibleTime {
+                            hour: 17,
+                            minute: 0,
+                        },
+                    ),
+                ],
+                ..Keywords::default()
+            },
+            ..ParseOptions::default()
+        };
+
+        let (_, result) = parse_flex_datetime_exact("tomorrow after lunch", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::approximate(FlexibleDateTime {
+                date: FlexibleDate::Tomorrow,
+                time: FlexibleTime {
+                    hour: 12,
+                    minute: 30
+                },
+                all_day: false,
+            })
+            .precise(Precision::Hour)
+            .specific(95)
+        );
+
+        let (_, result) = parse_flex_datetime_exact("friday before work", &options).unwrap();
+        assert_eq!(result.data.time, FlexibleTime { hour: 9, minute: 0 });
+        let (_, result) = parse_flex_datetime_exact("friday after work", &options).unwrap();
+        assert_eq!(
+            result.data.time,
+            FlexibleTime {
+                hour: 17,
+                minute: 0
+            }
+        );
+
+        let (_, result) = parse_flex_datetime_exact("monday at lunch", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::exact(FlexibleDateTime {
+                date: FlexibleDate::Weekday(Weekday::Monday),
+                time: FlexibleTime {
+                    hour: 12,
+                    minute: 30
+                },
+                all_day: false,
+            })
+            .precise(Precision::Hour)
+            .specific(80)
+        );
+
+        let Parsed { range, .. } =
+            parse_flex_datetime("call bob tomorrow after the lunch", &options).unwrap();
+        assert_eq!(range, (9..33));
+
+        // nothing is registered by default, so only the date matches
+        let options = ParseOptions::default();
+        let (rest, result) = parse_flex_datetime_exact("tomorrow after lunch", &options).unwrap();
+        assert_eq!(rest, " after lunch");
+        assert!(result.data.all_day);
+    }
+
+    #[test]
+    fn test_parse_recurrence_triggers() {
+        let options = ParseOptions::default();
+
+        let (_, result) = parse_recurrence_exact("each monday", &options).unwrap();
+        assert_eq!(
+            result.data.frequency,
+            Frequency::Weekly(Some(Weekday::Monday))
+        );
+
+        let (_, result) = parse_recurrence_exact("each year on march 5", &options).unwrap();
+        assert_eq!(
+            result.data.frequency,
+            Frequency::Yearly {
+                month: Month::March,
+                day: 5
+            }
+        );
+
+        let Parsed { range, .. } =
+            parse_recurrence("water plants each day at 8", &options).unwrap();
+        assert_eq!(range, (13..26));
+
+        // triggers come from the locale
+        let options = ParseOptions {
+            locale: Locale {
+                recurrence_triggers: vec!["cada".into()],
+                ..Locale::english()
+            },
+            ..ParseOptions::default()
+        };
+        assert!(parse_recurrence_exact("cada day", &options).is_ok());
+        assert!(parse_recurrence_exact("every day", &options).is_err());
+    }
+
+    #[test]
+    fn test_parse_recurrence_exact() {
+        let options = ParseOptions::default();
+
+        let (_, result) = parse_recurrence_exact("every monday at 9am", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::exact(Recurrence {
+                time: Some(FlexibleTime { hour: 9, minute: 0 }),
+                ..Recurrence::new(Frequency::Weekly(Some(Weekday::Monday)))
+            })
+        );
+
+        let (_, result) = parse_recurrence_exact("every morning at 8", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::exact(Recurrence {
+                time: Some(FlexibleTime { hour: 8, minute: 0 }),
+                ..Recurrence::new(Frequency::Daily)
+            })
+            .specific(75)
+        );
+
+        // the part of the day settles the meridiem
+        let (_, result) = parse_recurrence_exact("every evening at 8", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::exact(Recurrence {
+                time: Some(FlexibleTime {
+                    hour: 20,
+                    minute: 0
+                }),
+                ..Recurrence::new(Frequency::Daily)
+            })
+            .specific(75)
+        );
+
+        let (rest, result) = parse_recurrence_exact("every day", &options).unwrap();
+        assert_eq!(result.data.time, None);
+        assert_eq!(rest, "");
+
+        let march_5 = Frequency::Yearly {
+            month: Month::March,
+            day: 5,
+        };
+        let (_, result) = parse_recurrence_exact("every year on march 5", &options).unwrap();
+        assert_eq!(result.data.frequency, march_5);
+        let (_, result) = parse_recurrence_exact("every mar 5th", &options).unwrap();
+        assert_eq!(result.data.frequency, march_5);
+
+        assert!(parse_recurrence_exact("every feb 30", &options).is_err());
+
+        let (rest, result) =
+            parse_recurrence_exact("every week starting friday", &options).unwrap();
+        assert_eq!(
+            result.data,
+            Recurrence {
+                start: Some(FlexibleDate::Weekday(Weekday::Friday)),
+                ..Recurrence::new(Frequency::Weekly(None))
+            }
+        );
+        assert_eq!(rest, "");
+
+        let (_, result) = parse_recurrence_exact("every day from tomorrow at 9", &options).unwrap();
+        assert_eq!(result.data.start, Some(FlexibleDate::Tomorrow));
+        assert_eq!(result.data.time, Some(FlexibleTime { hour: 9, minute: 0 }));
+        assert!(result.inferred);
+
+        let (_, result) =
+            parse_recurrence_exact("every monday at 9am starting on tomorrow", &options).unwrap();
+        assert_eq!(result.data.start, Some(FlexibleDate::Tomorrow));
+        assert_eq!(result.data.time, Some(FlexibleTime { hour: 9, minute: 0 }));
+
+        let options = ParseOptions {
+            leap_day_policy: LeapDayPolicy::LastDayOfFebruary,
+            ..ParseOptions::default()
+        };
+        let (_, result) = parse_recurrence_exact("every feb 29", &options).unwrap();
+        assert_eq!(
+            result.data.leap_day_policy,
+            LeapDayPolicy::LastDayOfFebruary
+        );
+    }
+
+    #[test]
+    fn test_parse_except_holidays() {
+        let options = ParseOptions::default();
+
+        let (rest, result) =
+            parse_recurrence_exact("every workday at 9 except holidays", &options).unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(
+            result.data,
+            Recurrence {
+                time: Some(FlexibleTime { hour: 9, minute: 0 }),
+                except_holidays: true,
+                ..Recurrence::new(Frequency::BusinessDaily)
+            }
+        );
+
+        // the time can come after the exception
+        let (rest, result) =
+            parse_recurrence_exact("every monday excluding bank holidays at 10", &options).unwrap();
+        assert_eq!(rest, "");
+        assert!(result.data.except_holidays);
+        assert_eq!(
+            result.data.time,
+            Some(FlexibleTime {
+                hour: 10,
+                minute: 0
+            })
+        );
+
+        let (_, result) = parse_recurrence_exact("every business day", &options).unwrap();
+        assert_eq!(result.data.frequency, Frequency::BusinessDaily);
+        assert!(!result.data.except_holidays);
+    }
+
+    #[test]
+    fn test_recurrence_units_are_whole_words() {
+        let options = ParseOptions::default();
+
+        for text in ["every weekday", "every weekdays", "every workday"] {
+            let (rest, result) = parse_recurrence_exact(text, &options).unwrap();
+            assert_eq!(rest, "", "{text}");
+            assert_eq!(result.data.frequency, Frequency::BusinessDaily, "{text}");
+        }
+
+        let (_, result) = parse_recurrence_exact("every weekday at 9", &options).unwrap();
+        assert_eq!(result.data.frequency, Frequency::BusinessDaily);
+        assert_eq!(result.data.time, Some(FlexibleTime { hour: 9, minute: 0 }));
+
+        for text in [
+            "every weekend",
+            "every dayz",
+            "every weekly",
+            "every monthly",
+            "every mornings",
+            "every 2 dayz",
+            "every other weekend",
+        ] {
+            assert!(parse_recurrence_exact(text, &options).is_err(), "{text}");
+        }
+
+        let found = Recurrence::find_and_parse_in_str("water plants every weekday").unwrap();
+        assert_eq!(found.data.frequency, Frequency::BusinessDaily);
+        assert_eq!(found.range, (13..26));
+    }
+
+    #[test]
+    fn test_parse_recurrence_end() {
+        let options = ParseOptions::default();
+
+        let (rest, result) = parse_recurrence_exact("every friday until june 1", &options).unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(
+            result.data,
+            Recurrence {
+                end: Some(RecurrenceEnd::Until(FlexibleDate::MonthDay {
+                    month: Month::June,
+                    day: 1
+                })),
+                ..Recurrence::new(Frequency::Weekly(Some(Weekday::Friday)))
+            }
+        );
+
+        let (rest, result) = parse_recurrence_exact("every day for 5 weeks", &options).unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(
+            result.data.end,
+            Some(RecurrenceEnd::For(FlexibleDuration {
+                days: 35,
+                ..FlexibleDuration::default()
+            }))
+        );
+
+        // the time can come after the end
+        let (rest, result) =
+            parse_recurrence_exact("every friday until dec 31 at 5pm", &options).unwrap();
+        assert_eq!(rest, "");
+        assert_eq!(
+            result.data.time,
+            Some(FlexibleTime {
+                hour: 17,
+                minute: 0
+            })
+        );
+        assert_eq!(
+            result.data.end,
+            Some(RecurrenceEnd::Until(FlexibleDate::MonthDay {
+                month: Month::December,
+                day: 31
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_approximate_time() {
+        let options = ParseOptions::default();
+        let three_pm = FlexibleTime {
+            hour: 15,
+            minute: 0,
+        };
+
+        let (_, result) = parse_approximate_time("around 3", &options).unwrap();
+        assert_eq!(result.data, three_pm);
+        assert!(result.approximate);
+        assert!(result.inferred);
+        assert_eq!(result.tolerance, Some(Duration::minutes(30)));
+
+        let (_, result) = parse_approximate_time("3-ish", &options).unwrap();
+        assert_eq!(result.data, three_pm);
+        assert!(result.approximate);
+
+        let (_, result) = parse_approximate_time("noonish", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::exact(FlexibleTime {
+                hour: 12,
+                minute: 0
+            })
+            .within(Duration::minutes(30))
+        );
+
+        assert!(parse_approximate_time("noon", &options).is_err());
+    }
+
+    #[test]
+    fn test_parse_flex_datetime() {
+        let Parsed {
+            data,
+            range,
+            inferred,
+            ..
+        } = parse_flex_datetime("call bob tomorrow at 5 ok", &ParseOptions::default()).unwrap();
+        assert_eq!(
+            data,
+            FlexibleDateTime {
+                date: FlexibleDate::Tomorrow,
+                time: FlexibleTime {
+                    hour: 17,
+                    minute: 0
+                },
+                all_day: false,
+            }
+        );
+        assert_eq!(range, (9..22));
+        assert!(inferred);
+
+        let Parsed {
+            data,
+            range,
+            approximate,
+            ..
+        } = parse_flex_datetime("lunch fri around noon", &ParseOptions::default()).unwrap();
+        assert_eq!(data.date, FlexibleDate::Weekday(Weekday::Friday));
+        assert_eq!(range, (6..21));
+        assert!(approximate);
+
+        let Parsed { approximate, .. } =
+            parse_flex_datetime("lunch fri at noon", &ParseOptions::default()).unwrap();
+        assert!(!approximate);
+
+        let input = "report due in 3 days at noon";
+        let Parsed {
+            data,
+            range,
+            approximate,
+            inferred,
+            ..
+        } = parse_flex_datetime(input, &ParseOptions::default()).unwrap();
+        assert_eq!(
+            data,
+            FlexibleDateTime {
+                date: FlexibleDate::DaysFromNow(3),
+                time: FlexibleTime {
+                    hour: 12,
+                    minute: 0
+                },
+                all_day: false,
+            }
+        );
+        assert_eq!(&input[range], "in 3 days at noon");
+        assert!(!approximate);
+        assert!(!inferred);
+
+        // the range covers the time, however it's joined on
+        let options = ParseOptions::default();
+        for input in [
+            "call mom tomorrow at 3pm",
+            "call mom tomorrow @ 3pm",
+            "call mom tomorrow @3pm",
+            "call mom tomorrow, at 3pm",
+            "call mom tomorrow 3pm",
+            "call mom tomorrow 15:00",
+        ] {
+            let Parsed { data, range, .. } = parse_flex_datetime(input, &options).unwrap();
+            assert_eq!(
+                data.time,
+                FlexibleTime {
+                    hour: 15,
+                    minute: 0
+                }
+            );
+            assert_eq!(range, (9..input.len()));
+        }
+
+        // but bare hours need an "at", since they're usually something else
+        let Parsed { data, range, .. } =
+            parse_flex_datetime("fri 14:00 with the team", &options).unwrap();
+        assert_eq!(
+            data.time,
+            FlexibleTime {
+                hour: 14,
+                minute: 0
+            }
+        );
+        assert_eq!(range, (0..9));
+        let Parsed { data, range, .. } =
+            parse_flex_datetime("tomorrow 5 people are coming", &options).unwrap();
+        assert!(data.all_day);
+        assert_eq!(range, (0..8));
+    }
+
+    #[test]
+    fn test_parse_time_only() {
+        let options = ParseOptions::default();
+        let Parsed {
+            data,
+            range,
+            inferred,
+            ..
+        } = parse_flex_datetime("call mom at 5", &options).unwrap();
+        assert_eq!(
+            data,
+            FlexibleDateTime {
+                date: FlexibleDate::TodayOrTomorrow,
+                time: FlexibleTime {
+                    hour: 17,
+                    minute: 0
+                },
+                all_day: false,
+            }
+        );
+        assert_eq!(range, (9..13));
+        assert!(inferred);
+
+        let options = ParseOptions {
+            time_only: TimeOnlyPolicy::Today,
+            ..ParseOptions::default()
+        };
+        let (_, result) = parse_flex_datetime_exact("9:30pm", &options).unwrap();
+        assert_eq!(result.data.date, FlexibleDate::Today);
+
+        // a date still wins
+        let (_, result) = parse_flex_datetime_exact("noon friday", &options).unwrap();
+        assert_eq!(result.data.date, FlexibleDate::Weekday(Weekday::Friday));
+        assert!(parse_flex_datetime("chapter 5", &options).is_none());
+    }
+
+    #[test]
+    fn test_parse_time_before_date() {
+        let options = ParseOptions::default();
+
+        // the time can come first, with the same result as when the date does
+        for (time_first, date_first) in [
+            ("9am on the 3rd", "the 3rd at 9am"),
+            ("5:30pm tomorrow", "tomorrow at 5:30pm"),
+            ("at 5 friday", "friday at 5"),
+            ("noon on monday", "monday at noon"),
+        ] {
+            let (remainder, result) = parse_flex_datetime_exact(time_first, &options).unwrap();
+            assert_eq!(remainder, "", "{time_first}");
+            let (_, expected) = parse_flex_datetime_exact(date_first, &options).unwrap();
+            assert_eq!(result, expected, "{time_first}");
+        }
+
+        let Parsed { data, range, .. } =
+            parse_flex_datetime("lunch 1pm on friday", &options).unwrap();
+        assert_eq!(data.date, FlexibleDate::Weekday(Weekday::Friday));
+        assert_eq!(
+            data.time,
+            FlexibleTime {
+                hour: 13,
+                minute: 0
+            }
+        );
+        assert_eq!(range, (6..19));
+    }
+
+    #[test]
+    fn test_parse_part_of_today() {
+        let options = ParseOptions::default();
+        let today_at = |hour| FlexibleDateTime {
+            date: FlexibleDate::Today,
+            time: FlexibleTime { hour, minute: 0 },
+            all_day: false,
+        };
+
+        for (input, hour) in [
+            ("tonight", 21),
+            ("this morning", 9),
+            ("this afternoon", 15),
+            ("this evening", 19),
+        ] {
+            let (remainder, result) = parse_flex_datetime_exact(input, &options).unwrap();
+            assert_eq!(remainder, "");
+            assert_eq!(
+                result,
+                Matched::approximate(today_at(hour))
+                    .specific(95)
+                    .precise(Precision::Hour)
+            );
+        }
+
+        // a time settles the meridiem
+        let Parsed {
+            data,
+            range,
+            approximate,
+            inferred,
+            ..
+        } = parse_flex_datetime("dinner tonight at 8", &options).unwrap();
+        assert_eq!(data, today_at(20));
+        assert_eq!(range, (7..19));
+        assert!(!approximate);
+        assert!(!inferred);
+
+        // so does a time before the part of the day
+        let Parsed { data, range, .. } =
+            parse_flex_datetime("back by midnight tonight", &options).unwrap();
+        assert_eq!(data, today_at(24));
+        assert_eq!(range, (8..24));
+        let (_, result) = parse_flex_datetime_exact("at 8 tonight", &options).unwrap();
+        assert_eq!(result.data, today_at(20));
+        let (_, result) = parse_flex_datetime_exact("10am this morning", &options).unwrap();
+        assert_eq!(result.data, today_at(10));
+
+        assert_eq!(parse_flex_datetime("this one's mine", &options), None);
+    }
+
+    #[test]
+    fn test_parse_interpretations() {
+        let options = ParseOptions::default();
+
+        let input = "meet tuesday or wed";
+        let Parsed { data, range, .. } = parse_interpretations(input, &options).unwrap();
+        assert_eq!(
+            data,
+            Interpretations {
+                dates: vec![
+                    FlexibleDate::Weekday(Weekday::Tuesday),
+                    FlexibleDate::Weekday(Weekday::Wednesday)
+                ],
+                from_alternatives: true,
+            }
+        );
+        assert_eq!(&input[range], "tuesday or wed");
+
+        let Parsed { data, .. } = parse_interpretations("meet tuesday or not", &options).unwrap();
+        assert_eq!(
+            data,
+            Interpretations {
+                dates: vec![FlexibleDate::Weekday(Weekday::Tuesday)],
+                from_alternatives: false,
+            }
+        );
+
+        let Parsed {
+            data, approximate, ..
+        } = parse_interpretations("today or in a few days", &options).unwrap();
+        assert_eq!(data.dates.len(), 2);
+        assert!(approximate);
+    }
+
+    #[test]
+    fn test_parse_flex_date_exact() {
+        let options = ParseOptions::default();
+
+        let (_, result) = parse_flex_date_exact("tomorrow", &options).unwrap();
+        assert_eq!(result, Matched::exact(FlexibleDate::Tomorrow).specific(95));
+
+        let (_, result) = parse_flex_date_exact("tod", &options).unwrap();
+        assert_eq!(result, Matched::exact(FlexibleDate::Today).specific(95));
+    }
+
+    #[test]
+    fn test_parse_flex_date_substring() {
+        let Parsed { data, range, .. } =
+            parse_flex_date("tomorrow after", &ParseOptions::default()).unwrap();
+        assert_eq!(data, FlexibleDate::Tomorrow);
+        assert_eq!(range, (0..8));
+
+        let Parsed { data, range, .. } =
+            parse_flex_date("before tomorrow", &ParseOptions::default()).unwrap();
+        assert_eq!(data, FlexibleDate::Tomorrow);
+        assert_eq!(range, (7..15));
+
+        let input = "before tomorrow after";
+        let Parsed { data, range, .. } = parse_flex_date(input, &ParseOptions::default()).unwrap();
+        assert_eq!(data, FlexibleDate::Tomorrow);
+        assert_eq!(range, (7..15));
+        assert_eq!(&input[range], "tomorrow");
+
+        let Parsed { data, range, .. } =
+            parse_flex_date("do a barrel roll tod", &ParseOptions::default()).unwrap();
+        assert_eq!(data, FlexibleDate::Today);
+        assert_eq!(range, (17..20));
+
+        let Parsed { data, range, .. } =
+            parse_flex_date("go home fri okay", &ParseOptions::default()).unwrap();
+        assert_eq!(data, FlexibleDate::Weekday(Weekday::Friday));
+        assert_eq!(range, (8..11));
+    }
+
+    #[test]
+    fn text_parse_variations() {
+        let Parsed { data, .. } = parse_flex_date("Today", &ParseOptions::default()).unwrap();
+        assert_eq!(data, FlexibleDate::Today);
+
+        let Parsed { data, .. } = parse_flex_date("toMorRoW", &ParseOptions::default()).unwrap();
+        assert_eq!(data, FlexibleDate::Tomorrow);
+    }
+
+    #[test]
+    fn test_parse_approximate_flag() {
+        let Parsed {
+            data,
+            range,
+            approximate,
+            ..
+        } = parse_flex_date("water plants in a few days", &ParseOptions::default()).unwrap();
+        assert_eq!(data, FlexibleDate::DaysFromNow(3));
+        assert_eq!(range, (13..26));
+        assert!(approximate);
+
+        let Parsed { approximate, .. } =
+            parse_flex_date("water plants tomorrow", &ParseOptions::default()).unwrap();
+        assert!(!approximate);
+    }
+
+    #[test]
+    fn test_parse_month_day() {
+        let options = ParseOptions::default();
+        let jan_27 = FlexibleDate::MonthDay {
+            month: Month::January,
+            day: 27,
+        };
+
+        let (_, result) = parse_flex_date_exact("january 27", &options).unwrap();
+        assert_eq!(result, Matched::exact(jan_27.clone()).specific(90));
+        let (_, result) = parse_flex_date_exact("jan 27th", &options).unwrap();
+        assert_eq!(result.data, jan_27);
+
+        // names that are also words aren't ambiguous with a day after them
+        let Parsed {
+            data, ambiguous, ..
+        } = parse_flex_date("dentist may 5", &options).unwrap();
+        assert_eq!(
+            data,
+            FlexibleDate::MonthDay {
+                month: Month::May,
+                day: 5
+            }
+        );
+        assert!(!ambiguous);
+
+        assert!(parse_month_day("feb 30").is_err());
+        assert!(parse_flex_date("june 3.5", &options).is_none());
+
+        // the day can come first, or after "the"
+        let march_5 = FlexibleDate::MonthDay {
+            month: Month::March,
+            day: 5,
+        };
+        for text in [
+            "the 5th of march",
+            "the fifth of march",
+            "5th of march",
+            "the 5 of march",
+            "march the 5th",
+        ] {
+            let (rest, result) = parse_flex_date_exact(text, &options).unwrap();
+            assert_eq!(rest, "", "{text}");
+            assert_eq!(result.data, march_5, "{text}");
+        }
+        let Parsed { data, range, .. } =
+            parse_flex_date("party on the fifth of march", &options).unwrap();
+        assert_eq!(data, march_5);
+        assert_eq!(range, (9..27));
+        assert!(parse_month_day("5 of march").is_err());
+
+        // a day that isn't in the month isn't some other month's day either
+        assert!(parse_flex_date("on the thirtieth of february", &options).is_none());
+        assert!(parse_flex_date("due 31st of april", &options).is_none());
+    }
+
+    #[test]
+    fn test_parse_day_of_month() {
+        assert_eq!(
+            parse_day_of_month("the twenty-seventh"),
+            Ok(("", FlexibleDate::DayOfMonth(27)))
+        );
+        assert_eq!(
+            parse_day_of_month("the first"),
+            Ok(("", FlexibleDate::DayOfMonth(1)))
+        );
+        assert_eq!(
+            parse_day_of_month("the 5th"),
+            Ok(("", FlexibleDate::DayOfMonth(5)))
+        );
+        assert!(parse_day_of_month("the thirty-second").is_err());
+        assert!(parse_day_of_month("the fortieth").is_err());
+
+        let options = ParseOptions::default();
+        let Parsed { data, range, .. } =
+            parse_flex_date("rent is due the thirty first", &options).unwrap();
+        assert_eq!(data, FlexibleDate::DayOfMonth(31));
+        assert_eq!(range, (12..28));
+
+        let (_, recurrence) =
+            parse_recurrence_exact("every march twenty-seventh", &options).unwrap();
+        assert_eq!(
+            recurrence.data.frequency,
+            Frequency::Yearly {
+                month: Month::March,
+                day: 27
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_bare_ordinal() {
+        let options = ParseOptions::default();
+
+        for (text, day) in [
+            ("1st", 1),
+            ("22nd", 22),
+            ("3rd", 3),
+            ("11th", 11),
+            ("27th", 27),
+        ] {
+            let (rest, result) = parse_flex_date_exact(text, &options).unwrap();
+            assert_eq!(rest, "", "{text}");
+            assert_eq!(result.data, FlexibleDate::DayOfMonth(day), "{text}");
+        }
+        for text in ["3th", "11st", "32nd", "0th", "5ths", "1.5th"] {
+            assert!(parse_bare_ordinal(text).is_err(), "{text}");
+        }
+
+        let Parsed {
+            data,
+            range,
+            ambiguous,
+            ..
+        } = parse_flex_date("rent due 27th", &options).unwrap();
+        assert_eq!(data, FlexibleDate::DayOfMonth(27));
+        assert_eq!(range, (9..13));
+        assert!(!ambiguous);
+
+        let options = ParseOptions {
+            ambiguous_names: AmbiguousNames::RequireContext,
+            ..ParseOptions::default()
+        };
+        assert!(parse_flex_date("my 3rd attempt", &options).is_none());
+    }
+
+    #[test]
+    fn test_parse_last_business_day() {
+        let this_month = FlexibleDate::LastBusinessDayOf(Period {
+            unit: PeriodUnit::Month,
+            offset: 0,
+        });
+        let (_, result) = parse_last_business_day("the last weekday of the month").unwrap();
+        assert_eq!(result, Matched::exact(this_month.clone()));
+        let (_, result) = parse_last_business_day("last business day of this month").unwrap();
+        assert_eq!(result.data, this_month);
+
+        let (_, result) = parse_last_business_day("last working day of next month").unwrap();
+        assert_eq!(
+            result.data,
+            FlexibleDate::LastBusinessDayOf(Period {
+                unit: PeriodUnit::Month,
+                offset: 1,
+            })
+        );
+
+        let options = ParseOptions::default();
+        let Parsed { data, range, .. } =
+            parse_flex_date("invoice on the last workday of the month", &options).unwrap();
+        assert_eq!(data, this_month);
+        assert_eq!(range, (11..40));
+    }
+
+    #[test]
+    fn test_roman_numeral_value() {
+        assert_eq!(roman_numeral_value("i"), Some(1));
+        assert_eq!(roman_numeral_value("iv"), Some(4));
+        assert_eq!(roman_numeral_value("ix"), Some(9));
+        assert_eq!(roman_numeral_value("xii"), Some(12));
+        assert_eq!(roman_numeral_value("mcmxciv"), Some(1994));
+
+        assert_eq!(roman_numeral_value("iiii"), None);
+        assert_eq!(roman_numeral_value("vv"), None);
+        assert_eq!(roman_numeral_value("xm"), None);
+        assert_eq!(roman_numeral_value("ixi"), None);
+        assert_eq!(roman_numeral_value(""), None);
+    }
+
+    #[test]
+    fn test_parse_flex_duration() {
+        let options = ParseOptions::default();
+
+        let (_, result) = parse_flex_duration_exact("for 90 minutes", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::exact(FlexibleDuration {
+                minutes: 90,
+                ..FlexibleDuration::default()
+            })
+        );
+
+        let (_, result) = parse_flex_duration_exact("2 hrs", &options).unwrap();
+        assert_eq!(result.data.minutes, 120);
+        let (_, result) = parse_flex_duration_exact("for two weeks", &options).unwrap();
+        assert_eq!(result.data.days, 14);
+        let (_, result) = parse_flex_duration_exact("3 years", &options).unwrap();
+        assert_eq!(result.data.months, 36);
+        let (_, result) = parse_flex_duration_exact("a few days", &options).unwrap();
+        assert!(result.approximate);
+
+        assert!(parse_flex_duration_exact("for 3", &options).is_err());
+        assert!(parse_flex_duration_exact("for 400000000 years", &options).is_err());
+        assert_eq!(
+            FlexibleDuration::find_and_parse_in_str("for 400000000 years"),
+            None
+        );
+
+        // halves
+        let minutes = |minutes| FlexibleDuration {
+            minutes,
+            ..FlexibleDuration::default()
+        };
+        for (input, expected) in [
+            ("an hour and a half", minutes(90)),
+            ("two and a half hours", minutes(150)),
+            ("half an hour", minutes(30)),
+            (
+                "a week and a half",
+                FlexibleDuration {
+                    days: 10,
+                    minutes: 720,
+                    ..FlexibleDuration::default()
+                },
+            ),
+            (
+                "a year and a half",
+                FlexibleDuration {
+                    months: 18,
+                    ..FlexibleDuration::default()
+                },
+            ),
+        ] {
+            let (rest, result) = parse_flex_duration_exact(input, &options).unwrap();
+            assert_eq!(rest, "", "{input}");
+            assert_eq!(result.data, expected, "{input}");
+        }
+        // half a month isn't a whole number of days
+        assert!(parse_flex_duration_exact("a month and a half", &options).is_err());
+        // the half is what takes this past the longest length that can be counted
+        assert!(parse_flex_duration_exact("357913941 years", &options).is_ok());
+        assert!(parse_flex_duration_exact("357913941 and a half years", &options).is_err());
+        assert!(parse_flex_duration_exact("357913941 years and a half", &options).is_err());
+    }
+
+    #[test]
+    fn test_parse_iso_date() {
+        let (_, result) = parse_iso_date("2024-01-27").unwrap();
+        assert_eq!(
+            result,
+            Matched::exact(FlexibleDate::CalendarDate {
+                year: 2024,
+                month: Month::January,
+                day: 27
+            })
+        );
+        assert!(parse_iso_date("2023-02-29").is_err());
+        assert!(parse_iso_date("24-01-27").is_err());
+    }
+
+    #[test]
+    fn test_parse_keyed_date() {
+        let options = ParseOptions::default();
+
+        let (_, result) = parse_keyed_date_exact("due:fri", &options).unwrap();
+        assert_eq!(
+            result.data,
+            KeyedDate {
+                key: "due".into(),
+                date: FlexibleDate::Weekday(Weekday::Friday),
+            }
+        );
+
+        let (_, result) = parse_keyed_date_exact("scheduled:2024-01-27", &options).unwrap();
+        assert_eq!(result.data.key, "scheduled");
+
+        let (_, result) = parse_keyed_date_exact("wait:\"in a few days\"", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::approximate(KeyedDate {
+                key: "wait".into(),
+                date: FlexibleDate::DaysFromNow(3),
+            })
+            .specific(90)
+        );
+
+        // the whole value needs to be a date
+        assert!(parse_keyed_date_exact("due:fridge", &options).is_err());
+        assert!(parse_keyed_date_exact("due:\"friday please\"", &options).is_err());
+        assert!(parse_keyed_date_exact("due: friday", &options).is_err());
+
+        let Parsed { data, range, .. } = parse_keyed_date(
+            "water plants project:home due:\"in 2 days\" +garden",
+            &options,
+        )
+        .unwrap();
+        assert_eq!(data.key, "due");
+        assert_eq!(data.date, FlexibleDate::DaysFromNow(2));
+        assert_eq!(range, (26..41));
+    }
+
+    #[test]
+    fn test_parse_dotted_date() {
+        let options = ParseOptions::default();
+        let sept_27 = FlexibleDate::CalendarDate {
+            year: 2024,
+            month: Month::September,
+            day: 27,
+        };
+
+        let (_, result) = parse_dotted_date("27.09.2024", &options).unwrap();
+        assert_eq!(result, Matched::exact(sept_27.clone()));
+        let (_, result) = parse_dotted_date("27.9.2024", &options).unwrap();
+        assert_eq!(result.data, sept_27);
+
+        assert!(parse_dotted_date("31.09.2024", &options).is_err());
+        assert!(parse_dotted_date("27.13.2024", &options).is_err());
+        // the year needs all four digits
+        assert!(parse_dotted_date("1.2.3", &options).is_err());
+        assert!(parse_dotted_date("10.10.10", &options).is_err());
+        assert!(parse_dotted_date("1.2.20245", &options).is_err());
+        assert!(parse_dotted_date("1.2.2024.5", &options).is_err());
+        assert!(parse_dotted_date("127.09.2024", &options).is_err());
+        assert_eq!(parse_flex_date("see 10.10.10", &options), None);
+
+        // roman numeral months need to be turned on
+        assert!(parse_dotted_date("27.ix.2024", &options).is_err());
+
+        let options = ParseOptions {
+            roman_numeral_months: true,
+            ..ParseOptions::default()
+        };
+        let (_, result) = parse_dotted_date("27.ix.2024", &options).unwrap();
+        assert_eq!(result.data, sept_27);
+        let (_, result) = parse_dotted_date("27.09.2024", &options).unwrap();
+        assert_eq!(result.data, sept_27);
+
+        assert!(parse_dotted_date("27.xiii.2024", &options).is_err());
+        assert!(parse_dotted_date("27.iiii.2024", &options).is_err());
+
+        let Parsed { data, range, .. } = parse_flex_date("deadline 27.IX.2024", &options).unwrap();
+        assert_eq!(data, sept_27);
+        assert_eq!(range, (9..19));
+    }
+
+    #[test]
+    fn test_parse_slash_date() {
+        let options = ParseOptions::default();
+        let jan_27 = FlexibleDate::MonthDay {
+            month: Month::January,
+            day: 27,
+        };
+
+        let (_, result) = parse_slash_date("01/27", &options).unwrap();
+        assert_eq!(result, Matched::exact(jan_27.clone()));
+        let (_, result) = parse_slash_date("1/27/2024", &options).unwrap();
+        assert_eq!(
+            result.data,
+            FlexibleDate::CalendarDate {
+                year: 2024,
+                month: Month::January,
+                day: 27,
+            }
+        );
+        let (_, result) = parse_slash_date("01/27/24", &options).unwrap();
+        assert_eq!(
+            result.data,
+            parse_slash_date("01/27/2024", &options).unwrap().1.data
+        );
+
+        assert!(parse_slash_date("27/01", &options).is_err());
+        assert!(parse_slash_date("02/29/2023", &options).is_err());
+        assert!(parse_slash_date("1/2/3", &options).is_err());
+        assert!(parse_slash_date("01/27/202", &options).is_err());
+
+        let options = ParseOptions {
+            locale: Locale {
+                date_order: DateOrder::DayFirst,
+                ..Locale::english()
+            },
+            ..ParseOptions::default()
+        };
+        let (_, result) = parse_slash_date("27/01", &options).unwrap();
+        assert_eq!(result.data, jan_27);
+        assert!(parse_slash_date("01/27", &options).is_err());
+
+        // single digits on both sides might be a fraction, which aren't dates before a word
+        let options = ParseOptions::default();
+        assert_eq!(parse_flex_date("add 3/4 cup of flour", &options), None);
+        assert_eq!(FlexibleDate::find_and_parse_in_str("3/4 cup"), None);
+        let Parsed { data, .. } = parse_flex_date("1/2 cup of sugar by friday", &options).unwrap();
+        assert_eq!(data, FlexibleDate::Weekday(Weekday::Friday));
+        let Parsed {
+            data, ambiguous, ..
+        } = parse_flex_date("add it on 3/4", &options).unwrap();
+        assert_eq!(
+            data,
+            FlexibleDate::MonthDay {
+                month: Month::March,
+                day: 4
+            }
+        );
+        assert!(!ambiguous);
+        let Parsed { ambiguous, .. } = parse_flex_date("ate 3/4", &options).unwrap();
+        assert!(ambiguous);
+        let Parsed { range, .. } = parse_flex_date("dentist 3/4 at noon", &options).unwrap();
+        assert_eq!(range, (8..11));
+        // two-digit numbers aren't fractions
+        assert!(parse_flex_date("due 12/4 sharp", &options).is_some());
+
+        let Parsed { data, range, .. } =
+            parse_flex_date("renew passport by 12/31/2025", &options).unwrap();
+        assert!(data.is_absolute());
+        assert_eq!(range, (18..28));
+    }
+
+    #[test]
+    fn test_parse_business_days_from_now() {
+        let options = ParseOptions::default();
+
+        let (_, result) =
+            parse_business_days_from_now("in 5 days skipping weekends", &options).unwrap();
+        assert_eq!(result, Matched::exact(FlexibleDate::BusinessDaysFromNow(5)));
+        let (_, result) =
+            parse_business_days_from_now("in 1 day excluding weekends", &options).unwrap();
+        assert_eq!(result.data, FlexibleDate::BusinessDaysFromNow(1));
+        let (_, result) = parse_business_days_from_now("in 3 working days", &options).unwrap();
+        assert_eq!(result.data, FlexibleDate::BusinessDaysFromNow(3));
+
+        let (_, result) = parse_business_days_from_now("in a few business days", &options).unwrap();
+        assert_eq!(
+            result,
+            Matched::approximate(FlexibleDate::BusinessDaysFromNow(3))
+        );
+
+        // the whole phrase wins over a plain offset
+        let Parsed { data, range, .. } =
+            parse_flex_date("ship it in 5 days excluding weekends", &options).unwrap();
+        assert_eq!(data, FlexibleDate::BusinessDaysFromNow(5));
+        assert_eq!(range, (8..36));
+    }
+
+    #[test]
+    fn test_parse_glued_shorthand_boundaries() {
+        let options = ParseOptions::default();
+
+        let Parsed { data, range, .. } = parse_flex_date("ship it 2d", &options).unwrap();
+        assert_eq!(data, FlexibleDate::DaysFromNow(2));
+        assert_eq!(range, (8..10));
+
+        // ordinals and other words that start with a unit letter aren't offsets
+        let Parsed {
+            data, ambiguous, ..
+        } = parse_flex_date("second draft, 2nd try, 3dogs", &options).unwrap();
+        assert_eq!(data, FlexibleDate::DayOfMonth(2));
+        assert!(ambiguous);
+    }
+
+    #[test]
+    fn test_parse_numbers_inside_other_numbers() {
+        let options = ParseOptions::default();
+
+        let input = "upgrade to v1.27 tomorrow";
+        let Parsed { data, range, .. } = parse_flex_date(input, &options).unwrap();
+        assert_eq!(data, FlexibleDate::Tomorrow);
+        assert_eq!(&input[range], "tomorrow");
+
+        let input = "lunch costs $15.00 on friday";
+        let Parsed { data, range, .. } = parse_flex_date(input, &options).unwrap();
+        assert_eq!(data, FlexibleDate::Weekday(Weekday::Friday));
+        assert_eq!(&input[range], "friday");
+
+        // fractions and decimals aren't read as times
+        let input = "bake friday at 3/4 power";
+        let Parsed { data, range, .. } = parse_flex_datetime(input, &options).unwrap();
+        assert_eq!(data.date, FlexibleDate::Weekday(Weekday::Friday));
+        assert!(data.all_day);
+        assert_eq!(&input[range], "friday");
+
+        let Parsed { data, .. } =
+            parse_flex_datetime("pick up tomorrow at 5.30", &options).unwrap();
+        assert!(data.all_day);
+
+        // or as dates
+        for input in [
+            "upgrade to 1.2.3 tomorrow",
+            "version 10.10.10 ships tomorrow",
+            "build 1.2.2024.5 tomorrow",
+        ] {
+            let Parsed { data, .. } = parse_flex_date(input, &options).unwrap();
+            assert_eq!(data, FlexibleDate::Tomorrow, "{input}");
+        }
+        assert!(parse_flex_date("upgrade to 1.2.3", &options).is_none());
+
+        // or as days and counts
+        assert!(parse_flex_date("waiting on the 3.5 release", &options).is_none());
+        assert!(parse_flex_date("save 20% in 2.5 weeks", &options).is_none());
+    }
+
+    #[test]
+    fn test_disabled_rules() {
+        let options = ParseOptions {
+            disabled_rules: vec![Rule::DayOfMonth, Rule::BareOrdinal, Rule::BareHour],
+            ..ParseOptions::default()
+        };
+
+        assert!(parse_flex_date("read the 5th chapter", &options).is_none());
+        let Parsed { data, .. } = parse_flex_date("due jan 5th", &options).unwrap();
+        assert_eq!(
+            data,
+            FlexibleDate::MonthDay {
+                month: Month::January,
+                day: 5
+            }
+        );
+
+        let Parsed { data, range, .. } = parse_flex_datetime("tomorrow at 5", &options).unwrap();
+        assert!(data.all_day);
+        assert_eq!(range, (0..8));
+        let Parsed { data, .. } = parse_flex_datetime("tomorrow at 5pm", &options).unwrap();
+        assert_eq!(data.time.hour, 17);
+    }
+
+    #[test]
+    fn test_confidence() {
+        let options = ParseOptions::default();
+        let confidence = |input| parse_flex_datetime(input, &options).unwrap().confidence;
+
+        assert_eq!(confidence("due 2024-01-27"), 100);
+        assert_eq!(confidence("call mom tomorrow"), 95);
+        // the bare hour is the least specific part, and its meridiem is a guess
+        assert_eq!(confidence("call mom tomorrow at 5"), 65);
+        assert_eq!(confidence("call mom tomorrow at 5pm"), 95);
+        assert_eq!(confidence("read the 5th chapter"), 70);
+        assert_eq!(confidence("sat down"), 30);
+        assert_eq!(confidence("see you on sat"), 70);
+        assert_eq!(confidence("around the 15th"), 55);
+    }
+
+    #[test]
+    fn test_precision() {
+        let options = ParseOptions::default();
+        let precision = |input| parse_flex_datetime(input, &options).unwrap().precision;
+
+        assert_eq!(precision("budget due in 2026"), Precision::Year);
+        assert_eq!(precision("renew the lease next year"), Precision::Year);
+        assert_eq!(precision("move out next month"), Precision::Month);
+        assert_eq!(precision("book flights march next year"), Precision::Month);
+        assert_eq!(precision("the 1st of next month"), Precision::Day);
+        assert_eq!(precision("call mom tomorrow"), Precision::Day);
+        assert_eq!(precision("call mom tomorrow at 5"), Precision::Hour);
+        assert_eq!(precision("cob friday"), Precision::Hour);
+        assert_eq!(precision("standup at 9:15"), Precision::Minute);
+        assert_eq!(precision("next month at 9:15"), Precision::Minute);
+
+        let precision = |input| parse_flex_date_range(input, &options).unwrap().precision;
+        assert_eq!(precision("sometime next week"), Precision::Week);
+        assert_eq!(precision("sometime next quarter"), Precision::Quarter);
+        assert_eq!(precision("the last week of the month"), Precision::Week);
+        assert_eq!(precision("soon"), Precision::Day);
+
+        let Parsed { precision, .. } = parse_standalone_time("at noon", &options).unwrap();
+        assert_eq!(precision, Precision::Hour);
+    }
+
+    #[test]
+    fn test_typo_tolerance() {
+        let options = ParseOptions {
+            typo_tolerance: true,
+            ..ParseOptions::default()
+        };
+
+        let Parsed {
+            data,
+            range,
+            confidence,
+            ..
+        } = parse_flex_date("call mom Tommorow", &options).unwrap();
+        assert_eq!(data, FlexibleDate::Tomorrow);
+        assert_eq!(range, (9..17));
+        assert_eq!(confidence, 75);
+
+        let Parsed { data, range, .. } =
+            parse_flex_datetime("lunch next wensday at noon, ok?", &options).unwrap();
+        let written = parse_flex_datetime("next wednesday at noon", &options).unwrap();
+        assert_eq!(data, written.data);
+        assert_eq!(range, (6..26));
+        let Parsed { data, .. } = parse_flex_date("by thrusday", &options).unwrap();
+        assert_eq!(data, FlexibleDate::Weekday(Weekday::Thursday));
+
+        // text that's a date as written isn't corrected
+        let Parsed { confidence, .. } = parse_flex_date("call mom tomorrow", &options).unwrap();
+        assert_eq!(confidence, 95);
+        assert_eq!(parse_flex_date("a weak link", &options), None);
+
+        // it's opt-in
+        assert_eq!(
+            parse_flex_date("call mom tommorow", &ParseOptions::default()),
+            None
+        );
     }
 
     #[test]
     fn test_parse_junk() {
-        let result = parse_flex_date("I'm a little teapot");
+        let result = parse_flex_date("I'm a little teapot", &ParseOptions::default());
         assert!(result.is_none());
 
         // Make sure we only recognize dates that appear as full tokens
-        let result = parse_flex_date("todd tomm ttoday dtomorrow todayyy");
+        let result = parse_flex_date(
+            "todd tomm ttoday dtomorrow todayyy",
+            &ParseOptions::default(),
+        );
         assert!(result.is_none());
     }
 }
@@ -0,0 +1,376 @@
+use crate::{Precision, Weekday};
+use chrono::{Datelike, Days, Months, NaiveDate};
+
+/// The length of a calendar period.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PeriodUnit {
+    /// A week, starting on Monday.
+    Week,
+    /// The Saturday and Sunday at the end of a week.
+    Weekend,
+    Month,
+    /// Three months, starting in January, April, July, or October.
+    Quarter,
+    Year,
+}
+
+/// What "this week" means as a single date. It always means the whole week as a range.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ThisWeekPolicy {
+    /// "This week" isn't a single date, only a range.
+    WholeWeek,
+
+    /// The next business day, starting with today.
+    NextWorkday,
+
+    /// A particular day that the week is considered to end on, eg. Friday. It means that day of
+    /// the current week, even once it has passed.
+    EndOfWeek(Weekday),
+}
+
+/// What a year on its own, like "in 2026", means as a single date. It always means the whole year
+/// as a range.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum YearPolicy {
+    /// January 1 of that year.
+    FirstDay,
+
+    /// December 31 of that year.
+    LastDay,
+
+    /// A year isn't a single date, only a range.
+    WholeYear,
+}
+
+/// Which occurrence a weekday on its own, like "friday", stands for.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WeekdayScope {
+    /// The next one, starting with today, even if it's in the following week.
+    Upcoming,
+
+    /// The one in the current week, from Monday to Sunday, even if it's already passed. Dates
+    /// that have passed are flagged by [`ResolvedParse::in_past`](crate::ResolvedParse::in_past).
+    CurrentWeek,
+}
+
+/// What "next" means in front of a weekday, as in "next friday", since people disagree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WeekdayPolicy {
+    /// The first one after today, so on a Wednesday "next friday" is two days away.
+    NextOccurrence,
+
+    /// The one in the following calendar week, so on a Wednesday "next friday" is nine days
+    /// away.
+    FollowingWeek,
+}
+
+/// What the beginning, middle, and end of a month, quarter, or year mean, as in "the end of the
+/// year".
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SectionPolicy {
+    /// The period's first day, the day halfway through it, and its last day, so the end of the
+    /// year is December 31.
+    Boundaries,
+
+    /// The same rough days as "early", "mid", and "late" (see [`Period::segment_day`]), marked
+    /// as approximate, for applications that would rather not pin "the end of the year" to its
+    /// very last day.
+    Segments,
+}
+
+/// A rough part of a period, as in "early next month".
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Segment {
+    Early,
+    Mid,
+    Late,
+}
+
+/// A calendar period, counted relative to the period that contains today. An `offset` of 0
+/// is the current period, 1 is the next period, and so on.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Period {
+    pub unit: PeriodUnit,
+    pub offset: u32,
+}
+
+impl PeriodUnit {
+    /// How precise a phrase that covers a whole period of this unit is, eg. "sometime next week".
+    pub(crate) fn precision(&self) -> Precision {
+        match self {
+            PeriodUnit::Week => Precision::Week,
+            PeriodUnit::Weekend => Precision::Day,
+            PeriodUnit::Month => Precision::Month,
+            PeriodUnit::Quarter => Precision::Quarter,
+            PeriodUnit::Year => Precision::Year,
+        }
+    }
+}
+
+impl Period {
+    /// The first day of the period.
+    ///
+    /// ```rust
+    /// # use smart_date::{Period, PeriodUnit};
+    /// # fn main() {
+    /// // a Sunday
+    /// let today = chrono::NaiveDate::parse_from_str("2023-10-08", "%Y-%m-%d").unwrap();
+    ///
+    /// let next_week = Period { unit: PeriodUnit::Week, offset: 1 };
+    /// assert_eq!(next_week.first_day(today).to_string(), "2023-10-09");
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn first_day(&self, today: NaiveDate) -> NaiveDate {
+        match self.unit {
+            PeriodUnit::Week => {
+                let this_monday = today - Days::new(today.weekday().num_days_from_monday().into());
+                this_monday + Days::new(7 * u64::from(self.offset))
+            }
+            PeriodUnit::Weekend => {
+                let week = Period {
+                    unit: PeriodUnit::Week,
+                    offset: self.offset,
+                };
+                week.first_day(today) + Days::new(5)
+            }
+            PeriodUnit::Month => {
+                let first_of_this_month = today - Days::new(today.day0().into());
+                first_of_this_month + Months::new(self.offset)
+            }
+            PeriodUnit::Quarter => {
+                let first_of_this_month = today - Days::new(today.day0().into());
+                let first_of_this_quarter = first_of_this_month - Months::new(today.month0() % 3);
+                first_of_this_quarter + Months::new(3 * self.offset)
+            }
+            PeriodUnit::Year => {
+                let first_of_this_year = today - Days::new(today.ordinal0().into());
+                first_of_this_year + Months::new(12 * self.offset)
+            }
+        }
+    }
+
+    /// The last day of the period.
+    #[must_use]
+    pub fn last_day(&self, today: NaiveDate) -> NaiveDate {
+        match self.unit {
+            PeriodUnit::Week => self.first_day(today) + Days::new(6),
+            PeriodUnit::Weekend => self.first_day(today) + Days::new(1),
+            PeriodUnit::Month => self.first_day(today) + Months::new(1) - Days::new(1),
+            PeriodUnit::Quarter => self.first_day(today) + Months::new(3) - Days::new(1),
+            PeriodUnit::Year => self.first_day(today) + Months::new(12) - Days::new(1),
+        }
+    }
+
+    /// The day halfway through the period, rounding down. For a week, this is Thursday.
+    ///
+    /// ```rust
+    /// # use smart_date::{Period, PeriodUnit};
+    /// # fn main() {
+    /// let today = chrono::NaiveDate::parse_from_str("2023-10-08", "%Y-%m-%d").unwrap();
+    ///
+    /// let this_month = Period { unit: PeriodUnit::Month, offset: 0 };
+    /// assert_eq!(this_month.midpoint(today).to_string(), "2023-10-16");
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn midpoint(&self, today: NaiveDate) -> NaiveDate {
+        midpoint(self.first_day(today), self.last_day(today))
+    }
+
+    /// A representative day for a rough part of the period. Weeks use Monday, Wednesday, and
+    /// Friday for early, mid, and late; months use the 5th, 15th, and 25th; and years use March
+    /// 1, July 1, and November 1 (a day earlier in leap years).
+    ///
+    /// ```rust
+    /// # use smart_date::{Period, PeriodUnit, Segment};
+    /// # fn main() {
+    /// let today = chrono::NaiveDate::parse_from_str("2023-10-08", "%Y-%m-%d").unwrap();
+    ///
+    /// let next_month = Period { unit: PeriodUnit::Month, offset: 1 };
+    /// assert_eq!(next_month.segment_day(&Segment::Early, today).to_string(), "2023-11-05");
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn segment_day(&self, segment: &Segment, today: NaiveDate) -> NaiveDate {
+        let (early, mid, late) = match self.unit {
+            PeriodUnit::Week => (0, 2, 4),
+            PeriodUnit::Weekend => (0, 0, 1),
+            PeriodUnit::Month => (4, 14, 24),
+            PeriodUnit::Quarter => (14, 45, 75),
+            PeriodUnit::Year => (59, 181, 304),
+        };
+        let days_in = match segment {
+            Segment::Early => early,
+            Segment::Mid => mid,
+            Segment::Late => late,
+        };
+        self.first_day(today) + Days::new(days_in)
+    }
+}
+
+/// The quarter numbered `quarter` (from 1 to 4) that hasn't ended yet, as a period counted from
+/// the current quarter. It's in either this year or next year.
+pub(crate) fn next_quarter_numbered(quarter: u32, today: NaiveDate) -> Period {
+    let this_quarter = today.month0() / 3 + 1;
+    let offset = if quarter >= this_quarter {
+        quarter - this_quarter
+    } else {
+        quarter + 4 - this_quarter
+    };
+    Period {
+        unit: PeriodUnit::Quarter,
+        offset,
+    }
+}
+
+/// The day halfway from `first` to `last`, rounding down.
+fn midpoint(first: NaiveDate, last: NaiveDate) -> NaiveDate {
+    let days = (last - first).num_days() / 2;
+    first + Days::new(days.unsigned_abs())
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_year_bounds() {
+        let today = NaiveDate::parse_from_str("2023-10-11", "%Y-%m-%d").unwrap();
+
+        let next_year = Period {
+            unit: PeriodUnit::Year,
+            offset: 1,
+        };
+        assert_eq!(next_year.first_day(today).to_string(), "2024-01-01");
+        assert_eq!(next_year.last_day(today).to_string(), "2024-12-31");
+        assert_eq!(next_year.midpoint(today).to_string(), "2024-07-01");
+        assert_eq!(
+            next_year.segment_day(&Segment::Late, today).to_string(),
+            "2024-10-31"
+        );
+    }
+
+    #[test]
+    fn test_week_bounds() {
+        // a Wednesday
+        let today = NaiveDate::parse_from_str("2023-10-11", "%Y-%m-%d").unwrap();
+
+        let this_week = Period {
+            unit: PeriodUnit::Week,
+            offset: 0,
+        };
+        assert_eq!(this_week.first_day(today).to_string(), "2023-10-09");
+        assert_eq!(this_week.last_day(today).to_string(), "2023-10-15");
+
+        let week_after_next = Period {
+            unit: PeriodUnit::Week,
+            offset: 2,
+        };
+        assert_eq!(week_after_next.first_day(today).to_string(), "2023-10-23");
+        assert_eq!(week_after_next.last_day(today).to_string(), "2023-10-29");
+    }
+
+    #[test]
+    fn test_weekend_bounds() {
+        // a Wednesday
+        let today = NaiveDate::parse_from_str("2023-10-11", "%Y-%m-%d").unwrap();
+
+        let weekend_after_next = Period {
+            unit: PeriodUnit::Weekend,
+            offset: 2,
+        };
+        assert_eq!(
+            weekend_after_next.first_day(today).to_string(),
+            "2023-10-28"
+        );
+        assert_eq!(weekend_after_next.last_day(today).to_string(), "2023-10-29");
+    }
+
+    #[test]
+    fn test_month_bounds() {
+        let today = NaiveDate::parse_from_str("2024-01-31", "%Y-%m-%d").unwrap();
+
+        let this_month = Period {
+            unit: PeriodUnit::Month,
+            offset: 0,
+        };
+        assert_eq!(this_month.first_day(today).to_string(), "2024-01-01");
+        assert_eq!(this_month.last_day(today).to_string(), "2024-01-31");
+
+        let next_month = Period {
+            unit: PeriodUnit::Month,
+            offset: 1,
+        };
+        assert_eq!(next_month.first_day(today).to_string(), "2024-02-01");
+        assert_eq!(next_month.last_day(today).to_string(), "2024-02-29");
+    }
+
+    #[test]
+    fn test_quarter_bounds() {
+        let today = NaiveDate::parse_from_str("2024-05-20", "%Y-%m-%d").unwrap();
+
+        let this_quarter = Period {
+            unit: PeriodUnit::Quarter,
+            offset: 0,
+        };
+        assert_eq!(this_quarter.first_day(today).to_string(), "2024-04-01");
+        assert_eq!(this_quarter.last_day(today).to_string(), "2024-06-30");
+        assert_eq!(this_quarter.midpoint(today).to_string(), "2024-05-16");
+
+        let quarter_after_next = Period {
+            unit: PeriodUnit::Quarter,
+            offset: 2,
+        };
+        assert_eq!(
+            quarter_after_next.first_day(today).to_string(),
+            "2024-10-01"
+        );
+        assert_eq!(quarter_after_next.last_day(today).to_string(), "2024-12-31");
+
+        assert_eq!(next_quarter_numbered(2, today), this_quarter);
+        assert_eq!(next_quarter_numbered(1, today).offset, 3);
+    }
+
+    #[test]
+    fn test_week_midpoint() {
+        // a Sunday
+        let today = NaiveDate::parse_from_str("2023-10-08", "%Y-%m-%d").unwrap();
+
+        let next_week = Period {
+            unit: PeriodUnit::Week,
+            offset: 1,
+        };
+        assert_eq!(next_week.midpoint(today).to_string(), "2023-10-12");
+    }
+
+    #[test]
+    fn test_segment_day() {
+        // a Sunday
+        let today = NaiveDate::parse_from_str("2023-10-08", "%Y-%m-%d").unwrap();
+
+        let next_week = Period {
+            unit: PeriodUnit::Week,
+            offset: 1,
+        };
+        assert_eq!(
+            next_week.segment_day(&Segment::Mid, today).to_string(),
+            "2023-10-11"
+        );
+        assert_eq!(
+            next_week.segment_day(&Segment::Late, today).to_string(),
+            "2023-10-13"
+        );
+
+        let this_month = Period {
+            unit: PeriodUnit::Month,
+            offset: 0,
+        };
+        assert_eq!(
+            this_month.segment_day(&Segment::Late, today).to_string(),
+            "2023-10-25"
+        );
+    }
+}
@@ -0,0 +1,274 @@
+use crate::{
+    parser::{
+        parse_flex_date_range, parse_flex_date_range_exact, parse_lone_whole_year,
+        parse_open_date_range, parse_open_date_range_exact,
+    },
+    FlexibleDate, ParseOptions, Parsed,
+};
+use chrono::NaiveDate;
+use std::ops::RangeInclusive;
+
+/// Where a window of time like "the next 3 days" ends.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WindowEnd {
+    /// On the day that's that far from today, so on a Monday, "the next 3 days" runs through
+    /// Thursday.
+    Through,
+
+    /// On the day before that, so that the window is exactly that long. On a Monday, "the next 3
+    /// days" runs through Wednesday.
+    Before,
+}
+
+/// Represents a span of days between two (inclusive) dates, eg. "sometime next week".
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FlexibleDateRange {
+    pub start: FlexibleDate,
+    pub end: FlexibleDate,
+}
+
+impl FlexibleDateRange {
+    /// Parses a `FlexibleDateRange` from a string. Fails (returns `None`) if the full string does
+    /// not match a range.
+    ///
+    /// ```rust
+    /// # use smart_date::{FlexibleDate, FlexibleDateRange};
+    /// # fn main() {
+    /// let result = FlexibleDateRange::parse_from_str("soon").unwrap();
+    /// assert_eq!(result.start, FlexibleDate::Today);
+    /// assert_eq!(result.end, FlexibleDate::DaysFromNow(3));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn parse_from_str(text: &str) -> Option<FlexibleDateRange> {
+        Self::parse_from_str_with_options(text, &ParseOptions::default())
+    }
+
+    /// Same as [`FlexibleDateRange::parse_from_str`], but with control over how the text is
+    /// parsed.
+    #[must_use]
+    pub fn parse_from_str_with_options(
+        text: &str,
+        options: &ParseOptions,
+    ) -> Option<FlexibleDateRange> {
+        parse_flex_date_range_exact(text, options)
+            .or_else(|_| parse_lone_whole_year(text))
+            .ok()
+            .map(|(_, range)| range.data)
+    }
+
+    /// Finds and parses a `FlexibleDateRange` from within a string.
+    ///
+    /// ```rust
+    /// # use smart_date::FlexibleDateRange;
+    /// # fn main() {
+    /// let result = FlexibleDateRange::find_and_parse_in_str("fix the sink sometime next week").unwrap();
+    /// assert_eq!(result.range, (13..31));
+    /// assert!(result.approximate);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn find_and_parse_in_str(text: &str) -> Option<Parsed<FlexibleDateRange>> {
+        Self::find_and_parse_in_str_with_options(text, &ParseOptions::default())
+    }
+
+    /// Same as [`FlexibleDateRange::find_and_parse_in_str`], but with control over how the text
+    /// is parsed.
+    #[must_use]
+    pub fn find_and_parse_in_str_with_options(
+        text: &str,
+        options: &ParseOptions,
+    ) -> Option<Parsed<FlexibleDateRange>> {
+        parse_flex_date_range(text, options)
+    }
+
+    /// Converts the `FlexibleDateRange` into an inclusive range of [`NaiveDate`]s. An end that
+    /// would come before the start is counted from the start instead, so on a Saturday, "from
+    /// friday to monday" ends on the Monday after that Friday.
+    ///
+    /// ```rust
+    /// # use smart_date::FlexibleDateRange;
+    /// # fn main() {
+    /// // a Sunday
+    /// let today = chrono::NaiveDate::parse_from_str("2023-10-08", "%Y-%m-%d").unwrap();
+    ///
+    /// let range = FlexibleDateRange::parse_from_str("sometime next week").unwrap();
+    /// let range = range.into_naive_date_range(today);
+    /// assert_eq!(range.start().to_string(), "2023-10-09");
+    /// assert_eq!(range.end().to_string(), "2023-10-15");
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn into_naive_date_range(self, today: NaiveDate) -> RangeInclusive<NaiveDate> {
+        let start = self.start.into_naive_date(today);
+        let end = match self.end.clone().into_naive_date(today) {
+            end if end < start => self.end.into_naive_date(start),
+            end => end,
+        };
+        start..=end
+    }
+}
+
+/// Represents a span of days that's only bounded on one side, eg. "anytime before friday" or
+/// "after the 15th". Both bounds are inclusive when present.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct OpenDateRange {
+    /// The first day of the range, if it has one.
+    pub start: Option<FlexibleDate>,
+
+    /// The last day of the range, if it has one.
+    pub end: Option<FlexibleDate>,
+}
+
+impl OpenDateRange {
+    /// Parses an `OpenDateRange` from a string. Fails (returns `None`) if the full string does
+    /// not match an open range.
+    ///
+    /// ```rust
+    /// # use smart_date::{FlexibleDate, OpenDateRange, Weekday};
+    /// # fn main() {
+    /// let result = OpenDateRange::parse_from_str("by friday").unwrap();
+    /// assert_eq!(result.start, None);
+    /// assert_eq!(result.end, Some(FlexibleDate::Weekday(Weekday::Friday)));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn parse_from_str(text: &str) -> Option<OpenDateRange> {
+        Self::parse_from_str_with_options(text, &ParseOptions::default())
+    }
+
+    /// Same as [`OpenDateRange::parse_from_str`], but with control over how the text is parsed.
+    #[must_use]
+    pub fn parse_from_str_with_options(
+        text: &str,
+        options: &ParseOptions,
+    ) -> Option<OpenDateRange> {
+        parse_open_date_range_exact(text, options)
+            .ok()
+            .map(|(_, range)| range.data)
+    }
+
+    /// Finds and parses an `OpenDateRange` from within a string.
+    ///
+    /// ```rust
+    /// # use smart_date::OpenDateRange;
+    /// # fn main() {
+    /// let result = OpenDateRange::find_and_parse_in_str("show tasks due anytime before friday").unwrap();
+    /// assert_eq!(result.range, (15..36));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn find_and_parse_in_str(text: &str) -> Option<Parsed<OpenDateRange>> {
+        Self::find_and_parse_in_str_with_options(text, &ParseOptions::default())
+    }
+
+    /// Same as [`OpenDateRange::find_and_parse_in_str`], but with control over how the text is
+    /// parsed.
+    #[must_use]
+    pub fn find_and_parse_in_str_with_options(
+        text: &str,
+        options: &ParseOptions,
+    ) -> Option<Parsed<OpenDateRange>> {
+        parse_open_date_range(text, options)
+    }
+
+    /// Converts the `OpenDateRange` into inclusive bounds, where `None` means the range goes on
+    /// forever in that direction.
+    ///
+    /// ```rust
+    /// # use smart_date::OpenDateRange;
+    /// # fn main() {
+    /// // a Sunday
+    /// let today = chrono::NaiveDate::parse_from_str("2023-10-08", "%Y-%m-%d").unwrap();
+    ///
+    /// let range = OpenDateRange::parse_from_str("anytime before friday").unwrap();
+    /// let (start, end) = range.into_naive_date_bounds(today);
+    /// assert_eq!(start, None);
+    /// assert_eq!(end.unwrap().to_string(), "2023-10-12");
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn into_naive_date_bounds(
+        self,
+        today: NaiveDate,
+    ) -> (Option<NaiveDate>, Option<NaiveDate>) {
+        (
+            self.start.map(|start| start.into_naive_date(today)),
+            self.end.map(|end| end.into_naive_date(today)),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::{Locale, Weekday};
+
+    #[test]
+    fn test_into_naive_date_range() {
+        // a Saturday
+        let today = NaiveDate::parse_from_str("2023-10-14", "%Y-%m-%d").unwrap();
+
+        let range = FlexibleDateRange::parse_from_str("from friday to monday").unwrap();
+        let range = range.into_naive_date_range(today);
+        assert_eq!(range.start().to_string(), "2023-10-20");
+        assert_eq!(range.end().to_string(), "2023-10-23");
+
+        let range = FlexibleDateRange::parse_from_str("monday - friday").unwrap();
+        let range = range.into_naive_date_range(today);
+        assert_eq!(range.start().to_string(), "2023-10-16");
+        assert_eq!(range.end().to_string(), "2023-10-20");
+
+        let range = FlexibleDateRange::parse_from_str("the last week of the month").unwrap();
+        let range = range.into_naive_date_range(today);
+        assert_eq!(range.start().to_string(), "2023-10-30");
+        assert_eq!(range.end().to_string(), "2023-11-05");
+
+        let range = FlexibleDateRange::parse_from_str("in 2026").unwrap();
+        let range = range.into_naive_date_range(today);
+        assert_eq!(range.start().to_string(), "2026-01-01");
+        assert_eq!(range.end().to_string(), "2026-12-31");
+    }
+
+    #[test]
+    fn test_week_of_month_into_naive_date_range() {
+        // a Saturday
+        let today = NaiveDate::parse_from_str("2023-10-14", "%Y-%m-%d").unwrap();
+
+        // march 1 is a Friday
+        let range = FlexibleDateRange::parse_from_str("first week of march").unwrap();
+        let range = range.into_naive_date_range(today);
+        assert_eq!(range.start().to_string(), "2024-02-26");
+        assert_eq!(range.end().to_string(), "2024-03-03");
+
+        // weeks can start on another day
+        let options = ParseOptions {
+            locale: Locale {
+                week_start: Weekday::Sunday,
+                ..Locale::english()
+            },
+            ..ParseOptions::default()
+        };
+        let range =
+            FlexibleDateRange::parse_from_str_with_options("the first week of march", &options);
+        let range = range.unwrap().into_naive_date_range(today);
+        assert_eq!(range.start().to_string(), "2024-02-25");
+        assert_eq!(range.end().to_string(), "2024-03-02");
+
+        // the last week of december is in this year, and february's has its leap day
+        let range = FlexibleDateRange::parse_from_str("the last week of december").unwrap();
+        let range = range.into_naive_date_range(today);
+        assert_eq!(range.start().to_string(), "2023-12-25");
+        let range = FlexibleDateRange::parse_from_str("last week of february").unwrap();
+        let range = range.into_naive_date_range(today);
+        assert_eq!(range.start().to_string(), "2024-02-26");
+        assert_eq!(range.end().to_string(), "2024-03-03");
+
+        let range = FlexibleDateRange::parse_from_str("the first week of next month").unwrap();
+        let range = range.into_naive_date_range(today);
+        assert_eq!(range.start().to_string(), "2023-10-30");
+        assert_eq!(range.end().to_string(), "2023-11-05");
+    }
+}
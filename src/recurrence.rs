@@ -0,0 +1,870 @@
+use crate::{
+    parser::{parse_recurrence, parse_recurrence_exact},
+    BusinessCalendar, FlexibleDate, FlexibleDuration, FlexibleTime, Month, ParseOptions, Parsed,
+    Weekday, Weekdays,
+};
+use chrono::{Datelike, Days, Months, NaiveDate, NaiveDateTime, Weekday as ChronoWeekday};
+use std::iter;
+
+/// How often a [`Recurrence`] repeats.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Frequency {
+    Daily,
+
+    /// Every business day, as decided by the [`BusinessCalendar`] that the occurrences are found
+    /// with, eg. "every workday" or "every weekday".
+    BusinessDaily,
+
+    /// Weekly on a particular day, or on the same day as the recurrence's start if there's none.
+    Weekly(Option<Weekday>),
+
+    /// Monthly on a particular day of the month, or on the same day as the recurrence's start if
+    /// there's none. Months that don't have that day are skipped.
+    Monthly(Option<u32>),
+    Yearly {
+        month: Month,
+        day: u32,
+    },
+}
+
+/// What a yearly recurrence on February 29 does in years that don't have that day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LeapDayPolicy {
+    /// Skip those years entirely. This matches how iCalendar treats such recurrences.
+    Skip,
+
+    /// Happen on February 28 instead.
+    LastDayOfFebruary,
+}
+
+/// When a [`Recurrence`] stops.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RecurrenceEnd {
+    /// After a particular date, which can still have an occurrence, eg. "until june 1".
+    Until(FlexibleDate),
+
+    /// After a length of time from the recurrence's start, eg. "for 5 weeks".
+    For(FlexibleDuration),
+}
+
+/// Represents a date that repeats, eg. "every monday at 9am".
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Recurrence {
+    pub frequency: Frequency,
+
+    /// How many days, weeks, months, or years pass between occurrences, eg. 3 in "every 3rd
+    /// day". Defaults to 1.
+    pub interval: u32,
+
+    /// The time of day that each occurrence happens at, if there is one.
+    pub time: Option<FlexibleTime>,
+
+    /// Only relevant to yearly recurrences on February 29.
+    pub leap_day_policy: LeapDayPolicy,
+
+    /// When the recurrence starts, eg. "starting next monday". It starts today if there's none.
+    pub start: Option<FlexibleDate>,
+
+    /// When the recurrence stops, eg. "until june 1". It goes on forever if there's none.
+    pub end: Option<RecurrenceEnd>,
+
+    /// Whether occurrences that aren't business days are skipped, eg. "every monday except
+    /// holidays". Which days those are depends on the [`BusinessCalendar`] that the occurrences
+    /// are found with.
+    pub except_holidays: bool,
+}
+
+impl Recurrence {
+    /// Creates a recurrence with the given frequency, an interval of 1, no time, no end, and no
+    /// exceptions.
+    #[must_use]
+    pub fn new(frequency: Frequency) -> Self {
+        Self {
+            frequency,
+            interval: 1,
+            time: None,
+            leap_day_policy: LeapDayPolicy::Skip,
+            start: None,
+            end: None,
+            except_holidays: false,
+        }
+    }
+
+    /// Parses a `Recurrence` from a string. Fails (returns `None`) if the full string does not
+    /// match a recurrence.
+    ///
+    /// ```rust
+    /// # use smart_date::{Frequency, Recurrence, Weekday};
+    /// # fn main() {
+    /// let result = Recurrence::parse_from_str("every monday at 9am").unwrap();
+    /// assert_eq!(result.frequency, Frequency::Weekly(Some(Weekday::Monday)));
+    /// assert_eq!(result.time.unwrap().hour, 9);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn parse_from_str(text: &str) -> Option<Recurrence> {
+        Self::parse_from_str_with_options(text, &ParseOptions::default())
+    }
+
+    /// Same as [`Recurrence::parse_from_str`], but with control over how the text is parsed.
+    #[must_use]
+    pub fn parse_from_str_with_options(text: &str, options: &ParseOptions) -> Option<Recurrence> {
+        parse_recurrence_exact(text, options)
+            .ok()
+            .map(|(_, recurrence)| recurrence.data)
+    }
+
+    /// Finds and parses a `Recurrence` from within a string.
+    ///
+    /// ```rust
+    /// # use smart_date::{Frequency, Recurrence};
+    /// # fn main() {
+    /// let result = Recurrence::find_and_parse_in_str("stretch every morning at 8").unwrap();
+    /// assert_eq!(result.data.frequency, Frequency::Daily);
+    /// assert_eq!(result.range, (8..26));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn find_and_parse_in_str(text: &str) -> Option<Parsed<Recurrence>> {
+        Self::find_and_parse_in_str_with_options(text, &ParseOptions::default())
+    }
+
+    /// Same as [`Recurrence::find_and_parse_in_str`], but with control over how the text is
+    /// parsed.
+    #[must_use]
+    pub fn find_and_parse_in_str_with_options(
+        text: &str,
+        options: &ParseOptions,
+    ) -> Option<Parsed<Recurrence>> {
+        parse_recurrence(text, options)
+    }
+
+    /// The dates that the recurrence happens on, starting from (and possibly including) `today`
+    /// or the recurrence's start date if it has one, and stopping at its end if it has one.
+    ///
+    /// ```rust
+    /// # use smart_date::Recurrence;
+    /// # fn main() {
+    /// // a Sunday
+    /// let today = chrono::NaiveDate::parse_from_str("2023-10-08", "%Y-%m-%d").unwrap();
+    ///
+    /// let recurrence = Recurrence::parse_from_str("every monday").unwrap();
+    /// let dates: Vec<_> = recurrence.occurrences(today).take(2).map(|d| d.to_string()).collect();
+    /// assert_eq!(dates, vec!["2023-10-09", "2023-10-16"]);
+    /// # }
+    /// ```
+    pub fn occurrences(&self, today: NaiveDate) -> impl Iterator<Item = NaiveDate> {
+        self.occurrences_with_calendar(today, &Weekdays)
+    }
+
+    /// Same as [`Recurrence::occurrences`], but with control over which days count as business
+    /// days, eg. to leave out holidays.
+    ///
+    /// ```rust
+    /// # use smart_date::{BusinessCalendar, Recurrence, Weekdays};
+    /// # use chrono::NaiveDate;
+    /// # fn main() {
+    /// struct Holidays(Vec<NaiveDate>);
+    ///
+    /// impl BusinessCalendar for Holidays {
+    ///     fn is_business_day(&self, date: NaiveDate) -> bool {
+    ///         !self.0.contains(&date) && Weekdays.is_business_day(date)
+    ///     }
+    /// }
+    ///
+    /// // a Friday, before Christmas on a Monday
+    /// let today = NaiveDate::parse_from_str("2023-12-22", "%Y-%m-%d").unwrap();
+    /// let christmas = NaiveDate::parse_from_str("2023-12-25", "%Y-%m-%d").unwrap();
+    ///
+    /// let recurrence = Recurrence::parse_from_str("every workday at 9 except holidays").unwrap();
+    /// let dates: Vec<_> = recurrence
+    ///     .occurrences_with_calendar(today, &Holidays(vec![christmas]))
+    ///     .take(2)
+    ///     .map(|d| d.to_string())
+    ///     .collect();
+    /// assert_eq!(dates, vec!["2023-12-22", "2023-12-26"]);
+    /// # }
+    /// ```
+    pub fn occurrences_with_calendar<'a>(
+        &self,
+        today: NaiveDate,
+        calendar: &'a dyn BusinessCalendar,
+    ) -> impl Iterator<Item = NaiveDate> + 'a {
+        let occurrences = match self.first_day(today, calendar) {
+            Some(from) => self.occurrences_from(from, calendar),
+            // a start too far away for a `NaiveDate` never comes
+            None => Box::new(iter::empty()),
+        };
+        let last = self.last_day(today, calendar).unwrap_or(NaiveDate::MAX);
+        let except_holidays = self.except_holidays;
+        occurrences
+            .take_while(move |date| *date <= last)
+            .filter(move |date| !except_holidays || calendar.is_business_day(*date))
+    }
+
+    /// Every day that the recurrence's frequency lands on, starting from `from`, before taking
+    /// its end and holidays into account.
+    fn occurrences_from<'a>(
+        &self,
+        from: NaiveDate,
+        calendar: &'a dyn BusinessCalendar,
+    ) -> Box<dyn Iterator<Item = NaiveDate> + 'a> {
+        let interval = self.interval.max(1);
+        let weeks = Days::new(7 * u64::from(interval));
+        match &self.frequency {
+            Frequency::Daily => Box::new(every(from, Days::new(interval.into()))),
+            Frequency::BusinessDaily => Box::new(
+                from.iter_days()
+                    .filter(|date| calendar.is_business_day(*date))
+                    .step_by(interval as usize),
+            ),
+            Frequency::Weekly(None) => Box::new(every(from, weeks)),
+            Frequency::Weekly(Some(day)) => {
+                let weekday: Weekday = from.weekday().into();
+                let first = from.checked_add_days(Days::new(weekday.days_until(day)));
+                Box::new(first.into_iter().flat_map(move |first| every(first, weeks)))
+            }
+            Frequency::Monthly(day) => {
+                let day = day.unwrap_or(from.day());
+                Box::new(monthly_occurrences(from, day, interval))
+            }
+            Frequency::Yearly { month, day } => {
+                Box::new(self.yearly_occurrences(from, month.number(), *day, interval))
+            }
+        }
+    }
+
+    /// The day that the recurrence starts on, which is `today` unless it has a start date, or
+    /// `None` if the start is too far away for a [`NaiveDate`] to hold.
+    fn first_day(&self, today: NaiveDate, calendar: &dyn BusinessCalendar) -> Option<NaiveDate> {
+        self.start.clone().map_or(Some(today), |start| {
+            start.checked_into_naive_date_with_calendar(today, calendar)
+        })
+    }
+
+    /// The last day that the recurrence can happen on, if it ends. Lengths of time that end
+    /// partway through a day last through that day, so "every day for 90 minutes" happens today.
+    /// Ends too far away for a [`NaiveDate`] to hold count as no end.
+    fn last_day(&self, today: NaiveDate, calendar: &dyn BusinessCalendar) -> Option<NaiveDate> {
+        match self.end.as_ref()? {
+            RecurrenceEnd::Until(date) => date
+                .clone()
+                .checked_into_naive_date_with_calendar(today, calendar),
+            RecurrenceEnd::For(duration) => {
+                let day = 24 * 60;
+                let whole_days = FlexibleDuration {
+                    minutes: duration.minutes.div_ceil(day).checked_mul(day)?,
+                    ..duration.clone()
+                };
+                whole_days
+                    .add_to_date(self.first_day(today, calendar)?)?
+                    .pred_opt()
+            }
+        }
+    }
+
+    /// Writes the recurrence as an iCalendar (RFC 5545) `RRULE` property, eg.
+    /// `RRULE:FREQ=WEEKLY;BYDAY=MO` for "every monday", so that it can be handed to a calendar.
+    /// Relative end dates are resolved from `today`. The rule doesn't include the start date,
+    /// which belongs in the event's `DTSTART`, so recurrences without a particular day (like
+    /// "every week") happen on the same day as that. Business days are taken to be Monday through
+    /// Friday, and holidays can't be left out without listing them in `EXDATE`s.
+    ///
+    /// Midnight at the end of a day (24:00, as in "every friday at midnight") is written as the
+    /// start of the next day, since iCalendar hours only go up to 23, so the rule moves to that
+    /// day, and `DTSTART` should too. Fails (returns `None`) if no rule covers the days after,
+    /// like the day after the 31st in each month that has one.
+    ///
+    /// ```rust
+    /// # use smart_date::Recurrence;
+    /// # fn main() {
+    /// let today = chrono::NaiveDate::parse_from_str("2023-10-08", "%Y-%m-%d").unwrap();
+    ///
+    /// let recurrence = Recurrence::parse_from_str("every other friday at 9am").unwrap();
+    /// assert_eq!(
+    ///     recurrence.to_rrule_string(today).unwrap(),
+    ///     "RRULE:FREQ=WEEKLY;INTERVAL=2;BYDAY=FR;BYHOUR=9;BYMINUTE=0"
+    /// );
+    ///
+    /// let recurrence = Recurrence::parse_from_str("every month on the 15th until dec 31").unwrap();
+    /// assert_eq!(
+    ///     recurrence.to_rrule_string(today).unwrap(),
+    ///     "RRULE:FREQ=MONTHLY;UNTIL=20231231;BYMONTHDAY=15"
+    /// );
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_rrule_string(&self, today: NaiveDate) -> Option<String> {
+        let end_of_day = self.time.as_ref().is_some_and(|time| time.hour == 24);
+        let mut parts = vec![format!(
+            "FREQ={}",
+            match self.frequency {
+                Frequency::Daily => "DAILY",
+                Frequency::BusinessDaily | Frequency::Weekly(_) => "WEEKLY",
+                Frequency::Monthly(_) => "MONTHLY",
+                Frequency::Yearly { .. } => "YEARLY",
+            }
+        )];
+        if self.interval > 1 {
+            parts.push(format!("INTERVAL={}", self.interval));
+        }
+        if let Some(last) = self.last_day(today, &Weekdays) {
+            let last = if end_of_day { last.succ_opt()? } else { last };
+            // the end has to be a date and time if the occurrences are
+            parts.push(match self.time {
+                Some(_) => format!("UNTIL={}T235959", last.format("%Y%m%d")),
+                None => format!("UNTIL={}", last.format("%Y%m%d")),
+            });
+        }
+        match &self.frequency {
+            Frequency::Daily | Frequency::Weekly(None) | Frequency::Monthly(None) => {}
+            Frequency::BusinessDaily if end_of_day => parts.push("BYDAY=TU,WE,TH,FR,SA".into()),
+            Frequency::BusinessDaily => parts.push("BYDAY=MO,TU,WE,TH,FR".into()),
+            Frequency::Weekly(Some(day)) => {
+                let day = if end_of_day {
+                    ChronoWeekday::from(day.clone()).succ().into()
+                } else {
+                    day.clone()
+                };
+                parts.push(format!("BYDAY={}", rrule_weekday(&day)));
+            }
+            Frequency::Monthly(Some(day)) => {
+                let day = match end_of_day {
+                    // every month has the day after these
+                    true if *day < 28 => day + 1,
+                    true => return None,
+                    false => *day,
+                };
+                parts.push(format!("BYMONTHDAY={day}"));
+            }
+            Frequency::Yearly { month, day } => {
+                // the last day of february is the 29th in leap years and the 28th otherwise
+                let (month, day) = match (month, day, &self.leap_day_policy, end_of_day) {
+                    (Month::February, 29, LeapDayPolicy::LastDayOfFebruary, false) => (2, -1),
+                    (Month::February, 29, LeapDayPolicy::LastDayOfFebruary, true) => (3, 1),
+                    // the day after depends on whether it's a leap year
+                    (Month::February, 28 | 29, _, true) => return None,
+                    (month, day, _, true) => {
+                        let next =
+                            NaiveDate::from_ymd_opt(2023, month.number(), *day)?.succ_opt()?;
+                        (next.month(), i64::from(next.day()))
+                    }
+                    (month, day, _, false) => (month.number(), i64::from(*day)),
+                };
+                parts.push(format!("BYMONTH={month}"));
+                parts.push(format!("BYMONTHDAY={day}"));
+            }
+        }
+        if let Some(time) = &self.time {
+            parts.push(format!("BYHOUR={}", time.hour % 24));
+            parts.push(format!("BYMINUTE={}", time.minute));
+        }
+        Some(format!("RRULE:{}", parts.join(";")))
+    }
+
+    fn yearly_occurrences(
+        &self,
+        from: NaiveDate,
+        month: u32,
+        day: u32,
+        interval: u32,
+    ) -> impl Iterator<Item = NaiveDate> {
+        let leap_day_policy = self.leap_day_policy.clone();
+        (from.year()..=NaiveDate::MAX.year())
+            .step_by(interval as usize)
+            .filter_map(move |year| {
+                NaiveDate::from_ymd_opt(year, month, day).or_else(|| match leap_day_policy {
+                    LeapDayPolicy::Skip => None,
+                    LeapDayPolicy::LastDayOfFebruary => NaiveDate::from_ymd_opt(year, 2, 28),
+                })
+            })
+            .skip_while(move |date| *date < from)
+    }
+
+    /// The dates and times that the recurrence happens at, starting from (and possibly
+    /// including) `now`, or the recurrence's start date if it's later. Recurrences without a
+    /// time happen at midnight.
+    ///
+    /// ```rust
+    /// # use smart_date::Recurrence;
+    /// # fn main() {
+    /// let now = chrono::NaiveDateTime::parse_from_str("2023-10-08 12:00", "%Y-%m-%d %H:%M").unwrap();
+    ///
+    /// let recurrence = Recurrence::parse_from_str("every morning at 8").unwrap();
+    /// let next = recurrence.datetime_occurrences(now).next().unwrap();
+    /// assert_eq!(next.to_string(), "2023-10-09 08:00:00");
+    /// # }
+    /// ```
+    pub fn datetime_occurrences(&self, now: NaiveDateTime) -> impl Iterator<Item = NaiveDateTime> {
+        self.datetime_occurrences_with_calendar(now, &Weekdays)
+    }
+
+    /// Same as [`Recurrence::datetime_occurrences`], but with control over which days count as
+    /// business days, like [`Recurrence::occurrences_with_calendar`].
+    pub fn datetime_occurrences_with_calendar<'a>(
+        &self,
+        now: NaiveDateTime,
+        calendar: &'a dyn BusinessCalendar,
+    ) -> impl Iterator<Item = NaiveDateTime> + 'a {
+        let time = self
+            .time
+            .clone()
+            .unwrap_or(FlexibleTime { hour: 0, minute: 0 });
+        self.occurrences_with_calendar(now.date(), calendar)
+            .map_while(move |date| time.clone().on_date(date))
+            .skip_while(move |datetime| *datetime < now)
+    }
+}
+
+/// The two-letter code that iCalendar uses for a weekday.
+fn rrule_weekday(day: &Weekday) -> &'static str {
+    match day {
+        Weekday::Monday => "MO",
+        Weekday::Tuesday => "TU",
+        Weekday::Wednesday => "WE",
+        Weekday::Thursday => "TH",
+        Weekday::Friday => "FR",
+        Weekday::Saturday => "SA",
+        Weekday::Sunday => "SU",
+    }
+}
+
+/// The `day` of every `interval`th month, starting from (and possibly including) `from`.
+fn monthly_occurrences(
+    from: NaiveDate,
+    day: u32,
+    interval: u32,
+) -> impl Iterator<Item = NaiveDate> {
+    let first_of_month = from - Days::new(from.day0().into());
+    (0..)
+        .step_by(interval as usize)
+        .map_while(move |offset| first_of_month.checked_add_months(Months::new(offset)))
+        .filter_map(move |month| month.with_day(day))
+        .skip_while(move |date| *date < from)
+}
+
+/// Every date `step` apart, starting with `first`.
+fn every(first: NaiveDate, step: Days) -> impl Iterator<Item = NaiveDate> {
+    iter::successors(Some(first), move |date| date.checked_add_days(step))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_occurrences() {
+        // a Wednesday
+        let today = NaiveDate::parse_from_str("2023-10-11", "%Y-%m-%d").unwrap();
+
+        let recurrence = Recurrence::new(Frequency::Weekly(Some(Weekday::Wednesday)));
+        let dates: Vec<_> = recurrence.occurrences(today).take(2).collect();
+        assert_eq!(dates[0].to_string(), "2023-10-11");
+        assert_eq!(dates[1].to_string(), "2023-10-18");
+
+        let recurrence = Recurrence::new(Frequency::Daily);
+        let dates: Vec<_> = recurrence.occurrences(today).take(2).collect();
+        assert_eq!(dates[1].to_string(), "2023-10-12");
+    }
+
+    #[test]
+    fn test_occurrences_with_start() {
+        // a Wednesday
+        let today = NaiveDate::parse_from_str("2023-10-11", "%Y-%m-%d").unwrap();
+
+        let recurrence = Recurrence {
+            start: Some(FlexibleDate::Weekday(Weekday::Friday)),
+            ..Recurrence::new(Frequency::Weekly(None))
+        };
+        let dates: Vec<_> = recurrence.occurrences(today).take(2).collect();
+        assert_eq!(dates[0].to_string(), "2023-10-13");
+        assert_eq!(dates[1].to_string(), "2023-10-20");
+
+        let recurrence = Recurrence {
+            start: Some(FlexibleDate::Weekday(Weekday::Friday)),
+            ..Recurrence::new(Frequency::Weekly(Some(Weekday::Tuesday)))
+        };
+        let dates: Vec<_> = recurrence.occurrences(today).take(1).collect();
+        assert_eq!(dates[0].to_string(), "2023-10-17");
+    }
+
+    #[test]
+    fn test_occurrences_with_end() {
+        // a Wednesday
+        let today = NaiveDate::parse_from_str("2023-10-11", "%Y-%m-%d").unwrap();
+
+        // the end date can have an occurrence
+        let recurrence = Recurrence {
+            end: Some(RecurrenceEnd::Until(FlexibleDate::Weekday(Weekday::Friday))),
+            ..Recurrence::new(Frequency::Daily)
+        };
+        let dates: Vec<_> = recurrence.occurrences(today).collect();
+        assert_eq!(dates.len(), 3);
+        assert_eq!(dates[2].to_string(), "2023-10-13");
+
+        let recurrence = Recurrence {
+            end: Some(RecurrenceEnd::For(FlexibleDuration {
+                days: 14,
+                ..FlexibleDuration::default()
+            })),
+            ..Recurrence::new(Frequency::Weekly(None))
+        };
+        let dates: Vec<_> = recurrence.occurrences(today).collect();
+        assert_eq!(dates.len(), 2);
+        assert_eq!(dates[1].to_string(), "2023-10-18");
+
+        // part of a day lasts through that day
+        let recurrence = Recurrence::parse_from_str("every day for 90 minutes").unwrap();
+        let dates: Vec<_> = recurrence.occurrences(today).collect();
+        assert_eq!(dates, vec![today]);
+        assert_eq!(
+            recurrence.to_rrule_string(today).unwrap(),
+            "RRULE:FREQ=DAILY;UNTIL=20231011"
+        );
+        let recurrence = Recurrence::parse_from_str("every day for 36 hours").unwrap();
+        assert_eq!(recurrence.occurrences(today).count(), 2);
+    }
+
+    #[test]
+    fn test_occurrences_with_endless_end() {
+        // a Wednesday
+        let today = NaiveDate::parse_from_str("2023-10-11", "%Y-%m-%d").unwrap();
+
+        // ends past the end of the calendar are the same as no end
+        for text in [
+            "every day for 4000000000 days",
+            "every day for 99999999 years",
+        ] {
+            let recurrence = Recurrence::parse_from_str(text).unwrap();
+            let dates: Vec<_> = recurrence.occurrences(today).take(2).collect();
+            assert_eq!(dates[1].to_string(), "2023-10-12", "{text}");
+            assert_eq!(
+                recurrence.to_rrule_string(today).unwrap(),
+                "RRULE:FREQ=DAILY",
+                "{text}"
+            );
+        }
+
+        let recurrence =
+            Recurrence::parse_from_str("every day starting in 4000000000 days for 2 days").unwrap();
+        assert_eq!(recurrence.occurrences(today).next(), None);
+        assert_eq!(
+            recurrence.to_rrule_string(today).unwrap(),
+            "RRULE:FREQ=DAILY"
+        );
+    }
+
+    #[test]
+    fn test_occurrences_with_endless_start() {
+        let today = NaiveDate::parse_from_str("2023-10-11", "%Y-%m-%d").unwrap();
+
+        // starts too far away for a date to hold never come
+        for text in [
+            "every monday starting in 4000000000 days",
+            "every year on march 5 starting in 4000000000 days",
+            "every month on the 5th starting in 4000000000 days",
+        ] {
+            let recurrence = Recurrence::parse_from_str(text).unwrap();
+            assert_eq!(recurrence.occurrences(today).next(), None, "{text}");
+        }
+
+        // years run out at the end of the calendar
+        let recurrence = Recurrence::new(Frequency::Yearly {
+            month: Month::February,
+            day: 30,
+        });
+        assert_eq!(recurrence.occurrences(today).next(), None);
+    }
+
+    #[test]
+    fn test_business_day_occurrences() {
+        struct Holiday(NaiveDate);
+
+        impl BusinessCalendar for Holiday {
+            fn is_business_day(&self, date: NaiveDate) -> bool {
+                date != self.0 && Weekdays.is_business_day(date)
+            }
+        }
+
+        // a Friday, the day before a Monday holiday
+        let today = NaiveDate::parse_from_str("2023-10-13", "%Y-%m-%d").unwrap();
+        let holiday = Holiday(NaiveDate::parse_from_str("2023-10-16", "%Y-%m-%d").unwrap());
+
+        let recurrence = Recurrence::new(Frequency::BusinessDaily);
+        let dates: Vec<_> = recurrence.occurrences(today).take(2).collect();
+        assert_eq!(dates[1].to_string(), "2023-10-16");
+        let dates: Vec<_> = recurrence
+            .occurrences_with_calendar(today, &holiday)
+            .take(2)
+            .collect();
+        assert_eq!(dates[1].to_string(), "2023-10-17");
+
+        // other frequencies only skip holidays when asked to
+        let recurrence = Recurrence::new(Frequency::Weekly(Some(Weekday::Monday)));
+        let dates: Vec<_> = recurrence
+            .occurrences_with_calendar(today, &holiday)
+            .take(1)
+            .collect();
+        assert_eq!(dates[0].to_string(), "2023-10-16");
+        let recurrence = Recurrence {
+            except_holidays: true,
+            ..recurrence
+        };
+        let dates: Vec<_> = recurrence
+            .occurrences_with_calendar(today, &holiday)
+            .take(1)
+            .collect();
+        assert_eq!(dates[0].to_string(), "2023-10-23");
+
+        // the time comes along too
+        let now = today.and_hms_opt(12, 0, 0).unwrap();
+        let recurrence = Recurrence::parse_from_str("every workday at 9 except holidays").unwrap();
+        let next = recurrence
+            .datetime_occurrences_with_calendar(now, &holiday)
+            .next()
+            .unwrap();
+        assert_eq!(next.to_string(), "2023-10-17 09:00:00");
+    }
+
+    #[test]
+    fn test_to_rrule_string() {
+        // a Wednesday
+        let today = NaiveDate::parse_from_str("2023-10-11", "%Y-%m-%d").unwrap();
+        let rrule = |text| {
+            Recurrence::parse_from_str(text)
+                .unwrap()
+                .to_rrule_string(today)
+                .unwrap()
+        };
+
+        assert_eq!(rrule("every day"), "RRULE:FREQ=DAILY");
+        assert_eq!(rrule("every week"), "RRULE:FREQ=WEEKLY");
+        assert_eq!(
+            rrule("every workday"),
+            "RRULE:FREQ=WEEKLY;BYDAY=MO,TU,WE,TH,FR"
+        );
+        assert_eq!(rrule("every 3rd day"), "RRULE:FREQ=DAILY;INTERVAL=3");
+        assert_eq!(
+            rrule("every march 5"),
+            "RRULE:FREQ=YEARLY;BYMONTH=3;BYMONTHDAY=5"
+        );
+        assert_eq!(
+            rrule("every morning at 8:30"),
+            "RRULE:FREQ=DAILY;BYHOUR=8;BYMINUTE=30"
+        );
+
+        // the end is counted from the start
+        assert_eq!(
+            rrule("every day starting friday for 2 weeks"),
+            "RRULE:FREQ=DAILY;UNTIL=20231026"
+        );
+        assert_eq!(
+            rrule("every friday until dec 31 at 5pm"),
+            "RRULE:FREQ=WEEKLY;UNTIL=20231231T235959;BYDAY=FR;BYHOUR=17;BYMINUTE=0"
+        );
+
+        let recurrence = Recurrence {
+            leap_day_policy: LeapDayPolicy::LastDayOfFebruary,
+            ..Recurrence::new(Frequency::Yearly {
+                month: Month::February,
+                day: 29,
+            })
+        };
+        assert_eq!(
+            recurrence.to_rrule_string(today).unwrap(),
+            "RRULE:FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=-1"
+        );
+        let recurrence = Recurrence {
+            leap_day_policy: LeapDayPolicy::Skip,
+            ..recurrence
+        };
+        assert_eq!(
+            recurrence.to_rrule_string(today).unwrap(),
+            "RRULE:FREQ=YEARLY;BYMONTH=2;BYMONTHDAY=29"
+        );
+    }
+
+    #[test]
+    fn test_midnight_to_rrule_string() {
+        // a Wednesday
+        let today = NaiveDate::parse_from_str("2023-10-11", "%Y-%m-%d").unwrap();
+        let rrule = |text| {
+            Recurrence::parse_from_str(text)
+                .unwrap()
+                .to_rrule_string(today)
+                .unwrap()
+        };
+
+        // midnight at the end of the day is the start of the next one
+        assert_eq!(
+            rrule("every day at midnight"),
+            "RRULE:FREQ=DAILY;BYHOUR=0;BYMINUTE=0"
+        );
+        assert_eq!(
+            rrule("every friday at midnight"),
+            "RRULE:FREQ=WEEKLY;BYDAY=SA;BYHOUR=0;BYMINUTE=0"
+        );
+        assert_eq!(
+            rrule("every sunday at midnight"),
+            "RRULE:FREQ=WEEKLY;BYDAY=MO;BYHOUR=0;BYMINUTE=0"
+        );
+        assert_eq!(
+            rrule("every workday at midnight"),
+            "RRULE:FREQ=WEEKLY;BYDAY=TU,WE,TH,FR,SA;BYHOUR=0;BYMINUTE=0"
+        );
+        assert_eq!(
+            rrule("every friday until dec 31 at midnight"),
+            "RRULE:FREQ=WEEKLY;UNTIL=20240101T235959;BYDAY=SA;BYHOUR=0;BYMINUTE=0"
+        );
+
+        let midnight = Some(FlexibleTime {
+            hour: 24,
+            minute: 0,
+        });
+        let at_midnight = |frequency| Recurrence {
+            time: midnight.clone(),
+            ..Recurrence::new(frequency)
+        };
+        let rrule = |recurrence: Recurrence| recurrence.to_rrule_string(today);
+        assert_eq!(
+            rrule(at_midnight(Frequency::Monthly(Some(15)))).unwrap(),
+            "RRULE:FREQ=MONTHLY;BYMONTHDAY=16;BYHOUR=0;BYMINUTE=0"
+        );
+        assert_eq!(
+            rrule(at_midnight(Frequency::Yearly {
+                month: Month::December,
+                day: 31
+            }))
+            .unwrap(),
+            "RRULE:FREQ=YEARLY;BYMONTH=1;BYMONTHDAY=1;BYHOUR=0;BYMINUTE=0"
+        );
+        assert_eq!(
+            rrule(at_midnight(Frequency::Yearly {
+                month: Month::April,
+                day: 30
+            }))
+            .unwrap(),
+            "RRULE:FREQ=YEARLY;BYMONTH=5;BYMONTHDAY=1;BYHOUR=0;BYMINUTE=0"
+        );
+        assert_eq!(
+            rrule(Recurrence {
+                leap_day_policy: LeapDayPolicy::LastDayOfFebruary,
+                ..at_midnight(Frequency::Yearly {
+                    month: Month::February,
+                    day: 29
+                })
+            })
+            .unwrap(),
+            "RRULE:FREQ=YEARLY;BYMONTH=3;BYMONTHDAY=1;BYHOUR=0;BYMINUTE=0"
+        );
+
+        // the days after these aren't the same day each month or year
+        assert_eq!(rrule(at_midnight(Frequency::Monthly(Some(31)))), None);
+        assert_eq!(
+            rrule(at_midnight(Frequency::Yearly {
+                month: Month::February,
+                day: 28
+            })),
+            None
+        );
+    }
+
+    #[test]
+    fn test_monthly_occurrences() {
+        let today = NaiveDate::parse_from_str("2024-01-20", "%Y-%m-%d").unwrap();
+
+        let recurrence = Recurrence::new(Frequency::Monthly(Some(15)));
+        let dates: Vec<_> = recurrence.occurrences(today).take(2).collect();
+        assert_eq!(dates[0].to_string(), "2024-02-15");
+        assert_eq!(dates[1].to_string(), "2024-03-15");
+
+        // february doesn't have a 31st
+        let recurrence = Recurrence::new(Frequency::Monthly(Some(31)));
+        let dates: Vec<_> = recurrence.occurrences(today).take(2).collect();
+        assert_eq!(dates[0].to_string(), "2024-01-31");
+        assert_eq!(dates[1].to_string(), "2024-03-31");
+
+        let recurrence = Recurrence::new(Frequency::Monthly(None));
+        let dates: Vec<_> = recurrence.occurrences(today).take(2).collect();
+        assert_eq!(dates[0].to_string(), "2024-01-20");
+        assert_eq!(dates[1].to_string(), "2024-02-20");
+    }
+
+    #[test]
+    fn test_occurrences_with_interval() {
+        // a Wednesday
+        let today = NaiveDate::parse_from_str("2023-10-11", "%Y-%m-%d").unwrap();
+
+        let recurrence = Recurrence {
+            interval: 3,
+            ..Recurrence::new(Frequency::Daily)
+        };
+        let dates: Vec<_> = recurrence.occurrences(today).take(2).collect();
+        assert_eq!(dates[1].to_string(), "2023-10-14");
+
+        let recurrence = Recurrence {
+            interval: 2,
+            ..Recurrence::new(Frequency::Weekly(Some(Weekday::Friday)))
+        };
+        let dates: Vec<_> = recurrence.occurrences(today).take(2).collect();
+        assert_eq!(dates[0].to_string(), "2023-10-13");
+        assert_eq!(dates[1].to_string(), "2023-10-27");
+
+        let recurrence = Recurrence {
+            interval: 2,
+            ..Recurrence::new(Frequency::Monthly(Some(15)))
+        };
+        let dates: Vec<_> = recurrence.occurrences(today).take(2).collect();
+        assert_eq!(dates[0].to_string(), "2023-10-15");
+        assert_eq!(dates[1].to_string(), "2023-12-15");
+    }
+
+    #[test]
+    fn test_yearly_occurrences() {
+        let today = NaiveDate::parse_from_str("2023-10-11", "%Y-%m-%d").unwrap();
+
+        let recurrence = Recurrence::new(Frequency::Yearly {
+            month: Month::March,
+            day: 5,
+        });
+        let dates: Vec<_> = recurrence.occurrences(today).take(2).collect();
+        assert_eq!(dates[0].to_string(), "2024-03-05");
+        assert_eq!(dates[1].to_string(), "2025-03-05");
+
+        let recurrence = Recurrence::new(Frequency::Yearly {
+            month: Month::February,
+            day: 29,
+        });
+        let dates: Vec<_> = recurrence.occurrences(today).take(2).collect();
+        assert_eq!(dates[0].to_string(), "2024-02-29");
+        assert_eq!(dates[1].to_string(), "2028-02-29");
+
+        let recurrence = Recurrence {
+            leap_day_policy: LeapDayPolicy::LastDayOfFebruary,
+            ..recurrence
+        };
+        let dates: Vec<_> = recurrence.occurrences(today).take(2).collect();
+        assert_eq!(dates[0].to_string(), "2024-02-29");
+        assert_eq!(dates[1].to_string(), "2025-02-28");
+    }
+
+    #[test]
+    fn test_datetime_occurrences() {
+        let recurrence = Recurrence {
+            time: Some(FlexibleTime { hour: 9, minute: 0 }),
+            ..Recurrence::new(Frequency::Weekly(Some(Weekday::Wednesday)))
+        };
+
+        // earlier on a Wednesday, so today's occurrence is still upcoming
+        let now = NaiveDateTime::parse_from_str("2023-10-11 08:00", "%Y-%m-%d %H:%M").unwrap();
+        let next = recurrence.datetime_occurrences(now).next().unwrap();
+        assert_eq!(next.to_string(), "2023-10-11 09:00:00");
+
+        // later on that Wednesday, so today's occurrence has passed
+        let now = NaiveDateTime::parse_from_str("2023-10-11 10:00", "%Y-%m-%d %H:%M").unwrap();
+        let next = recurrence.datetime_occurrences(now).next().unwrap();
+        assert_eq!(next.to_string(), "2023-10-18 09:00:00");
+    }
+}
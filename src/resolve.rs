@@ -0,0 +1,510 @@
+use crate::{
+    parser::parse_flex_datetime, FlexibleDate, FlexibleDateTime, FlexibleTime, ParseOptions,
+    Parsed, Precision,
+};
+use chrono::{Datelike, Days, Duration, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use std::{fmt, ops::Range};
+
+/// What [`parse_and_resolve`] needs to know besides the text itself.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResolveContext {
+    /// The moment that relative dates are counted from.
+    pub now: NaiveDateTime,
+    pub options: ParseOptions,
+}
+
+impl ResolveContext {
+    /// A context for resolving dates relative to `now`, with the default options.
+    #[must_use]
+    pub fn new(now: NaiveDateTime) -> Self {
+        Self {
+            now,
+            options: ParseOptions::default(),
+        }
+    }
+
+    /// A context for resolving dates relative to the current local time, with the default
+    /// options. The time is read once, so every date resolved with the context is counted from
+    /// the same moment, even in a batch that runs past midnight.
+    #[must_use]
+    pub fn now() -> Self {
+        Self::new(chrono::Local::now().naive_local())
+    }
+}
+
+/// The results of [`resolve_batch`], along with the context that every item was resolved with,
+/// so that the batch can be reproduced or audited later.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ResolvedBatch {
+    /// The context that every item was resolved with, including its `now`.
+    pub context: ResolveContext,
+
+    /// The result for each item, in the order they were given. See [`parse_and_resolve`].
+    pub results: Vec<Option<ResolvedParse>>,
+}
+
+/// Whether a [`ResolvedParse`] is a whole day or a particular time.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ResolvedKind {
+    /// A whole day, eg. "tomorrow".
+    Date,
+    /// A time on some day, eg. "tomorrow at 5".
+    DateTime,
+}
+
+/// A normalized form of a date, for telling when differently written dates mean the same thing,
+/// eg. "tom", "tomorrow", and "2023-10-09" on October 8. See [`FlexibleDate::canonical_key`] and
+/// [`FlexibleDateTime::canonical_key`].
+///
+/// Dates only match when they're as precise as each other, so "next month" and "november 1"
+/// have different keys. Dates less precise than a day are moved to the start of their period,
+/// so "2026" has the same key whether it stands for the first day of the year or the last.
+///
+/// The key's [`Display`](fmt::Display) form is stable between versions, for storing next to a
+/// task, eg. "2023-10-09", "2023-10-09T17:30", "2023-W42", "2024-Q1", or "2026".
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CanonicalKey {
+    /// The day the date lands on, or the first day of its period if it's less precise than that.
+    pub date: NaiveDate,
+
+    /// The time on that day, for [`ResolvedKind::DateTime`] keys. It's on the hour for keys to the
+    /// hour.
+    pub time: Option<NaiveTime>,
+
+    pub precision: Precision,
+    pub kind: ResolvedKind,
+}
+
+impl CanonicalKey {
+    /// The key for a whole day, or for the period around it that `precision` covers.
+    pub(crate) fn for_date(date: NaiveDate, precision: Precision) -> Self {
+        let date = match precision {
+            Precision::Year => date.with_ordinal(1),
+            Precision::Quarter => date
+                .with_day(1)
+                .and_then(|date| date.with_month0(date.month0() / 3 * 3)),
+            Precision::Month => date.with_day(1),
+            Precision::Week => Some(date - Days::new(date.weekday().num_days_from_monday().into())),
+            Precision::Day | Precision::Hour | Precision::Minute => Some(date),
+        };
+        Self {
+            // only out-of-range dates can't be moved back
+            date: date.unwrap_or(NaiveDate::MIN),
+            time: None,
+            precision: precision.min(Precision::Day),
+            kind: ResolvedKind::Date,
+        }
+    }
+
+    /// The key for a moment, to the hour or to the minute.
+    pub(crate) fn for_datetime(datetime: NaiveDateTime, precision: Precision) -> Self {
+        let precision = precision.max(Precision::Hour);
+        let time = match precision {
+            Precision::Hour => datetime.time().with_minute(0),
+            _ => Some(datetime.time()),
+        };
+        Self {
+            date: datetime.date(),
+            time: time.and_then(|time| time.with_second(0)),
+            precision,
+            kind: ResolvedKind::DateTime,
+        }
+    }
+}
+
+impl fmt::Display for CanonicalKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let date = self.date;
+        match (self.precision, self.time) {
+            (Precision::Year, _) => write!(f, "{}", date.format("%Y")),
+            (Precision::Quarter, _) => write!(f, "{}-Q{}", date.year(), date.month0() / 3 + 1),
+            (Precision::Month, _) => write!(f, "{}", date.format("%Y-%m")),
+            (Precision::Week, _) => write!(f, "{}", date.format("%G-W%V")),
+            (Precision::Hour | Precision::Minute, Some(time)) => {
+                write!(f, "{}T{}", date.format("%Y-%m-%d"), time.format("%H:%M"))
+            }
+            (Precision::Day | Precision::Hour | Precision::Minute, _) => {
+                write!(f, "{}", date.format("%Y-%m-%d"))
+            }
+        }
+    }
+}
+
+impl FlexibleDate {
+    /// A normalized form of the date, resolved relative to `context.now`, for telling when
+    /// differently written dates mean the same thing. See [`CanonicalKey`].
+    ///
+    /// ```rust
+    /// # use smart_date::{FlexibleDate, ResolveContext};
+    /// # fn main() {
+    /// let now = chrono::NaiveDateTime::parse_from_str("2023-10-08 12:00", "%Y-%m-%d %H:%M").unwrap();
+    /// let context = ResolveContext::new(now);
+    ///
+    /// let tomorrow = FlexibleDate::parse_from_str("tomorrow").unwrap();
+    /// let explicit = FlexibleDate::parse_from_str("2023-10-09").unwrap();
+    /// assert_eq!(tomorrow.canonical_key(&context), explicit.canonical_key(&context));
+    /// assert_eq!(tomorrow.canonical_key(&context).to_string(), "2023-10-09");
+    ///
+    /// let next_month = FlexibleDate::parse_from_str("next month").unwrap();
+    /// assert_eq!(next_month.canonical_key(&context).to_string(), "2023-11");
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn canonical_key(&self, context: &ResolveContext) -> CanonicalKey {
+        let date = self.clone().into_naive_date(context.now.date());
+        CanonicalKey::for_date(date, self.precision())
+    }
+
+    /// How precise the date is on its own, without the text it was parsed from. A [`Parsed`]
+    /// result knows better, since the same date can be written more or less precisely.
+    fn precision(&self) -> Precision {
+        match self {
+            FlexibleDate::NextMonth | FlexibleDate::MonthInYear { .. } => Precision::Month,
+            FlexibleDate::NextYear | FlexibleDate::StartOfYear(_) | FlexibleDate::EndOfYear(_) => {
+                Precision::Year
+            }
+            _ => Precision::Day,
+        }
+    }
+}
+
+impl FlexibleDateTime {
+    /// A normalized form of the date time, resolved relative to `context.now`, like
+    /// [`FlexibleDate::canonical_key`]. Dates that last all day have the key of their date, and
+    /// others are keyed to the minute, or to the hour when they're on the hour. Returns `None` if
+    /// the time is out of range.
+    #[must_use]
+    pub fn canonical_key(&self, context: &ResolveContext) -> Option<CanonicalKey> {
+        if self.all_day {
+            return Some(self.date.canonical_key(context));
+        }
+        let datetime = self.clone().into_naive_datetime(context.now)?;
+        let precision = if datetime.minute() == 0 {
+            Precision::Hour
+        } else {
+            Precision::Minute
+        };
+        Some(CanonicalKey::for_datetime(datetime, precision))
+    }
+}
+
+impl ResolvedParse {
+    /// A normalized form of the result, like [`FlexibleDateTime::canonical_key`], but as precise
+    /// as the text was written.
+    #[must_use]
+    pub fn canonical_key(&self) -> CanonicalKey {
+        match self.resolved_datetime {
+            Some(datetime) => CanonicalKey::for_datetime(datetime, self.precision),
+            None => CanonicalKey::for_date(self.resolved_date, self.precision),
+        }
+    }
+}
+
+/// Everything about the first date in some text, both as it was written and as an actual date.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools)] // the flags are independent of each other
+pub struct ResolvedParse {
+    /// The date as it was written, eg. [`FlexibleDate::Tomorrow`].
+    pub date: FlexibleDate,
+
+    /// The time of day as it was written, for [`ResolvedKind::DateTime`] results.
+    pub time: Option<FlexibleTime>,
+
+    /// The day that the date lands on.
+    pub resolved_date: NaiveDate,
+
+    /// The moment that the date and time land on, for [`ResolvedKind::DateTime`] results.
+    pub resolved_datetime: Option<NaiveDateTime>,
+
+    pub kind: ResolvedKind,
+
+    /// Where the date is in the text.
+    pub range: Range<usize>,
+
+    /// See [`Parsed::approximate`].
+    pub approximate: bool,
+
+    /// See [`Parsed::inferred`].
+    pub inferred: bool,
+
+    /// See [`Parsed::tolerance`].
+    pub tolerance: Option<Duration>,
+
+    /// See [`Parsed::ambiguous`].
+    pub ambiguous: bool,
+
+    /// See [`Parsed::confidence`].
+    pub confidence: u8,
+
+    /// See [`Parsed::precision`].
+    pub precision: Precision,
+
+    /// Whether the result is before `now`, eg. "monday" on a Wednesday with
+    /// [`WeekdayScope::CurrentWeek`](crate::WeekdayScope::CurrentWeek). For whole days, it's
+    /// whether the day is before today.
+    pub in_past: bool,
+}
+
+/// Finds the first date, with or without a time, within a string and resolves it relative to
+/// `context.now`. Returns `None` if there's no date in the string, or if its date or time is out
+/// of range, eg. "in 4000000000 days".
+///
+/// ```rust
+/// # use smart_date::{parse_and_resolve, FlexibleDate, ResolveContext, ResolvedKind};
+/// # fn main() {
+/// let now = chrono::NaiveDateTime::parse_from_str("2023-10-08 12:00", "%Y-%m-%d %H:%M").unwrap();
+/// let context = ResolveContext::new(now);
+///
+/// let result = parse_and_resolve("call mom tomorrow at 5", &context).unwrap();
+/// assert_eq!(result.date, FlexibleDate::Tomorrow);
+/// assert_eq!(result.kind, ResolvedKind::DateTime);
+/// assert_eq!(result.resolved_datetime.unwrap().to_string(), "2023-10-09 17:00:00");
+/// assert_eq!(result.range, (9..22));
+///
+/// let result = parse_and_resolve("water plants friday", &context).unwrap();
+/// assert_eq!(result.kind, ResolvedKind::Date);
+/// assert_eq!(result.resolved_date.to_string(), "2023-10-13");
+/// # }
+/// ```
+#[must_use]
+pub fn parse_and_resolve(text: &str, context: &ResolveContext) -> Option<ResolvedParse> {
+    let Parsed {
+        data,
+        range,
+        approximate,
+        inferred,
+        tolerance,
+        ambiguous,
+        confidence,
+        precision,
+        ..
+    } = parse_flex_datetime(text, &context.options)?;
+
+    let resolved_date = data.resolve_date(context.now)?;
+    let (kind, time, resolved_datetime) = if data.all_day {
+        (ResolvedKind::Date, None, None)
+    } else {
+        let resolved_datetime = data.clone().into_naive_datetime(context.now)?;
+        (
+            ResolvedKind::DateTime,
+            Some(data.time),
+            Some(resolved_datetime),
+        )
+    };
+    let in_past = match resolved_datetime {
+        Some(resolved_datetime) => resolved_datetime < context.now,
+        None => resolved_date < context.now.date(),
+    };
+
+    Some(ResolvedParse {
+        date: data.date,
+        time,
+        resolved_date,
+        resolved_datetime,
+        kind,
+        range,
+        approximate,
+        inferred,
+        tolerance,
+        ambiguous,
+        confidence,
+        precision,
+        in_past,
+    })
+}
+
+/// Resolves the first date in each of `texts` like [`parse_and_resolve`], all against the one
+/// `context`, so that a large import doesn't resolve some items against a different today than
+/// others.
+///
+/// ```rust
+/// # use smart_date::{resolve_batch, ResolveContext};
+/// # fn main() {
+/// let now = chrono::NaiveDateTime::parse_from_str("2023-10-08 23:59", "%Y-%m-%d %H:%M").unwrap();
+///
+/// let texts = ["pay rent tomorrow", "no date here", "call mom tomorrow"];
+/// let batch = resolve_batch(texts, ResolveContext::new(now));
+/// assert_eq!(batch.context.now, now);
+///
+/// let first = batch.results[0].as_ref().unwrap();
+/// let last = batch.results[2].as_ref().unwrap();
+/// assert_eq!(first.resolved_date, last.resolved_date);
+/// assert_eq!(batch.results[1], None);
+/// # }
+/// ```
+#[must_use]
+pub fn resolve_batch<I>(texts: I, context: ResolveContext) -> ResolvedBatch
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    let results = texts
+        .into_iter()
+        .map(|text| parse_and_resolve(text.as_ref(), &context))
+        .collect();
+    ResolvedBatch { context, results }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::WeekdayScope;
+
+    #[test]
+    fn test_parse_and_resolve_flags() {
+        let now = NaiveDateTime::parse_from_str("2023-10-08 12:00", "%Y-%m-%d %H:%M").unwrap();
+        let context = ResolveContext::new(now);
+
+        let result = parse_and_resolve("sat around 3", &context).unwrap();
+        assert_eq!(result.resolved_date.to_string(), "2023-10-14");
+        assert_eq!(
+            result.time,
+            Some(FlexibleTime {
+                hour: 15,
+                minute: 0
+            })
+        );
+        assert!(result.approximate);
+        assert!(result.inferred);
+        assert!(result.ambiguous);
+        assert_eq!(result.tolerance, Some(Duration::minutes(30)));
+
+        assert_eq!(parse_and_resolve("no dates here", &context), None);
+    }
+
+    #[test]
+    fn test_parse_and_resolve_out_of_range() {
+        let now = NaiveDateTime::parse_from_str("2023-10-08 12:00", "%Y-%m-%d %H:%M").unwrap();
+        let context = ResolveContext::new(now);
+
+        assert_eq!(parse_and_resolve("in 4000000000 days", &context), None);
+        assert_eq!(parse_and_resolve("in 4000000000 days at 5", &context), None);
+    }
+
+    #[test]
+    fn test_in_past() {
+        // a wednesday
+        let now = NaiveDateTime::parse_from_str("2023-10-11 12:00", "%Y-%m-%d %H:%M").unwrap();
+        let mut context = ResolveContext::new(now);
+        assert!(!parse_and_resolve("monday", &context).unwrap().in_past);
+        assert!(!parse_and_resolve("today", &context).unwrap().in_past);
+
+        context.options.weekday_scope = WeekdayScope::CurrentWeek;
+        let result = parse_and_resolve("monday", &context).unwrap();
+        assert_eq!(result.resolved_date.to_string(), "2023-10-09");
+        assert!(result.in_past);
+        assert!(!parse_and_resolve("friday", &context).unwrap().in_past);
+
+        // times earlier today have passed too
+        assert!(
+            parse_and_resolve("wednesday at 9am", &context)
+                .unwrap()
+                .in_past
+        );
+        assert!(
+            !parse_and_resolve("wednesday at 5pm", &context)
+                .unwrap()
+                .in_past
+        );
+    }
+
+    #[test]
+    fn test_resolve_time_only() {
+        let now = NaiveDateTime::parse_from_str("2023-10-08 12:00", "%Y-%m-%d %H:%M").unwrap();
+        let context = ResolveContext::new(now);
+
+        let result = parse_and_resolve("call mom at 5", &context).unwrap();
+        assert_eq!(
+            result.resolved_datetime.unwrap().to_string(),
+            "2023-10-08 17:00:00"
+        );
+
+        let result = parse_and_resolve("standup at 9am", &context).unwrap();
+        assert_eq!(result.resolved_date.to_string(), "2023-10-09");
+        assert_eq!(
+            result.resolved_datetime.unwrap().to_string(),
+            "2023-10-09 09:00:00"
+        );
+        assert!(!result.in_past);
+    }
+
+    #[test]
+    fn test_resolve_batch() {
+        let now = NaiveDateTime::parse_from_str("2023-10-08 23:59", "%Y-%m-%d %H:%M").unwrap();
+        let texts = vec!["tomorrow".to_string(); 1000];
+        let batch = resolve_batch(&texts, ResolveContext::new(now));
+        assert_eq!(batch.context.now, now);
+        assert_eq!(batch.results.len(), 1000);
+        assert!(batch.results.iter().all(|result| result
+            .as_ref()
+            .unwrap()
+            .resolved_date
+            .to_string()
+            == "2023-10-09"));
+    }
+
+    #[test]
+    fn test_canonical_key() {
+        let now = NaiveDateTime::parse_from_str("2023-10-08 12:00", "%Y-%m-%d %H:%M").unwrap();
+        let context = ResolveContext::new(now);
+        let key = |text| {
+            FlexibleDate::parse_from_str(text)
+                .unwrap()
+                .canonical_key(&context)
+        };
+
+        assert_eq!(key("tom"), key("tomorrow"));
+        assert_eq!(key("tomorrow"), key("2023-10-09"));
+        assert_eq!(key("tomorrow"), key("monday"));
+        assert_ne!(key("tomorrow"), key("tuesday"));
+
+        // a whole month isn't the same as its first day
+        assert_eq!(key("next month").date, key("the 1st of next month").date);
+        assert_ne!(key("next month"), key("the 1st of next month"));
+        assert_eq!(key("next month").to_string(), "2023-11");
+        assert_eq!(key("next year").to_string(), "2024");
+
+        let key = |text| {
+            FlexibleDateTime::parse_from_str(text)
+                .unwrap()
+                .canonical_key(&context)
+                .unwrap()
+        };
+        assert_eq!(key("tomorrow at 5pm"), key("tom at 17:00"));
+        assert_eq!(key("tomorrow at 5pm").to_string(), "2023-10-09T17:00");
+        assert_eq!(key("tomorrow at 5:30pm").precision, Precision::Minute);
+        assert_eq!(key("tomorrow").kind, ResolvedKind::Date);
+        assert_ne!(key("tomorrow"), key("tomorrow at 5pm"));
+    }
+
+    #[test]
+    fn test_canonical_key_display() {
+        let date = NaiveDate::from_ymd_opt(2023, 10, 18).unwrap();
+        let key = |precision| CanonicalKey::for_date(date, precision).to_string();
+        assert_eq!(key(Precision::Year), "2023");
+        assert_eq!(key(Precision::Quarter), "2023-Q4");
+        assert_eq!(key(Precision::Month), "2023-10");
+        assert_eq!(key(Precision::Week), "2023-W42");
+        assert_eq!(key(Precision::Day), "2023-10-18");
+        // whole days don't have a time to be precise to
+        assert_eq!(key(Precision::Minute), "2023-10-18");
+
+        // keys less precise than a day start at the start of their period
+        let week = CanonicalKey::for_date(date, Precision::Week);
+        assert_eq!(week.date, NaiveDate::from_ymd_opt(2023, 10, 16).unwrap());
+        let quarter = CanonicalKey::for_date(date, Precision::Quarter);
+        assert_eq!(quarter.date, NaiveDate::from_ymd_opt(2023, 10, 1).unwrap());
+    }
+
+    #[test]
+    fn test_resolved_canonical_key() {
+        let now = NaiveDateTime::parse_from_str("2023-10-08 12:00", "%Y-%m-%d %H:%M").unwrap();
+        let context = ResolveContext::new(now);
+        let key = |text| parse_and_resolve(text, &context).unwrap().canonical_key();
+
+        assert_eq!(key("call mom tom"), key("call mom on 2023-10-09"));
+        assert_eq!(key("dentist tomorrow at 9").to_string(), "2023-10-09T09:00");
+    }
+}
@@ -0,0 +1,224 @@
+/// One of the grammar rules that dates and times are recognized by, which can be turned off with
+/// [`ParseOptions::disabled_rules`] to avoid its false positives.
+///
+/// Each rule has an identifier that won't change between versions, so that rules can be
+/// listed in configuration files.
+///
+/// ```rust
+/// # use smart_date::{FlexibleDate, ParseOptions, Rule};
+/// # fn main() {
+/// let options = ParseOptions {
+///     disabled_rules: vec![Rule::from_id("bare_ordinal").unwrap()],
+///     ..ParseOptions::default()
+/// };
+///
+/// let result = FlexibleDate::find_and_parse_in_str_with_options("my 2nd try", &options);
+/// assert_eq!(result, None);
+/// # }
+/// ```
+///
+/// [`ParseOptions::disabled_rules`]: crate::ParseOptions::disabled_rules
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Rule {
+    /// "around the 15th", "about a week from now"
+    ApproximateDate,
+    /// "2024-01-27"
+    IsoDate,
+    /// "27.09.2024"
+    DottedDate,
+    /// "01/27", "01/27/2024"
+    SlashDate,
+    /// "in 5 business days", "in 5 days skipping weekends"
+    BusinessDaysFromNow,
+    /// "in 3 days", "2 weeks from now", "2d"
+    OffsetFromNow,
+    /// "this weekend", "next weekend"
+    Weekend,
+    /// "later this week"
+    LaterThisWeek,
+    /// "end of the week", "end of week"
+    EndOfWeek,
+    /// "this week", with some [`ThisWeekPolicy`](crate::ThisWeekPolicy) values
+    ThisWeek,
+    /// "the last business day of the month"
+    LastBusinessDay,
+    /// "early next month", "mid next week"
+    PeriodSegment,
+    /// "the end of the year", "the beginning of next month"
+    PeriodSection,
+    /// "halfway through q2", "the middle of next week"
+    Midpoint,
+    /// "the week after next"
+    PeriodAfterNext,
+    /// "3 weeks from friday", "two weeks from tomorrow", "3 days after jan 5"; named for the
+    /// first kind of date it counted from
+    OffsetFromWeekday,
+    /// "2 weeks into next quarter", "3 days into next month"
+    OffsetIntoPeriod,
+    /// "the monday of week 42", "mon w42"
+    WeekdayOfWeek,
+    /// "the friday after this one"
+    SkippedWeekday,
+    /// "next friday"
+    NextWeekday,
+    /// "this saturday"
+    ThisWeekday,
+    /// A weekday on its own, eg. "friday" or "sat"
+    Weekday,
+    /// "january 27", "jan 27", "the 27th of january"
+    MonthDay,
+    /// "the 15th of next month"
+    DayInMonth,
+    /// "today", "tod", and parts of today like "tonight"
+    Today,
+    /// "tomorrow", "tmrw"
+    Tomorrow,
+    /// "next month", "next year"
+    NextMonthOrYear,
+    /// "march next year", "next year in march"
+    MonthInYear,
+    /// A day of the month, eg. "the 5th"
+    DayOfMonth,
+    /// A day of the month without "the", eg. "27th"
+    BareOrdinal,
+    /// A year on its own, eg. "in 2026", or "2026" when that's the whole text
+    Year,
+    /// An hour without AM or PM, eg. the "5" in "tomorrow at 5"
+    BareHour,
+}
+
+impl Rule {
+    /// Every rule, in the order they're tried.
+    pub const ALL: [Rule; 32] = [
+        Rule::ApproximateDate,
+        Rule::IsoDate,
+        Rule::DottedDate,
+        Rule::SlashDate,
+        Rule::BusinessDaysFromNow,
+        Rule::OffsetFromNow,
+        Rule::Weekend,
+        Rule::ThisWeek,
+        Rule::LastBusinessDay,
+        Rule::PeriodSegment,
+        Rule::PeriodSection,
+        Rule::Midpoint,
+        Rule::PeriodAfterNext,
+        Rule::OffsetFromWeekday,
+        Rule::OffsetIntoPeriod,
+        Rule::WeekdayOfWeek,
+        Rule::SkippedWeekday,
+        Rule::NextWeekday,
+        Rule::Weekday,
+        Rule::MonthDay,
+        Rule::DayInMonth,
+        Rule::ThisWeekday,
+        Rule::LaterThisWeek,
+        Rule::EndOfWeek,
+        Rule::Today,
+        Rule::Tomorrow,
+        Rule::MonthInYear,
+        Rule::NextMonthOrYear,
+        Rule::DayOfMonth,
+        Rule::BareOrdinal,
+        Rule::Year,
+        Rule::BareHour,
+    ];
+
+    /// The rule's stable identifier, eg. `"day_of_month"`.
+    #[must_use]
+    pub fn id(self) -> &'static str {
+        match self {
+            Rule::ApproximateDate => "approximate_date",
+            Rule::IsoDate => "iso_date",
+            Rule::DottedDate => "dotted_date",
+            Rule::SlashDate => "slash_date",
+            Rule::BusinessDaysFromNow => "business_days_from_now",
+            Rule::OffsetFromNow => "offset_from_now",
+            Rule::Weekend => "weekend",
+            Rule::LaterThisWeek => "later_this_week",
+            Rule::EndOfWeek => "end_of_week",
+            Rule::ThisWeek => "this_week",
+            Rule::LastBusinessDay => "last_business_day",
+            Rule::PeriodSegment => "period_segment",
+            Rule::PeriodSection => "period_section",
+            Rule::Midpoint => "midpoint",
+            Rule::PeriodAfterNext => "period_after_next",
+            Rule::OffsetFromWeekday => "offset_from_weekday",
+            Rule::OffsetIntoPeriod => "offset_into_period",
+            Rule::WeekdayOfWeek => "weekday_of_week",
+            Rule::SkippedWeekday => "skipped_weekday",
+            Rule::NextWeekday => "next_weekday",
+            Rule::ThisWeekday => "this_weekday",
+            Rule::Weekday => "weekday",
+            Rule::MonthDay => "month_day",
+            Rule::DayInMonth => "day_in_month",
+            Rule::Today => "today",
+            Rule::Tomorrow => "tomorrow",
+            Rule::MonthInYear => "month_in_year",
+            Rule::NextMonthOrYear => "next_month_or_year",
+            Rule::DayOfMonth => "day_of_month",
+            Rule::BareOrdinal => "bare_ordinal",
+            Rule::Year => "year",
+            Rule::BareHour => "bare_hour",
+        }
+    }
+
+    /// How much a match of this rule on its own suggests a date, from 0 to 100. Distinctive
+    /// phrases like "2024-01-27" score higher than ones that often mean something else, like the
+    /// "5th" in "the 5th chapter". See [`Parsed::confidence`](crate::Parsed::confidence).
+    #[must_use]
+    pub fn specificity(self) -> u8 {
+        match self {
+            Rule::IsoDate => 100,
+            Rule::DottedDate
+            | Rule::BusinessDaysFromNow
+            | Rule::LastBusinessDay
+            | Rule::WeekdayOfWeek
+            | Rule::Today
+            | Rule::Tomorrow => 95,
+            Rule::ApproximateDate
+            | Rule::OffsetFromNow
+            | Rule::Weekend
+            | Rule::EndOfWeek
+            | Rule::PeriodSection
+            | Rule::Midpoint
+            | Rule::PeriodAfterNext
+            | Rule::OffsetFromWeekday
+            | Rule::OffsetIntoPeriod
+            | Rule::SkippedWeekday
+            | Rule::NextWeekday
+            | Rule::ThisWeekday
+            | Rule::SlashDate
+            | Rule::MonthDay
+            | Rule::DayInMonth
+            | Rule::MonthInYear => 90,
+            Rule::LaterThisWeek | Rule::ThisWeek | Rule::PeriodSegment | Rule::NextMonthOrYear => {
+                85
+            }
+            Rule::Weekday => 80,
+            Rule::BareHour => 75,
+            Rule::DayOfMonth => 70,
+            Rule::Year => 65,
+            Rule::BareOrdinal => 60,
+        }
+    }
+
+    /// The rule with the given identifier, if there is one.
+    #[must_use]
+    pub fn from_id(id: &str) -> Option<Rule> {
+        Rule::ALL.into_iter().find(|rule| rule.id() == id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ids_are_unique() {
+        for rule in Rule::ALL {
+            assert_eq!(Rule::from_id(rule.id()), Some(rule));
+        }
+        assert_eq!(Rule::from_id("dotted"), None);
+    }
+}
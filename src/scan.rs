@@ -0,0 +1,65 @@
+use crate::{FlexibleDate, Month, Parsed};
+use std::{fmt, ops::Range};
+
+/// Everything found by scanning a whole document for dates: the dates themselves, and the
+/// phrases that look like dates but can't be, like "feb 30". See [`FlexibleDate::scan_str`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Scan {
+    /// The dates in the text, in the order they appear.
+    pub dates: Vec<Parsed<FlexibleDate>>,
+
+    /// The phrases that were written like dates but don't name a real day, in the order they
+    /// appear.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// A phrase that's written like a date but doesn't name a real day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Where the phrase is in the text.
+    pub range: Range<usize>,
+    pub problem: DateProblem,
+}
+
+/// Why a [`Diagnostic`]'s phrase isn't a date.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DateProblem {
+    /// The month doesn't have that day, eg. "feb 30", or doesn't have it in the given year, eg.
+    /// "2023-02-29".
+    NoSuchDay {
+        year: Option<i32>,
+        month: Month,
+        day: u32,
+    },
+
+    /// There's no month with that number, eg. the 13 in "2024-13-01".
+    NoSuchMonth(u32),
+}
+
+impl fmt::Display for DateProblem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateProblem::NoSuchDay {
+                year: Some(year),
+                month,
+                day,
+            } => write!(f, "{month:?} {year} has no day {day}"),
+            DateProblem::NoSuchDay {
+                year: None,
+                month,
+                day,
+            } => write!(f, "{month:?} has no day {day}"),
+            DateProblem::NoSuchMonth(month) => write!(f, "there's no month {month}"),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the date at {:?} isn't real: {}",
+            self.range, self.problem
+        )
+    }
+}
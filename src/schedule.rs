@@ -0,0 +1,154 @@
+use crate::{
+    parser::{parse_schedule, parse_schedule_exact},
+    ParseOptions, Parsed, Recurrence,
+};
+use chrono::NaiveDate;
+use std::iter::Peekable;
+
+/// Several recurrences written together, eg. "every monday at 9 and every friday at 5", kept
+/// apart so that each one's time and end stay with it. A time or end written after the last
+/// recurrence applies to the ones before it that don't have their own.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Schedule {
+    /// The recurrences, in the order they were written.
+    pub recurrences: Vec<Recurrence>,
+}
+
+impl Schedule {
+    /// Parses a `Schedule` from a string. Fails (returns `None`) if the full string does not
+    /// match one or more recurrences.
+    ///
+    /// ```rust
+    /// # use smart_date::{Frequency, Schedule, Weekday};
+    /// # fn main() {
+    /// let result = Schedule::parse_from_str("every monday and every thursday at 9").unwrap();
+    /// assert_eq!(result.recurrences.len(), 2);
+    /// assert_eq!(
+    ///     result.recurrences[0].frequency,
+    ///     Frequency::Weekly(Some(Weekday::Monday))
+    /// );
+    /// // the time applies to both
+    /// assert_eq!(result.recurrences[0].time, result.recurrences[1].time);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn parse_from_str(text: &str) -> Option<Schedule> {
+        Self::parse_from_str_with_options(text, &ParseOptions::default())
+    }
+
+    /// Same as [`Schedule::parse_from_str`], but with control over how the text is parsed.
+    #[must_use]
+    pub fn parse_from_str_with_options(text: &str, options: &ParseOptions) -> Option<Schedule> {
+        parse_schedule_exact(text, options)
+            .ok()
+            .and_then(|(rest, schedule)| rest.is_empty().then_some(schedule.data))
+    }
+
+    /// Finds and parses a `Schedule` from within a string.
+    ///
+    /// ```rust
+    /// # use smart_date::Schedule;
+    /// # fn main() {
+    /// let result = Schedule::find_and_parse_in_str("gym every monday at 7, every friday at 6").unwrap();
+    /// assert_eq!(result.data.recurrences.len(), 2);
+    /// assert_eq!(result.range, (4..40));
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn find_and_parse_in_str(text: &str) -> Option<Parsed<Schedule>> {
+        Self::find_and_parse_in_str_with_options(text, &ParseOptions::default())
+    }
+
+    /// Same as [`Schedule::find_and_parse_in_str`], but with control over how the text is
+    /// parsed.
+    #[must_use]
+    pub fn find_and_parse_in_str_with_options(
+        text: &str,
+        options: &ParseOptions,
+    ) -> Option<Parsed<Schedule>> {
+        parse_schedule(text, options)
+    }
+
+    /// The dates that any of the recurrences happen on, in order, like
+    /// [`Recurrence::occurrences`]. A date that several recurrences share only comes up once.
+    ///
+    /// ```rust
+    /// # use smart_date::Schedule;
+    /// # fn main() {
+    /// // a Sunday
+    /// let today = chrono::NaiveDate::parse_from_str("2023-10-08", "%Y-%m-%d").unwrap();
+    ///
+    /// let schedule = Schedule::parse_from_str("every monday and every wednesday").unwrap();
+    /// let dates: Vec<_> = schedule.occurrences(today).take(3).map(|d| d.to_string()).collect();
+    /// assert_eq!(dates, vec!["2023-10-09", "2023-10-11", "2023-10-16"]);
+    /// # }
+    /// ```
+    pub fn occurrences(&self, today: NaiveDate) -> impl Iterator<Item = NaiveDate> + '_ {
+        let mut occurrences: Vec<Peekable<_>> = self
+            .recurrences
+            .iter()
+            .map(|recurrence| recurrence.occurrences(today).peekable())
+            .collect();
+        std::iter::from_fn(move || {
+            let next = occurrences
+                .iter_mut()
+                .filter_map(|dates| dates.peek().copied())
+                .min()?;
+            for dates in &mut occurrences {
+                dates.next_if_eq(&next);
+            }
+            Some(next)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::{FlexibleTime, RecurrenceEnd};
+
+    #[test]
+    fn test_shared_parts() {
+        // each recurrence keeps its own time
+        let schedule =
+            Schedule::parse_from_str("every monday at 8 and every friday at 5pm").unwrap();
+        assert_eq!(
+            schedule.recurrences[0].time,
+            Some(FlexibleTime { hour: 8, minute: 0 })
+        );
+        assert_eq!(
+            schedule.recurrences[1].time,
+            Some(FlexibleTime {
+                hour: 17,
+                minute: 0
+            })
+        );
+
+        // but the last one's end applies to the others
+        let schedule = Schedule::parse_from_str("every monday, every friday until dec 31").unwrap();
+        assert!(matches!(
+            schedule.recurrences[0].end,
+            Some(RecurrenceEnd::Until(_))
+        ));
+
+        // a single recurrence is a schedule too
+        let schedule = Schedule::parse_from_str("every friday until dec 31 at 5pm").unwrap();
+        assert_eq!(schedule.recurrences.len(), 1);
+
+        assert_eq!(Schedule::parse_from_str("every monday and tuesday"), None);
+    }
+
+    #[test]
+    fn test_occurrences_end() {
+        // a Sunday
+        let today = NaiveDate::parse_from_str("2023-10-08", "%Y-%m-%d").unwrap();
+
+        // the merged dates run out with the last recurrence
+        let schedule =
+            Schedule::parse_from_str("every day for 2 days and every friday until oct 13").unwrap();
+        let dates: Vec<_> = schedule.occurrences(today).map(|d| d.to_string()).collect();
+        assert_eq!(dates, vec!["2023-10-08", "2023-10-09", "2023-10-13"]);
+    }
+}
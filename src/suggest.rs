@@ -0,0 +1,297 @@
+use crate::{
+    parser::{date_words, parse_all_flex_dates},
+    FlexibleDate, ParseOptions, Parsed,
+};
+use std::{fmt, ops::Range};
+
+/// A word that's probably a date word written wrong, like the "tomorow" in "call mom tomorow", or
+/// only partly typed, like the "tom" in "call mom tom", along with what it was likely meant to
+/// be. See [`FlexibleDate::suggest_in_str`] and [`FlexibleDate::completions`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Suggestion {
+    /// Where the word is in the text.
+    pub range: Range<usize>,
+
+    /// The word as it was written, eg. "tomorow".
+    pub word: String,
+
+    /// The date word it's probably meant to be, eg. "tomorrow".
+    pub replacement: String,
+
+    /// The date that the text has once the word is replaced. Its range is in the corrected
+    /// text, not the original.
+    pub date: Parsed<FlexibleDate>,
+}
+
+impl fmt::Display for Suggestion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "did you mean \"{}\" instead of \"{}\"?",
+            self.replacement, self.word
+        )
+    }
+}
+
+/// Finds the word in `text` that's closest to being a date word, among the ones that would make
+/// a date if they were replaced with it. Words that are already part of a date are left alone.
+pub(crate) fn suggest_date_word(text: &str, options: &ParseOptions) -> Option<Suggestion> {
+    let dates = parse_all_flex_dates(text, options);
+    let mut candidates: Vec<(usize, Range<usize>, &str)> = words(text)
+        .filter(|range| {
+            !dates
+                .iter()
+                .any(|date| date.range.start < range.end && range.start < date.range.end)
+        })
+        .filter_map(|range| {
+            let word = text[range.clone()].to_lowercase();
+            date_words()
+                .filter_map(|candidate| Some((closeness(&word, candidate)?, candidate)))
+                .min_by_key(|(distance, _)| *distance)
+                .map(|(distance, candidate)| (distance, range, candidate))
+        })
+        .collect();
+    // the closest words first, and then the earliest
+    candidates.sort_by_key(|(distance, range, _)| (*distance, range.start));
+
+    candidates.into_iter().find_map(|(_, range, replacement)| {
+        let corrected = format!(
+            "{}{replacement}{}",
+            &text[..range.start],
+            &text[range.end..]
+        );
+        let replaced = range.start..range.start + replacement.len();
+        let date = parse_all_flex_dates(&corrected, options)
+            .into_iter()
+            .find(|date| date.range.start <= replaced.start && replaced.end <= date.range.end)?;
+        Some(Suggestion {
+            word: text[range.clone()].to_string(),
+            range,
+            replacement: replacement.to_string(),
+            date,
+        })
+    })
+}
+
+/// The date words that the partly typed word at the end of `text` could become, along with the
+/// dates they'd finish, in the order they're most likely meant. Completions of the same date are
+/// left out in favor of the word written out in full, like "thursday" over "thurs".
+pub(crate) fn complete_date_word(text: &str, options: &ParseOptions) -> Vec<Suggestion> {
+    let Some(range) = words(text).last().filter(|range| range.end == text.len()) else {
+        return Vec::new();
+    };
+    let partial = text[range.clone()].to_lowercase();
+    let mut completions: Vec<Suggestion> = date_words()
+        .filter(|candidate| candidate.len() > partial.len() && candidate.starts_with(&partial))
+        .filter_map(|replacement| {
+            let completed = format!("{}{replacement}", &text[..range.start]);
+            let date = parse_all_flex_dates(&completed, options)
+                .into_iter()
+                .find(|date| {
+                    date.range.start <= range.start && date.range.end == completed.len()
+                })?;
+            Some(Suggestion {
+                range: range.clone(),
+                word: text[range.clone()].to_string(),
+                replacement: replacement.to_string(),
+                date,
+            })
+        })
+        .collect();
+
+    completions.sort_by_key(|completion| std::cmp::Reverse(completion.replacement.len()));
+    let mut completed_dates = Vec::new();
+    completions.retain(|completion| {
+        let new = !completed_dates.contains(&completion.date.data);
+        completed_dates.push(completion.date.data.clone());
+        new
+    });
+    // the surest dates first, and then the shortest words
+    completions.sort_by(|a, b| {
+        (b.date.confidence, a.replacement.len(), &a.replacement).cmp(&(
+            a.date.confidence,
+            b.replacement.len(),
+            &b.replacement,
+        ))
+    });
+    completions
+}
+
+/// Replaces each word in `text` that looks like a misspelled date word, like "tommorow", with the
+/// date word it's closest to. Gives the corrected text, along with where each of its bytes (and
+/// its end) came from in `text`. The bytes of a replacement all come from the start of the word.
+pub(crate) fn correct_typos(text: &str) -> (String, Vec<usize>) {
+    let mut corrected = String::with_capacity(text.len());
+    let mut offsets = Vec::with_capacity(text.len() + 1);
+    let mut copied = 0;
+    for range in words(text) {
+        let Some(replacement) = correction(&text[range.clone()]) else {
+            continue;
+        };
+        corrected.push_str(&text[copied..range.start]);
+        offsets.extend(copied..range.start);
+        corrected.push_str(replacement);
+        offsets.resize(corrected.len(), range.start);
+        copied = range.end;
+    }
+    corrected.push_str(&text[copied..]);
+    offsets.extend(copied..=text.len());
+    (corrected, offsets)
+}
+
+/// The date word that `word` is most likely a misspelling of. This is stricter than the
+/// suggestions, since the correction is read as a date without asking: words need five letters,
+/// and only long ones can be two changes away. Cut-off words aren't completed.
+fn correction(word: &str) -> Option<&'static str> {
+    let length = word.chars().count();
+    if length < 5 || date_words().any(|candidate| candidate == word) {
+        return None;
+    }
+    let limit = if length < 7 { 1 } else { 2 };
+    date_words()
+        .map(|candidate| (edit_distance(word, candidate), candidate))
+        .filter(|(distance, _)| *distance <= limit)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// The ranges of the words in `text` made of letters, ignoring punctuation around them.
+fn words(text: &str) -> impl Iterator<Item = Range<usize>> + '_ {
+    text.split(|c: char| !c.is_alphabetic())
+        .filter(|word| !word.is_empty())
+        .map(move |word| {
+            let start = word.as_ptr() as usize - text.as_ptr() as usize;
+            start..start + word.len()
+        })
+}
+
+/// How far `word` is from `candidate`, if it's close enough to be a slip. Short words can only be
+/// one change away, and longer ones two. A word that `candidate` starts with, like "tomor",
+/// counts as one change, since it's only been cut off.
+fn closeness(word: &str, candidate: &str) -> Option<usize> {
+    let length = word.chars().count();
+    if length < 3 || word == candidate {
+        return None;
+    }
+    if length >= 4 && candidate.starts_with(word) {
+        return Some(1);
+    }
+    let limit = if length <= 4 { 1 } else { 2 };
+    Some(edit_distance(word, candidate)).filter(|distance| *distance <= limit)
+}
+
+/// The number of letters that have to be added, removed, changed, or swapped with their neighbor
+/// to turn `a` into `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+    // distances[i][j] is the distance between the first i letters of a and the first j of b
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, distance) in distances[0].iter_mut().enumerate() {
+        *distance = j;
+    }
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let change = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + change);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(distances[i - 2][j - 2] + 1);
+            }
+            distances[i][j] = distance;
+        }
+    }
+    distances[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+    use crate::Weekday;
+
+    #[test]
+    fn test_edit_distance() {
+        assert_eq!(edit_distance("tomorow", "tomorrow"), 1);
+        assert_eq!(edit_distance("fridya", "friday"), 1);
+        assert_eq!(edit_distance("satruday", "saturday"), 1);
+        assert_eq!(edit_distance("febuary", "february"), 1);
+        assert_eq!(edit_distance("week", "week"), 0);
+        assert_eq!(edit_distance("abc", "xyz"), 3);
+    }
+
+    #[test]
+    fn test_complete_date_word() {
+        let options = ParseOptions::default();
+        let replacements = |text| -> Vec<String> {
+            complete_date_word(text, &options)
+                .into_iter()
+                .map(|completion| completion.replacement)
+                .collect()
+        };
+
+        assert_eq!(replacements("call mom to"), ["today", "tomorrow"]);
+        assert_eq!(replacements("lunch next Fr"), ["friday"]);
+        // "thurs" would be the same day as "thursday"
+        assert_eq!(replacements("due thu"), ["thursday"]);
+
+        let completion = complete_date_word("lunch next Fr", &options).remove(0);
+        assert_eq!(completion.range, (11..13));
+        assert_eq!(completion.word, "Fr");
+        assert_eq!(completion.date.range, (6..17));
+
+        // only the word at the very end is completed, and it has to finish a date
+        assert!(replacements("call mom to ").is_empty());
+        assert!(replacements("go to the store").is_empty());
+        assert!(replacements("").is_empty());
+    }
+
+    #[test]
+    fn test_correct_typos() {
+        let (corrected, offsets) = correct_typos("tommorow or wensday, not tuesday");
+        assert_eq!(corrected, "tomorrow or wednesday, not tuesday");
+        assert_eq!(offsets.len(), corrected.len() + 1);
+        // "or" lines up again after the corrections
+        assert_eq!(offsets[9], 9);
+        assert_eq!(offsets[12], 12);
+        assert_eq!(offsets[14], 12);
+        assert_eq!(offsets[corrected.len()], 32);
+
+        // short words and words that are close to nothing are left alone
+        assert_eq!(correct_typos("wek, a money thing").0, "wek, a money thing");
+    }
+
+    #[test]
+    fn test_suggest_date_word() {
+        let options = ParseOptions::default();
+
+        let suggestion = suggest_date_word("call mom tomorow", &options).unwrap();
+        assert_eq!(suggestion.range, (9..16));
+        assert_eq!(suggestion.replacement, "tomorrow");
+        assert_eq!(suggestion.date.data, FlexibleDate::Tomorrow);
+
+        // cut-off words are completed, and capitals are kept in the word
+        let suggestion = suggest_date_word("See you Wednes", &options).unwrap();
+        assert_eq!(suggestion.word, "Wednes");
+        assert_eq!(suggestion.replacement, "wednesday");
+        assert_eq!(
+            suggestion.date.data,
+            FlexibleDate::Weekday(Weekday::Wednesday)
+        );
+
+        let suggestion = suggest_date_word("due next mnoth", &options).unwrap();
+        assert_eq!(suggestion.replacement, "month");
+        assert_eq!(suggestion.date.data, FlexibleDate::NextMonth);
+        assert_eq!(suggestion.date.range, (4..14));
+
+        // words that would still not make a date aren't suggestions
+        assert_eq!(suggest_date_word("a weak link", &options), None);
+        assert_eq!(suggest_date_word("call mom tomorrow", &options), None);
+        assert_eq!(suggest_date_word("", &options), None);
+    }
+}
@@ -0,0 +1,288 @@
+use crate::{
+    parser::{
+        parse_flex_datetime, parse_flex_datetime_exact, parse_standalone_time,
+        parse_standalone_time_exact,
+    },
+    FlexibleDate, ParseOptions, Parsed,
+};
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+
+/// Represents a time of day on a 24-hour clock. 24:00 stands for the midnight at the end of
+/// the day, as opposed to 00:00 at its start (see [`MidnightPolicy`]).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FlexibleTime {
+    pub hour: u32,
+    pub minute: u32,
+}
+
+/// Which midnight "midnight" means when it's attached to a day, as in "friday at midnight".
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MidnightPolicy {
+    /// The midnight at the end of the day, so "friday at midnight" is the very start of
+    /// Saturday. This is what people usually mean by "midnight tonight".
+    EndOfDay,
+
+    /// The midnight at the start of the day, so "friday at midnight" is the very start of
+    /// Friday.
+    StartOfDay,
+}
+
+/// Which day a time given without a date means, as in "call mom at 5".
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TimeOnlyPolicy {
+    /// Today if the time is still to come, and tomorrow otherwise, so "at 9am" said in the
+    /// afternoon means tomorrow morning.
+    Upcoming,
+
+    /// Always today, even if the time has passed.
+    Today,
+}
+
+impl FlexibleTime {
+    /// Parses a `FlexibleTime` from a string. Fails (returns `None`) if the string does not
+    /// start with a time of day.
+    ///
+    /// ```rust
+    /// # use smart_date::FlexibleTime;
+    /// # fn main() {
+    /// let result = FlexibleTime::parse_from_str("5:30pm").unwrap();
+    /// assert_eq!(result, FlexibleTime { hour: 17, minute: 30 });
+    ///
+    /// let result = FlexibleTime::parse_from_str("at 17:00").unwrap();
+    /// assert_eq!(result, FlexibleTime { hour: 17, minute: 0 });
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn parse_from_str(text: &str) -> Option<FlexibleTime> {
+        Self::parse_from_str_with_options(text, &ParseOptions::default())
+    }
+
+    /// Same as [`FlexibleTime::parse_from_str`], but with control over how the text is parsed.
+    #[must_use]
+    pub fn parse_from_str_with_options(text: &str, options: &ParseOptions) -> Option<FlexibleTime> {
+        parse_standalone_time_exact(text, options)
+            .ok()
+            .map(|(_, time)| time.data)
+    }
+
+    /// Finds and parses a `FlexibleTime` from within a string. Hours on their own, like "5",
+    /// are only found after "at" or when they're approximate, like "around 5" or "5ish".
+    ///
+    /// ```rust
+    /// # use smart_date::FlexibleTime;
+    /// # fn main() {
+    /// let result = FlexibleTime::find_and_parse_in_str("standup at 9:15").unwrap();
+    /// assert_eq!(result.data, FlexibleTime { hour: 9, minute: 15 });
+    /// assert_eq!(result.range, (8..15));
+    ///
+    /// assert_eq!(FlexibleTime::find_and_parse_in_str("chapter 5"), None);
+    ///
+    /// let result = FlexibleTime::find_and_parse_in_str("home 6ish").unwrap();
+    /// assert_eq!(result.data, FlexibleTime { hour: 18, minute: 0 });
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn find_and_parse_in_str(text: &str) -> Option<Parsed<FlexibleTime>> {
+        Self::find_and_parse_in_str_with_options(text, &ParseOptions::default())
+    }
+
+    /// Same as [`FlexibleTime::find_and_parse_in_str`], but with control over how the text is
+    /// parsed.
+    #[must_use]
+    pub fn find_and_parse_in_str_with_options(
+        text: &str,
+        options: &ParseOptions,
+    ) -> Option<Parsed<FlexibleTime>> {
+        parse_standalone_time(text, options)
+    }
+
+    /// Converts the `FlexibleTime` into a [`NaiveTime`]. Returns `None` if the hour or minute
+    /// is out of range, including for 24:00.
+    #[must_use]
+    pub fn into_naive_time(self) -> Option<NaiveTime> {
+        NaiveTime::from_hms_opt(self.hour, self.minute, 0)
+    }
+
+    /// This time on `date`, where 24:00 is the start of the following day.
+    pub(crate) fn on_date(self, date: NaiveDate) -> Option<NaiveDateTime> {
+        if self.hour == 24 && self.minute == 0 {
+            Some(date.succ_opt()?.and_time(NaiveTime::MIN))
+        } else {
+            Some(date.and_time(self.into_naive_time()?))
+        }
+    }
+}
+
+/// Represents a date along with a time of day, eg. "tomorrow at 5".
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FlexibleDateTime {
+    pub date: FlexibleDate,
+
+    /// The time of day. This is midnight for all-day dates.
+    pub time: FlexibleTime,
+
+    /// Whether the text only gave a date, so the date time lasts the whole day rather than
+    /// starting at a particular time, eg. "tomorrow".
+    pub all_day: bool,
+}
+
+impl FlexibleDateTime {
+    /// Parses a `FlexibleDateTime` from a string. Fails (returns `None`) if the full string does
+    /// not match a date and time.
+    ///
+    /// ```rust
+    /// # use smart_date::{FlexibleDate, FlexibleDateTime, FlexibleTime};
+    /// # fn main() {
+    /// let result = FlexibleDateTime::parse_from_str("tomorrow at 5").unwrap();
+    /// assert_eq!(result.date, FlexibleDate::Tomorrow);
+    /// assert_eq!(result.time, FlexibleTime { hour: 17, minute: 0 });
+    ///
+    /// // dates without a time last all day
+    /// let result = FlexibleDateTime::parse_from_str("tomorrow").unwrap();
+    /// assert!(result.all_day);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn parse_from_str(text: &str) -> Option<FlexibleDateTime> {
+        Self::parse_from_str_with_options(text, &ParseOptions::default())
+    }
+
+    /// Same as [`FlexibleDateTime::parse_from_str`], but with control over how the text is
+    /// parsed.
+    #[must_use]
+    pub fn parse_from_str_with_options(
+        text: &str,
+        options: &ParseOptions,
+    ) -> Option<FlexibleDateTime> {
+        parse_flex_datetime_exact(text, options)
+            .ok()
+            .map(|(_, datetime)| datetime.data)
+    }
+
+    /// Finds and parses a `FlexibleDateTime` from within a string. The range covers the whole
+    /// phrase, including its time, eg. "tomorrow at 3pm" or "tomorrow @ 3pm".
+    ///
+    /// ```rust
+    /// # use smart_date::FlexibleDateTime;
+    /// # fn main() {
+    /// let result = FlexibleDateTime::find_and_parse_in_str("call bob fri at 9").unwrap();
+    /// assert_eq!(result.range, (9..17));
+    /// assert_eq!(result.data.time.hour, 9);
+    /// assert!(result.inferred);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn find_and_parse_in_str(text: &str) -> Option<Parsed<FlexibleDateTime>> {
+        Self::find_and_parse_in_str_with_options(text, &ParseOptions::default())
+    }
+
+    /// Same as [`FlexibleDateTime::find_and_parse_in_str`], but with control over how the text
+    /// is parsed.
+    #[must_use]
+    pub fn find_and_parse_in_str_with_options(
+        text: &str,
+        options: &ParseOptions,
+    ) -> Option<Parsed<FlexibleDateTime>> {
+        parse_flex_datetime(text, options)
+    }
+
+    /// Converts the `FlexibleDateTime` into a [`NaiveDateTime`]. Returns `None` if the date or
+    /// time is out of range. A time of 24:00 resolves to the start of the next day.
+    ///
+    /// ```rust
+    /// # use smart_date::FlexibleDateTime;
+    /// # fn main() {
+    /// let now = chrono::NaiveDateTime::parse_from_str("2023-10-08 12:00", "%Y-%m-%d %H:%M").unwrap();
+    ///
+    /// let datetime = FlexibleDateTime::parse_from_str("tomorrow at 5").unwrap();
+    /// let datetime = datetime.into_naive_datetime(now).unwrap();
+    /// assert_eq!(datetime.to_string(), "2023-10-09 17:00:00");
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn into_naive_datetime(self, now: NaiveDateTime) -> Option<NaiveDateTime> {
+        let date = self.resolve_date(now)?;
+        self.time.on_date(date)
+    }
+
+    /// The day that the date time lands on, relative to `now`. This is usually just the date
+    /// resolved against today, except that [`FlexibleDate::TodayOrTomorrow`] moves to tomorrow
+    /// once its time has passed. Returns `None` if the date or time is out of range.
+    pub(crate) fn resolve_date(&self, now: NaiveDateTime) -> Option<NaiveDate> {
+        let date = self.date.clone().checked_into_naive_date(now.date())?;
+        if self.date == FlexibleDate::TodayOrTomorrow && self.time.clone().on_date(date)? <= now {
+            date.succ_opt()
+        } else {
+            Some(date)
+        }
+    }
+
+    /// How long it is from this date time until `other`, with both resolved relative to `now`.
+    /// Negative if `other` comes first. Returns `None` if either time is out of range.
+    ///
+    /// ```rust
+    /// # use smart_date::FlexibleDateTime;
+    /// # fn main() {
+    /// let now = chrono::NaiveDateTime::parse_from_str("2023-10-08 12:00", "%Y-%m-%d %H:%M").unwrap();
+    ///
+    /// let start = FlexibleDateTime::parse_from_str("tomorrow at 2pm").unwrap();
+    /// let end = FlexibleDateTime::parse_from_str("tomorrow at 5pm").unwrap();
+    /// let duration = start.duration_until(end, now).unwrap();
+    /// assert_eq!(duration.num_hours(), 3);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn duration_until(self, other: FlexibleDateTime, now: NaiveDateTime) -> Option<Duration> {
+        let start = self.into_naive_datetime(now)?;
+        let end = other.into_naive_datetime(now)?;
+        Some(end - start)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::unwrap_used)]
+
+    use super::*;
+
+    #[test]
+    fn test_end_of_day_into_naive_datetime() {
+        let now = NaiveDateTime::parse_from_str("2023-10-08 12:00", "%Y-%m-%d %H:%M").unwrap();
+
+        let datetime = FlexibleDateTime {
+            date: FlexibleDate::Tomorrow,
+            time: FlexibleTime {
+                hour: 24,
+                minute: 0,
+            },
+            all_day: false,
+        };
+        let datetime = datetime.into_naive_datetime(now).unwrap();
+        assert_eq!(datetime.to_string(), "2023-10-10 00:00:00");
+
+        let datetime = FlexibleDateTime {
+            date: FlexibleDate::Tomorrow,
+            time: FlexibleTime { hour: 0, minute: 0 },
+            all_day: false,
+        };
+        let datetime = datetime.into_naive_datetime(now).unwrap();
+        assert_eq!(datetime.to_string(), "2023-10-09 00:00:00");
+    }
+
+    #[test]
+    fn test_today_or_tomorrow_into_naive_datetime() {
+        let now = NaiveDateTime::parse_from_str("2023-10-08 12:00", "%Y-%m-%d %H:%M").unwrap();
+        let at = |hour| FlexibleDateTime {
+            date: FlexibleDate::TodayOrTomorrow,
+            time: FlexibleTime { hour, minute: 0 },
+            all_day: false,
+        };
+
+        let datetime = at(17).into_naive_datetime(now).unwrap();
+        assert_eq!(datetime.to_string(), "2023-10-08 17:00:00");
+        let datetime = at(9).into_naive_datetime(now).unwrap();
+        assert_eq!(datetime.to_string(), "2023-10-09 09:00:00");
+        let datetime = at(12).into_naive_datetime(now).unwrap();
+        assert_eq!(datetime.to_string(), "2023-10-09 12:00:00");
+    }
+}